@@ -0,0 +1,222 @@
+//! Parsing for `#[check(...)]` attributes.
+
+use syn::{
+    meta::ParseNestedMeta, parenthesized, parse_quote, punctuated::Punctuated, Attribute, Expr,
+    Ident, LitStr, Path, Token, Type, WherePredicate,
+};
+
+/// Options declared on the type being derived.
+#[derive(Default)]
+pub(crate) struct Container {
+    /// `#[check(with = "<path>")]`: functions checking the whole value, after its fields.
+    pub(crate) with: Vec<Path>,
+
+    /// `#[check(error_enum = "<ident>")]`: generate an error enum with the given name.
+    pub(crate) error_enum: Option<Ident>,
+
+    /// `#[check(error = "<type>")]`: the error type for the implementation, which the generated
+    /// errors are converted into with `From`.
+    pub(crate) error: Option<Type>,
+
+    /// `#[check(bound = "<predicates>")]`: bounds for the implementation, replacing the inferred
+    /// ones.
+    pub(crate) bound: Option<Vec<WherePredicate>>,
+
+    /// `#[check(builder)]`: generate a builder for the value.
+    pub(crate) builder: Option<Builder>,
+}
+
+/// The kind of builder to generate.
+pub(crate) enum Builder {
+    /// `#[check(builder)]`: a builder that checks the value when it's built.
+    Plain,
+
+    /// `#[check(builder(typestate))]`: a typestate builder that checks fields as they're set, and
+    /// can only be built once every field is set.
+    Typestate,
+}
+
+impl Container {
+    /// Parse the options from the type's attributes.
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut container = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("check")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("with") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    container.with.push(path.parse()?);
+                } else if meta.path.is_ident("error_enum") {
+                    let ident: LitStr = meta.value()?.parse()?;
+                    container.error_enum = Some(ident.parse()?);
+                } else if meta.path.is_ident("error") {
+                    let ty: LitStr = meta.value()?.parse()?;
+                    container.error = Some(ty.parse()?);
+                } else if meta.path.is_ident("builder") {
+                    container.builder = Some(Builder::Plain);
+                    if !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+                        meta.parse_nested_meta(|meta| {
+                            if meta.path.is_ident("typestate") {
+                                container.builder = Some(Builder::Typestate);
+                                Ok(())
+                            } else {
+                                Err(meta.error("unsupported builder option"))
+                            }
+                        })?;
+                    }
+                } else if meta.path.is_ident("bound") {
+                    let bound: LitStr = meta.value()?.parse()?;
+                    let predicates = bound
+                        .parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+                    container
+                        .bound
+                        .get_or_insert_with(Vec::new)
+                        .extend(predicates);
+                } else {
+                    return Err(meta.error("unsupported option"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(container)
+    }
+}
+
+/// A check declared on a field.
+pub(crate) enum FieldCheck {
+    /// `#[check(range(<expr>))]`: the field must be within the given range.
+    Range(Expr),
+
+    /// `#[check(non_empty)]`: the field must not be empty.
+    NonEmpty,
+
+    /// `#[check(custom = "<path>")]`: the field must pass the given function.
+    Custom(Path),
+
+    /// `#[check(nested)]`: the field must pass its own `Check` implementation.
+    Nested,
+
+    /// `#[check(length(min = <expr>, max = <expr>, equal = <expr>))]`: the field's length must be
+    /// within the given bounds.
+    Length {
+        min: Option<Expr>,
+        max: Option<Expr>,
+    },
+
+    /// `#[check(contains = "<str>")]`: the field must contain the given string.
+    Contains(LitStr),
+
+    /// `#[check(must_match = "<field>")]`: the field must equal another field.
+    MustMatch(LitStr),
+
+    /// `#[check(email)]`: the field must be an email address.
+    Email,
+
+    /// `#[check(url)]`: the field must be a URL.
+    Url,
+}
+
+/// Parse the checks from a field's attributes.
+///
+/// If the field is marked `#[check(skip)]`, any other checks are parsed but ignored.
+pub(crate) fn field_checks(attrs: &[Attribute]) -> syn::Result<Vec<FieldCheck>> {
+    let mut checks = Vec::new();
+    let mut skip = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("check")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("range") {
+                checks.push(FieldCheck::Range(range(&meta)?));
+            } else if meta.path.is_ident("non_empty") {
+                checks.push(FieldCheck::NonEmpty);
+            } else if meta.path.is_ident("custom") {
+                let path: LitStr = meta.value()?.parse()?;
+                checks.push(FieldCheck::Custom(path.parse()?));
+            } else if meta.path.is_ident("nested") {
+                checks.push(FieldCheck::Nested);
+            } else if meta.path.is_ident("length") {
+                let (mut min, mut max) = (None, None);
+                meta.parse_nested_meta(|meta| {
+                    let value: Expr = meta.value()?.parse()?;
+                    if meta.path.is_ident("min") {
+                        min = Some(value);
+                    } else if meta.path.is_ident("max") {
+                        max = Some(value);
+                    } else if meta.path.is_ident("equal") {
+                        min = Some(value.clone());
+                        max = Some(value);
+                    } else {
+                        return Err(meta.error("expected `min`, `max`, or `equal`"));
+                    }
+                    Ok(())
+                })?;
+                checks.push(FieldCheck::Length { min, max });
+            } else if meta.path.is_ident("contains") {
+                checks.push(FieldCheck::Contains(string(&meta, "pattern")?));
+            } else if meta.path.is_ident("must_match") {
+                checks.push(FieldCheck::MustMatch(string(&meta, "other")?));
+            } else if meta.path.is_ident("email") {
+                checks.push(FieldCheck::Email);
+            } else if meta.path.is_ident("url") {
+                checks.push(FieldCheck::Url);
+            } else if meta.path.is_ident("skip") {
+                skip = true;
+            } else {
+                return Err(meta.error("unsupported check"));
+            }
+            Ok(())
+        })?;
+    }
+    if skip {
+        checks.clear();
+    }
+    Ok(checks)
+}
+
+/// Parse a range, given either as an expression (`range(1..=10)`) or as `validator`-style bounds
+/// (`range(min = 1, max = 10)`).
+fn range(meta: &ParseNestedMeta) -> syn::Result<Expr> {
+    let fork = meta.input.fork();
+    let content;
+    parenthesized!(content in fork);
+    if !(content.peek(Ident) && content.peek2(Token![=])) {
+        let content;
+        parenthesized!(content in meta.input);
+        return content.parse();
+    }
+
+    let (mut min, mut max) = (None, None);
+    meta.parse_nested_meta(|meta| {
+        let value: Expr = meta.value()?.parse()?;
+        if meta.path.is_ident("min") {
+            min = Some(value);
+        } else if meta.path.is_ident("max") {
+            max = Some(value);
+        } else {
+            return Err(meta.error("expected `min` or `max`"));
+        }
+        Ok(())
+    })?;
+    Ok(match (min, max) {
+        (Some(min), Some(max)) => parse_quote!(#min..=#max),
+        (Some(min), None) => parse_quote!(#min..),
+        (None, Some(max)) => parse_quote!(..=#max),
+        (None, None) => return Err(meta.error("expected `min` or `max`")),
+    })
+}
+
+/// Parse a string argument, given either as `name = "..."` or `name(<key> = "...")`.
+fn string(meta: &ParseNestedMeta, key: &str) -> syn::Result<LitStr> {
+    if meta.input.peek(Token![=]) {
+        return meta.value()?.parse();
+    }
+
+    let mut string = None;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident(key) {
+            string = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error(format_args!("expected `{key}`")))
+        }
+    })?;
+    string.ok_or_else(|| meta.error(format_args!("expected `{key}`")))
+}
@@ -0,0 +1,399 @@
+//! `#[check(builder)]`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parse_quote, punctuated::Punctuated, token::Comma, Data, DeriveInput, Error,
+    Field, Fields, GenericParam, Generics, Ident, Path, Visibility,
+};
+
+use crate::{
+    attr::{self, Builder, FieldCheck},
+    check::{self, Errors},
+};
+
+/// The parts of the `Check` implementation that builders need.
+pub(crate) struct Context<'a> {
+    /// The generics of the `Check` implementation, including any inferred bounds.
+    pub(crate) generics: &'a Generics,
+
+    /// How the implementation reports errors.
+    pub(crate) errors: &'a Errors<'a>,
+
+    /// The implementation's error type.
+    pub(crate) error_ty: &'a TokenStream,
+
+    /// Functions checking the whole value.
+    pub(crate) with: &'a [Path],
+}
+
+/// Expand a builder for a struct with named fields.
+pub(crate) fn expand(
+    input: &DeriveInput,
+    kind: &Builder,
+    context: &Context,
+) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Some(&fields.named),
+            Fields::Unnamed(_) | Fields::Unit => None,
+        },
+        Data::Enum(_) | Data::Union(_) => None,
+    }
+    .ok_or_else(|| {
+        Error::new_spanned(
+            input,
+            "`#[check(builder)]` requires a struct with named fields",
+        )
+    })?;
+
+    match kind {
+        Builder::Plain => Ok(expand_plain(input, fields, context)),
+        Builder::Typestate => expand_typestate(input, fields, context),
+    }
+}
+
+/// Expand a builder whose `build` method checks the value.
+fn expand_plain(
+    input: &DeriveInput,
+    fields: &Punctuated<Field, Comma>,
+    context: &Context,
+) -> TokenStream {
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let builder = format_ident!("{}Builder", ident);
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (check_generics, _, check_where_clause) = context.generics.split_for_impl();
+    let error_ty = context.error_ty;
+
+    let idents: Vec<_> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+    let names = idents.iter().map(|ident| ident.unraw().to_string());
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let setters = idents.iter().zip(&types).map(|(ident, ty)| {
+        let doc = format!(" Set the `{}` field.", ident.unraw());
+        quote! {
+            #[doc = #doc]
+            #[must_use]
+            #vis fn #ident(mut self, #ident: #ty) -> Self {
+                self.#ident = ::core::option::Option::Some(#ident);
+                self
+            }
+        }
+    });
+
+    let doc = format!(" A builder for a checked [`{ident}`].");
+    let builder_doc = format!(" Start building a checked [`{ident}`].");
+    quote! {
+        #[doc = #doc]
+        #vis struct #builder #generics #where_clause {
+            #(#idents: ::core::option::Option<#types>,)*
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc = #builder_doc]
+            #[must_use]
+            #vis fn builder() -> #builder #ty_generics {
+                ::core::default::Default::default()
+            }
+        }
+
+        impl #impl_generics ::core::default::Default for #builder #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#idents: ::core::option::Option::None,)*
+                }
+            }
+        }
+
+        impl #impl_generics #builder #ty_generics #where_clause {
+            #(#setters)*
+        }
+
+        impl #check_generics #builder #ty_generics #check_where_clause {
+            /// Build and check the value.
+            ///
+            /// # Errors
+            ///
+            /// This will return an error if any fields haven't been set, or if the check fails.
+            #vis fn build(
+                self,
+            ) -> ::core::result::Result<
+                ::check_mate::Checked<#ident #ty_generics>,
+                ::check_mate::error::BuildError<#error_ty>,
+            > {
+                let value = #ident {
+                    #(
+                        #idents: self.#idents.ok_or(
+                            ::check_mate::error::BuildError::Missing(#names),
+                        )?,
+                    )*
+                };
+                ::check_mate::Checked::try_from(value)
+                    .map_err(::check_mate::error::BuildError::Invalid)
+            }
+        }
+    }
+}
+
+/// Expand a typestate builder, whose setters check their fields, and whose `build` method only
+/// exists once every field is set.
+///
+/// The builder has a type parameter for the state of each field, which is either
+/// `check_mate::derive::Unset`, or `check_mate::derive::Set<T>` once the field has been set.
+fn expand_typestate(
+    input: &DeriveInput,
+    fields: &Punctuated<Field, Comma>,
+    context: &Context,
+) -> syn::Result<TokenStream> {
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let builder = format_ident!("{}Builder", ident);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (check_generics, _, check_where_clause) = context.generics.split_for_impl();
+    let error_ty = context.error_ty;
+    let unset = quote!(::check_mate::derive::Unset);
+
+    let idents: Vec<_> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let states: Vec<_> = (0..idents.len())
+        .map(|index| format_ident!("__S{}", index))
+        .collect();
+    let args = generic_args(&input.generics);
+
+    let mut def_generics = input.generics.clone();
+    def_generics.params.extend(
+        states
+            .iter()
+            .map(|state| -> GenericParam { parse_quote!(#state = ::check_mate::derive::Unset) }),
+    );
+
+    let typestate = Typestate {
+        vis,
+        builder: &builder,
+        idents: &idents,
+        states: &states,
+        args: &args,
+        context,
+    };
+    let setters = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| typestate.setter(index, field))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let with = if context.with.is_empty() {
+        Vec::new()
+    } else {
+        let error = context.errors.whole_expr();
+        context
+            .with
+            .iter()
+            .map(|path| {
+                quote! {
+                    #path(&__value).map_err(|error| #error)?;
+                }
+            })
+            .collect()
+    };
+
+    let doc = format!(
+        " A builder for a checked [`{ident}`], which checks fields as they're set.\n\n \
+         [`build`]({builder}::build) is only available once every field has been set."
+    );
+    let builder_doc = format!(" Start building a checked [`{ident}`].");
+    let unsets = states.iter().map(|_| &unset);
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug)]
+        #vis struct #builder #def_generics #where_clause {
+            #(#idents: #states,)*
+            __marker: ::core::marker::PhantomData<fn() -> #ident #ty_generics>,
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc = #builder_doc]
+            #[must_use]
+            #vis fn builder() -> #builder<#(#args,)* #(#unsets),*> {
+                #builder {
+                    #(#idents: #unset,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        #(#setters)*
+
+        impl #check_generics #builder<#(#args,)* #(::check_mate::derive::Set<#types>),*>
+            #check_where_clause
+        {
+            /// Build the value, running any whole-value checks.
+            ///
+            /// # Errors
+            ///
+            /// This will return an error if a whole-value check fails.
+            #vis fn build(
+                self,
+            ) -> ::core::result::Result<::check_mate::Checked<#ident #ty_generics>, #error_ty> {
+                let __value = #ident {
+                    #(#idents: self.#idents.0,)*
+                };
+                #(#with)*
+                // SAFETY: every field was checked when it was set, and the whole-value checks have
+                // just passed, which is everything the derived `Check` implementation checks.
+                ::core::result::Result::Ok(unsafe { ::check_mate::derive::assume_checked(__value) })
+            }
+        }
+    })
+}
+
+/// The arguments for the given generic parameters, e.g. `'a, T, N` for `<'a, T: Debug, const N:
+/// usize>`.
+fn generic_args(generics: &Generics) -> Vec<TokenStream> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                quote!(#lifetime)
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+        })
+        .collect()
+}
+
+/// The parts of a typestate builder needed to expand its setters.
+struct Typestate<'a> {
+    vis: &'a Visibility,
+    builder: &'a Ident,
+    idents: &'a [&'a Ident],
+    states: &'a [Ident],
+    args: &'a [TokenStream],
+    context: &'a Context<'a>,
+}
+
+impl Typestate<'_> {
+    /// The builder's state parameters, with the given field's replaced by `state`.
+    fn states_with(&self, index: usize, state: &TokenStream) -> Vec<TokenStream> {
+        self.states
+            .iter()
+            .enumerate()
+            .map(|(other, other_state)| {
+                if other == index {
+                    state.clone()
+                } else {
+                    quote!(#other_state)
+                }
+            })
+            .collect()
+    }
+
+    /// Expand the setter for a field, which is implemented when the field is unset.
+    fn setter(&self, index: usize, field: &Field) -> syn::Result<TokenStream> {
+        let Self {
+            vis,
+            builder,
+            idents,
+            states,
+            args,
+            context,
+        } = self;
+        let error_ty = context.error_ty;
+        let unset = quote!(::check_mate::derive::Unset);
+        let ty = &field.ty;
+        let field_ident = &idents[index];
+        let mut checks = attr::field_checks(&field.attrs)?;
+        if check::is_checked(ty) {
+            checks.retain(|check| !matches!(check, FieldCheck::Nested));
+        }
+        if let Some(FieldCheck::MustMatch(other)) = checks
+            .iter()
+            .find(|check| matches!(check, FieldCheck::MustMatch(_)))
+        {
+            return Err(Error::new_spanned(
+                other,
+                "`must_match` isn't supported with `#[check(builder(typestate))]`",
+            ));
+        }
+
+        let mut generics = context.generics.clone();
+        generics.params.extend(
+            states
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .map(|(_, state)| -> GenericParam { parse_quote!(#state) }),
+        );
+        let (setter_generics, _, setter_where_clause) = generics.split_for_impl();
+        let before = self.states_with(index, &unset);
+        let after = self.states_with(index, &quote!(::check_mate::derive::Set<#ty>));
+        let others = idents
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, ident)| ident);
+        let next = quote! {
+            #builder {
+                #field_ident: ::check_mate::derive::Set(#field_ident),
+                #(#others: self.#others,)*
+                __marker: ::core::marker::PhantomData,
+            }
+        };
+
+        let name = field_ident.unraw().to_string();
+        let setter = if checks.is_empty() {
+            let doc = format!(" Set the `{name}` field.");
+            quote! {
+                #[doc = #doc]
+                #[must_use]
+                #vis fn #field_ident(
+                    self,
+                    #field_ident: #ty,
+                ) -> #builder<#(#args,)* #(#after),*> {
+                    #next
+                }
+            }
+        } else {
+            let doc = format!(" Check and set the `{name}` field.");
+            let error = context.errors.field_expr(None, &name);
+            let checks = checks.iter().map(|check| {
+                check::expand_check(check, field_ident, &quote!(&#field_ident), &error, |_| {
+                    unreachable!("must_match is rejected above")
+                })
+            });
+            quote! {
+                #[doc = #doc]
+                ///
+                /// # Errors
+                ///
+                /// This will return an error if the field fails its checks.
+                #vis fn #field_ident(
+                    self,
+                    #field_ident: #ty,
+                ) -> ::core::result::Result<#builder<#(#args,)* #(#after),*>, #error_ty> {
+                    #(#checks)*
+                    ::core::result::Result::Ok(#next)
+                }
+            }
+        };
+        Ok(quote! {
+            impl #setter_generics #builder<#(#args,)* #(#before),*> #setter_where_clause {
+                #setter
+            }
+        })
+    }
+}
@@ -0,0 +1,557 @@
+//! `#[derive(Check)]`.
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    ext::IdentExt, parse_quote, Data, DeriveInput, Error, Expr, Fields, Generics, Ident, LitStr,
+    Type, WherePredicate,
+};
+
+use crate::{
+    attr::{self, Container, FieldCheck},
+    builder,
+};
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let container = Container::from_attrs(&input.attrs)?;
+    let mut errors = match &container.error_enum {
+        Some(ident) => Errors::Enum {
+            ident,
+            variants: Vec::new(),
+        },
+        None => Errors::Field,
+    };
+
+    // Nested checks consume their fields, so if there are any the value is destructured (and
+    // reconstructed) by value. Otherwise, fields are checked by reference.
+    let nested = nested_types(input)?;
+    let owned = !nested.is_empty();
+    let body = expand_body(input, owned, &mut errors)?;
+
+    let with = if container.with.is_empty() {
+        Vec::new()
+    } else {
+        let error = errors.whole();
+        let value = if owned {
+            quote!(&__value)
+        } else {
+            quote!(self)
+        };
+        container
+            .with
+            .iter()
+            .map(|path| {
+                quote! {
+                    #path(#value).map_err(|error| #error)?;
+                }
+            })
+            .collect()
+    };
+
+    let ident = &input.ident;
+    let generics = impl_generics(input, &container, &nested);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let error_ty = match &container.error {
+        Some(ty) => ty.to_token_stream(),
+        None => errors.ty(),
+    };
+    let error_enum = errors.definition(input);
+    let builder = if let Some(kind) = &container.builder {
+        let context = builder::Context {
+            generics: &generics,
+            errors: &errors,
+            error_ty: &error_ty,
+            with: &container.with,
+        };
+        builder::expand(input, kind, &context)?
+    } else {
+        TokenStream::new()
+    };
+
+    // Without nested checks, the checks are implemented by reference in a `CheckRef`
+    // implementation, which `Check` delegates to.
+    let check = if owned {
+        quote! {
+            #body
+            #(#with)*
+            ::core::result::Result::Ok(__value)
+        }
+    } else {
+        quote! {
+            ::check_mate::CheckRef::check_ref(&self)?;
+            ::core::result::Result::Ok(self)
+        }
+    };
+    let check_ref = if owned {
+        TokenStream::new()
+    } else {
+        quote! {
+            impl #impl_generics ::check_mate::CheckRef for #ident #ty_generics #where_clause {
+                type Err = #error_ty;
+
+                fn check_ref(&self) -> ::core::result::Result<(), Self::Err> {
+                    #body
+                    #(#with)*
+                    ::core::result::Result::Ok(())
+                }
+            }
+        }
+    };
+    Ok(quote! {
+        #error_enum
+        #builder
+        #check_ref
+
+        impl #impl_generics ::check_mate::Check for #ident #ty_generics #where_clause {
+            type Ok = Self;
+            type Err = #error_ty;
+
+            fn check(self) -> ::core::result::Result<Self::Ok, Self::Err> {
+                #check
+            }
+        }
+    })
+}
+
+/// Expand the checks for the type's fields.
+///
+/// When `owned`, `self` is checked by value and bound to `__value` if the checks pass. Otherwise,
+/// `self` is a reference and is left as-is.
+fn expand_body(input: &DeriveInput, owned: bool, errors: &mut Errors) -> syn::Result<TokenStream> {
+    Ok(match &input.data {
+        Data::Struct(data) => {
+            let (pattern, checks) = expand_fields(&data.fields, None, owned, errors)?;
+            if owned {
+                quote! {
+                    let Self #pattern = self;
+                    #(#checks)*
+                    let __value = Self #pattern;
+                }
+            } else {
+                quote! {
+                    let Self #pattern = *self;
+                    #(#checks)*
+                }
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let (pattern, checks) = expand_fields(&variant.fields, Some(ident), owned, errors)?;
+                arms.push(if owned {
+                    quote! {
+                        Self::#ident #pattern => {
+                            #(#checks)*
+                            Self::#ident #pattern
+                        }
+                    }
+                } else {
+                    quote! {
+                        Self::#ident #pattern => {
+                            #(#checks)*
+                        }
+                    }
+                });
+            }
+            if owned {
+                quote! {
+                    let __value = match self {
+                        #(#arms)*
+                    };
+                }
+            } else {
+                quote! {
+                    match *self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                input,
+                "`Check` can't be derived for unions",
+            ))
+        }
+    })
+}
+
+/// The types of the fields with nested checks.
+///
+/// Fields that are `Checked<...>` are skipped, since they're not checked again.
+fn nested_types(input: &DeriveInput) -> syn::Result<Vec<&Type>> {
+    let fields: Vec<_> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| &variant.fields)
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+    let mut types = Vec::new();
+    for field in fields {
+        let nested = attr::field_checks(&field.attrs)?
+            .iter()
+            .any(|check| matches!(check, FieldCheck::Nested));
+        if nested && !is_checked(&field.ty) {
+            types.push(&field.ty);
+        }
+    }
+    Ok(types)
+}
+
+/// The generics for the implementation, with bounds for nested fields.
+///
+/// Nested fields whose types mention a type parameter need bounds for their own `Check`
+/// implementations. These are inferred unless `#[check(bound = "...")]` is given.
+fn impl_generics(input: &DeriveInput, container: &Container, nested: &[&Type]) -> Generics {
+    let mut generics = input.generics.clone();
+    let predicates: Vec<WherePredicate> = if let Some(bound) = &container.bound {
+        bound.clone()
+    } else {
+        let params: Vec<_> = input
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect();
+        nested
+            .iter()
+            .filter(|ty| mentions(ty.to_token_stream(), &params))
+            .flat_map(|ty| -> [WherePredicate; 2] {
+                [
+                    parse_quote!(#ty: ::check_mate::Check<Ok = #ty>),
+                    parse_quote! {
+                        <#ty as ::check_mate::Check>::Err:
+                            ::core::convert::Into<::check_mate::error::BoxError>
+                    },
+                ]
+            })
+            .collect()
+    };
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+    generics
+}
+
+/// Whether some tokens mention any of the given identifiers.
+fn mentions(tokens: TokenStream, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&&ident),
+        TokenTree::Group(group) => mentions(group.stream(), idents),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
+
+/// Expand the checks for a struct's or variant's fields.
+///
+/// This returns a pattern binding the checked fields, and the checks to run on them. If `owned` is
+/// set, the pattern binds every field by value, so it can also be used to reconstruct the value.
+/// Otherwise it binds references to the checked fields only.
+fn expand_fields(
+    fields: &Fields,
+    variant: Option<&Ident>,
+    owned: bool,
+    errors: &mut Errors,
+) -> syn::Result<(TokenStream, Vec<TokenStream>)> {
+    let names: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => (ident.unraw().to_string(), ident.clone()),
+            None => (index.to_string(), format_ident!("__field{}", index)),
+        })
+        .collect();
+    let reference = |binding: &Ident| {
+        if owned {
+            quote!(&#binding)
+        } else {
+            quote!(#binding)
+        }
+    };
+
+    let mut field_checks = Vec::new();
+    let mut matched = Vec::new();
+    for field in fields {
+        // `Checked` values are valid by construction, so aren't checked again.
+        let mut checks = attr::field_checks(&field.attrs)?;
+        if is_checked(&field.ty) {
+            checks.retain(|check| !matches!(check, FieldCheck::Nested));
+        }
+        for check in &checks {
+            if let FieldCheck::MustMatch(other) = check {
+                if !names.iter().any(|(name, _)| *name == other.value()) {
+                    return Err(Error::new_spanned(other, "unknown field"));
+                }
+                matched.push(other.value());
+            }
+        }
+        field_checks.push(checks);
+    }
+
+    let mut bindings = Vec::new();
+    let mut checks = Vec::new();
+    for ((field, field_checks), (name, binding)) in fields.iter().zip(field_checks).zip(&names) {
+        let value = reference(binding);
+
+        if !field_checks.is_empty() {
+            let error = errors.field(variant, name);
+            checks.extend(field_checks.iter().map(|check| {
+                expand_check(check, binding, &value, &error, |other| {
+                    let (_, other_binding) = names
+                        .iter()
+                        .find(|(name, _)| *name == other.value())
+                        .expect("must_match fields are validated above");
+                    reference(other_binding)
+                })
+            }));
+        }
+
+        let by_ref = if owned { quote!() } else { quote!(ref) };
+        let bound = owned || !field_checks.is_empty() || matched.contains(name);
+        bindings.push(match (&field.ident, bound) {
+            (Some(_), false) => continue,
+            (Some(ident), true) => quote!(#by_ref #ident),
+            (None, false) => quote!(_),
+            (None, true) => quote!(#by_ref #binding),
+        });
+    }
+
+    let rest = if owned { quote!() } else { quote!(..) };
+    let pattern = match fields {
+        Fields::Named(_) => quote!({ #(#bindings,)* #rest }),
+        Fields::Unnamed(_) => quote!((#(#bindings,)*)),
+        Fields::Unit => quote!(),
+    };
+    Ok((pattern, checks))
+}
+
+/// Expand a check of a field, bound to `binding`.
+///
+/// `value` is a reference to the field, and `other` gives references to other fields (for
+/// `must_match`). Errors are mapped with `error`, an expression of `error`.
+pub(crate) fn expand_check(
+    check: &FieldCheck,
+    binding: &Ident,
+    value: &TokenStream,
+    error: &TokenStream,
+    other: impl FnOnce(&LitStr) -> TokenStream,
+) -> TokenStream {
+    let result = match check {
+        FieldCheck::Range(range) => quote!(::check_mate::derive::range(#value, #range)),
+        FieldCheck::NonEmpty => quote!(::check_mate::derive::non_empty(#value)),
+        FieldCheck::Custom(path) => quote!(#path(#value)),
+        FieldCheck::Length { min, max } => {
+            let (min, max) = (option(min.as_ref()), option(max.as_ref()));
+            quote!(::check_mate::derive::length(#value, #min, #max))
+        }
+        FieldCheck::Contains(pattern) => quote!(::check_mate::derive::contains(#value, #pattern)),
+        FieldCheck::MustMatch(name) => {
+            let other = other(name);
+            quote!(::check_mate::derive::must_match(#value, #other, #name))
+        }
+        FieldCheck::Email => quote!(::check_mate::derive::email(#value)),
+        FieldCheck::Url => quote!(::check_mate::derive::url(#value)),
+        FieldCheck::Nested => {
+            return quote! {
+                let #binding = ::check_mate::derive::nested(#binding).map_err(|error| #error)?;
+            }
+        }
+    };
+    quote! {
+        #result.map_err(|error| #error)?;
+    }
+}
+
+/// An `Option` expression wrapping the given expression, if any.
+fn option(expr: Option<&Expr>) -> TokenStream {
+    if let Some(expr) = expr {
+        quote!(::core::option::Option::Some(#expr))
+    } else {
+        quote!(::core::option::Option::None)
+    }
+}
+
+/// Whether a type is (syntactically) `Checked<...>`.
+pub(crate) fn is_checked(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Checked"),
+        _ => false,
+    }
+}
+
+/// How the generated implementation reports errors.
+pub(crate) enum Errors<'a> {
+    /// Errors are reported as `FieldError`s.
+    Field,
+
+    /// Errors are reported using a generated enum, with a variant per checked field.
+    Enum {
+        ident: &'a Ident,
+        variants: Vec<ErrorVariant>,
+    },
+}
+
+/// A variant of a generated error enum.
+pub(crate) struct ErrorVariant {
+    ident: Ident,
+
+    /// The field the variant is for, e.g. `name` or `Variant.name`, or `None` for whole-value
+    /// checks.
+    field: Option<String>,
+}
+
+impl Errors<'_> {
+    /// The type of the errors.
+    fn ty(&self) -> TokenStream {
+        match self {
+            Self::Field => quote!(::check_mate::error::FieldError),
+            Self::Enum { ident, .. } => quote!(#ident),
+        }
+    }
+
+    /// An expression converting `error` into an error for the given field.
+    ///
+    /// For generated error enums, this also adds a variant for the field.
+    fn field(&mut self, variant: Option<&Ident>, name: &str) -> TokenStream {
+        if let Self::Enum { variants, .. } = self {
+            let (ident, field) = error_variant(variant, name);
+            variants.push(ErrorVariant {
+                ident,
+                field: Some(field),
+            });
+        }
+        self.field_expr(variant, name)
+    }
+
+    /// An expression converting `error` into an error for the given field, which must already
+    /// have been added with [`field`](Self::field).
+    pub(crate) fn field_expr(&self, variant: Option<&Ident>, name: &str) -> TokenStream {
+        match (self, variant) {
+            (Self::Field, None) => quote!(::check_mate::error::FieldError::new(#name, error)),
+            (Self::Field, Some(variant)) => {
+                let variant = variant.unraw().to_string();
+                quote!(::check_mate::error::FieldError::in_variant(#variant, #name, error))
+            }
+            (Self::Enum { ident, .. }, variant) => {
+                let (error_variant, _) = error_variant(variant, name);
+                quote!(#ident::#error_variant(::core::convert::Into::into(error)))
+            }
+        }
+    }
+
+    /// An expression converting `error` into an error for a whole-value check.
+    ///
+    /// For generated error enums, this also adds an `Invalid` variant.
+    fn whole(&mut self) -> TokenStream {
+        if let Self::Enum { variants, .. } = self {
+            variants.push(ErrorVariant {
+                ident: format_ident!("Invalid"),
+                field: None,
+            });
+        }
+        self.whole_expr()
+    }
+
+    /// An expression converting `error` into an error for a whole-value check, which must already
+    /// have been added with [`whole`](Self::whole).
+    pub(crate) fn whole_expr(&self) -> TokenStream {
+        match self {
+            Self::Field => quote!(::check_mate::error::FieldError::whole(error)),
+            Self::Enum { ident, .. } => quote!(#ident::Invalid(::core::convert::Into::into(error))),
+        }
+    }
+
+    /// The definition of the generated error enum, if there is one.
+    fn definition(&self, input: &DeriveInput) -> TokenStream {
+        let (ident, variants) = match self {
+            Self::Field => return TokenStream::new(),
+            Self::Enum { ident, variants } => (ident, variants),
+        };
+
+        let vis = &input.vis;
+        let doc = format!(" An error from checking a [`{}`].", input.ident);
+        let defs = variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let doc = match &variant.field {
+                Some(field) => format!(" The `{field}` field failed its check."),
+                None => " The value failed a whole-value check.".to_owned(),
+            };
+            quote! {
+                #[doc = #doc]
+                #ident(::check_mate::error::BoxError)
+            }
+        });
+        let fmts = variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            if let Some(field) = &variant.field {
+                quote! {
+                    Self::#ident(ref error) => ::core::write!(f, "{}: {}", #field, error)
+                }
+            } else {
+                quote! {
+                    Self::#ident(ref error) => ::core::fmt::Display::fmt(error, f)
+                }
+            }
+        });
+        let sources = variants.iter().map(|variant| &variant.ident);
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug)]
+            #vis enum #ident {
+                #(#defs,)*
+            }
+
+            impl ::core::fmt::Display for #ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match *self {
+                        #(#fmts,)*
+                    }
+                }
+            }
+
+            impl ::core::error::Error for #ident {
+                fn source(&self) -> ::core::option::Option<&(dyn ::core::error::Error + 'static)> {
+                    match *self {
+                        #(Self::#sources(ref error) => ::core::option::Option::Some(&**error),)*
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The name of the error enum variant for the given field, and the field's path.
+fn error_variant(variant: Option<&Ident>, name: &str) -> (Ident, String) {
+    let mut error_variant = match name.parse::<usize>() {
+        Ok(index) if variant.is_none() => format!("Field{index}"),
+        Ok(index) => index.to_string(),
+        Err(_) => upper_camel(name),
+    };
+    let mut field = name.to_owned();
+    if let Some(variant) = variant {
+        let variant = variant.unraw().to_string();
+        error_variant.insert_str(0, &variant);
+        field.insert_str(0, &format!("{variant}."));
+    }
+    (format_ident!("{}", error_variant), field)
+}
+
+/// Convert a `snake_case` name to `UpperCamelCase`.
+fn upper_camel(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,126 @@
+//! `#[checked]`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    meta::ParseNestedMeta, parenthesized, parse_quote, punctuated::Punctuated, DeriveInput, Ident,
+    LitStr, Path, Token,
+};
+
+/// Options given to `#[checked(...)]`.
+#[derive(Default)]
+pub(crate) struct Args {
+    /// `name = "<ident>"`: the name of the generated type.
+    name: Option<Ident>,
+
+    /// `derive(<paths>)`: derives to apply to the generated type.
+    derives: Vec<Path>,
+}
+
+impl Args {
+    /// Parse an option.
+    pub(crate) fn parse(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("name") {
+            let name: LitStr = meta.value()?.parse()?;
+            self.name = Some(name.parse()?);
+        } else if meta.path.is_ident("derive") {
+            let content;
+            parenthesized!(content in meta.input);
+            self.derives
+                .extend(Punctuated::<Path, Token![,]>::parse_terminated(&content)?);
+        } else {
+            return Err(meta.error("unsupported option"));
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn expand(args: Args, input: &DeriveInput) -> TokenStream {
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let name = args
+        .name
+        .unwrap_or_else(|| format_ident!("Checked{}", ident));
+    let derives = if args.derives.is_empty() {
+        TokenStream::new()
+    } else {
+        let derives = &args.derives;
+        quote!(#[derive(#(#derives),*)])
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let generics = &input.generics;
+    let ty = quote!(#ident #ty_generics);
+
+    let mut checkable = input.generics.clone();
+    checkable
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#ty: ::check_mate::Check<Ok = #ty>));
+    let checkable_where_clause = &checkable.where_clause;
+
+    let doc = format!(" A [`{ident}`] that has been checked.");
+    let new_doc = format!(" Check a [`{ident}`].");
+
+    quote! {
+        #input
+
+        #[doc = #doc]
+        #derives
+        #vis struct #name #generics (::check_mate::Checked<#ty>) #where_clause;
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #new_doc]
+            ///
+            /// # Errors
+            ///
+            /// This will return the error from `Check::check` if the check fails.
+            #vis fn new(value: #ty) -> ::core::result::Result<Self, <#ty as ::check_mate::Check>::Err>
+            where
+                #ty: ::check_mate::Check<Ok = #ty>,
+            {
+                ::check_mate::Checked::try_from(value).map(Self)
+            }
+
+            /// Wrap a value without checking it.
+            ///
+            /// # Safety
+            ///
+            /// The value must already be known to be valid.
+            #vis unsafe fn new_unchecked(value: #ty) -> Self {
+                // SAFETY: the caller guarantees that the value is valid.
+                Self(unsafe { ::check_mate::derive::assume_checked(value) })
+            }
+
+            /// Retrieve the inner value, dropping the 'proof' that it was checked.
+            #vis fn into_inner(self) -> #ty {
+                self.0.into_inner()
+            }
+        }
+
+        impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
+            type Target = #ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<::check_mate::Checked<#ty>> for #name #ty_generics
+        #where_clause
+        {
+            fn from(checked: ::check_mate::Checked<#ty>) -> Self {
+                Self(checked)
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<#ty> for #name #ty_generics
+        #checkable_where_clause
+        {
+            type Error = <#ty as ::check_mate::Check>::Err;
+
+            fn try_from(value: #ty) -> ::core::result::Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+    }
+}
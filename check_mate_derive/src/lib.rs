@@ -0,0 +1,39 @@
+//! Derive macros for [`check_mate`](https://docs.rs/check_mate).
+//!
+//! This crate is re-exported by `check_mate` when its `derive` feature is enabled, and isn't
+//! intended to be used directly.
+
+#![warn(clippy::pedantic)]
+
+mod attr;
+mod builder;
+mod check;
+mod checked;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive `Check` for a struct or enum, from checks declared on its fields.
+///
+/// See the [`check_mate` documentation](https://docs.rs/check_mate/latest/check_mate/#deriving-check)
+/// for the supported attributes.
+#[proc_macro_derive(Check, attributes(check))]
+pub fn derive_check(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    check::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generate a dedicated checked newtype for a type implementing `Check`.
+///
+/// See the [`check_mate` documentation](https://docs.rs/check_mate/latest/check_mate/#checked-newtypes)
+/// for the generated items and supported options.
+#[proc_macro_attribute]
+pub fn checked(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut options = checked::Args::default();
+    let parser = syn::meta::parser(|meta| options.parse(&meta));
+    parse_macro_input!(args with parser);
+    let input = parse_macro_input!(input as DeriveInput);
+    checked::expand(options, &input).into()
+}
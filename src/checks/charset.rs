@@ -0,0 +1,77 @@
+//! Character sets for use with [`OnlyChars`](super::OnlyChars).
+
+/// Character sets, as types.
+///
+/// Custom sets can be declared by implementing this trait:
+///
+/// ```
+/// use check_mate::{checks::{charset::CharSet, OnlyChars}, Checked};
+///
+/// struct Ticker;
+///
+/// impl CharSet for Ticker {
+///     fn contains(c: char) -> bool {
+///         "ABCDEFGHIJKLMNOPQRSTUVWXYZ.".contains(c)
+///     }
+/// }
+///
+/// assert!(Checked::<_, OnlyChars<Ticker>>::refine("BRK.A").is_ok());
+/// ```
+pub trait CharSet {
+    /// Whether `c` is in the set.
+    fn contains(c: char) -> bool;
+}
+
+macro_rules! char_sets {
+    ($($(#[$attr:meta])* $name:ident => $method:ident;)*) => {$(
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl CharSet for $name {
+            fn contains(c: char) -> bool {
+                c.$method()
+            }
+        }
+    )*};
+}
+
+char_sets! {
+    /// Unicode alphabetic and numeric characters.
+    Alphanumeric => is_alphanumeric;
+
+    /// ASCII letters and digits (`A-Z`, `a-z`, `0-9`).
+    AsciiAlphanumeric => is_ascii_alphanumeric;
+
+    /// ASCII letters (`A-Z`, `a-z`).
+    AsciiAlphabetic => is_ascii_alphabetic;
+
+    /// ASCII digits (`0-9`).
+    AsciiDigit => is_ascii_digit;
+
+    /// ASCII hexadecimal digits (`0-9`, `A-F`, `a-f`).
+    AsciiHexDigit => is_ascii_hexdigit;
+}
+
+/// The characters in either `A` or `B`.
+///
+/// ```
+/// use check_mate::{checks::{charset::{AsciiDigit, CharSet, Union}, OnlyChars}, Checked};
+///
+/// struct Dash;
+///
+/// impl CharSet for Dash {
+///     fn contains(c: char) -> bool {
+///         c == '-'
+///     }
+/// }
+///
+/// assert!(Checked::<_, OnlyChars<Union<AsciiDigit, Dash>>>::refine("2024-01-01").is_ok());
+/// ```
+pub struct Union<A, B>(core::marker::PhantomData<fn() -> (A, B)>);
+
+impl<A: CharSet, B: CharSet> CharSet for Union<A, B> {
+    fn contains(c: char) -> bool {
+        A::contains(c) || B::contains(c)
+    }
+}
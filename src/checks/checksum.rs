@@ -0,0 +1,107 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for digit strings with a valid Luhn checksum.
+///
+/// This applies to anything implementing `AsRef<str>`, and is used by e.g. payment card numbers and
+/// IMEIs. The string must be at least two ASCII digits, without separators.
+///
+/// ```
+/// use check_mate::{checks::Luhn, Checked};
+///
+/// assert!(Checked::<_, Luhn>::refine("4111111111111111").is_ok());
+/// assert!(Checked::<_, Luhn>::refine("4111111111111112").is_err());
+/// assert!(Checked::<_, Luhn>::refine("4111 1111 1111 1111").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Luhn;
+
+impl<T: AsRef<str>> Predicate<T> for Luhn {
+    type Err = InvalidLuhn;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let digits = value.as_ref().as_bytes();
+        if let Some(index) = digits.iter().position(|b| !b.is_ascii_digit()) {
+            return Err(InvalidLuhn::NonDigit { index });
+        }
+        if digits.len() < 2 {
+            return Err(InvalidLuhn::TooShort);
+        }
+
+        let sum: u32 = digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, b)| {
+                let digit = u32::from(b - b'0');
+                match (i % 2, digit * 2) {
+                    (0, _) => digit,
+                    (_, doubled) if doubled > 9 => doubled - 9,
+                    (_, doubled) => doubled,
+                }
+            })
+            .sum();
+        if sum.is_multiple_of(10) {
+            Ok(value)
+        } else {
+            Err(InvalidLuhn::Checksum)
+        }
+    }
+}
+
+/// The error returned when a string doesn't have a valid Luhn checksum.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidLuhn {
+    /// The string contained a character that isn't an ASCII digit.
+    NonDigit {
+        /// The byte index of the first non-digit.
+        index: usize,
+    },
+
+    /// The string had fewer than two digits.
+    TooShort,
+
+    /// The checksum was incorrect.
+    Checksum,
+}
+
+impl fmt::Display for InvalidLuhn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonDigit { index } => {
+                write!(f, "must only contain digits, but index {index} isn't")
+            }
+            Self::TooShort => f.write_str("must have at least two digits"),
+            Self::Checksum => f.write_str("must have a valid checksum"),
+        }
+    }
+}
+
+impl core::error::Error for InvalidLuhn {}
+
+impl ErrorCode for InvalidLuhn {
+    fn code(&self) -> &'static str {
+        "invalid_luhn"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidLuhn, Luhn};
+    use crate::Checked;
+
+    fn check(digits: &str) -> Result<&str, InvalidLuhn> {
+        Checked::<_, Luhn>::refine(digits).map(Checked::into_inner)
+    }
+
+    #[test]
+    fn luhn() {
+        assert!(check("79927398713").is_ok());
+        assert!(check("490154203237518").is_ok());
+        assert!(check("00").is_ok());
+        assert_eq!(check("79927398710"), Err(InvalidLuhn::Checksum));
+        assert_eq!(check("0"), Err(InvalidLuhn::TooShort));
+        assert_eq!(check("7992-7398"), Err(InvalidLuhn::NonDigit { index: 4 }));
+    }
+}
@@ -0,0 +1,453 @@
+use core::{fmt, marker::PhantomData, ops::Deref};
+
+use super::Length;
+use crate::{ErrorCode, Implies, Predicate};
+
+/// A predicate that holds for slices whose elements are in non-decreasing order.
+///
+/// This applies to anything that dereferences to a slice, such as `&[T]` and `Vec<T>`. Elements
+/// that can't be compared (e.g. NaN) are treated as out of order.
+///
+/// ```
+/// use check_mate::{checks::Sorted, Checked};
+///
+/// fn contains(haystack: &Checked<&[u32], Sorted>, needle: u32) -> bool {
+///     haystack.binary_search(&needle).is_ok()
+/// }
+///
+/// let haystack = Checked::refine(&[1, 2, 2, 5][..]).unwrap();
+/// assert!(contains(&haystack, 5));
+///
+/// assert!(Checked::<&[u32], Sorted>::refine(&[2, 1]).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Sorted;
+
+/// A predicate that holds for slices whose elements are in non-decreasing order of a
+/// [`SortKey`] `K`.
+///
+/// ```
+/// use check_mate::{checks::{SortKey, SortedBy}, Checked};
+///
+/// struct ByLength;
+///
+/// impl SortKey<&str> for ByLength {
+///     type Key = usize;
+///
+///     fn key(value: &&str) -> usize {
+///         value.len()
+///     }
+/// }
+///
+/// assert!(Checked::<&[&str], SortedBy<ByLength>>::refine(&["b", "a", "ccc"]).is_ok());
+/// ```
+pub struct SortedBy<K>(PhantomData<fn() -> K>);
+
+/// Sort keys, as types.
+///
+/// This is used to supply the key for [`SortedBy`].
+pub trait SortKey<T> {
+    /// The type of the key.
+    type Key: PartialOrd;
+
+    /// Extract the key from a value.
+    fn key(value: &T) -> Self::Key;
+}
+
+impl<T: Deref<Target = [E]>, E: PartialOrd> Predicate<T> for Sorted {
+    type Err = Unsorted;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        Unsorted::check(value, |a, b| a <= b)
+    }
+}
+
+impl<T: Deref<Target = [E]>, E, K: SortKey<E>> Predicate<T> for SortedBy<K> {
+    type Err = Unsorted;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        Unsorted::check(value, |a, b| K::key(a) <= K::key(b))
+    }
+}
+
+/// The error returned when a slice is not sorted.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Unsorted {
+    /// The index of the first element that is out of order with the element before it.
+    pub index: usize,
+}
+
+impl Unsorted {
+    fn check<T: Deref<Target = [E]>, E>(
+        value: T,
+        in_order: impl Fn(&E, &E) -> bool,
+    ) -> Result<T, Self> {
+        match value
+            .windows(2)
+            .position(|pair| !in_order(&pair[0], &pair[1]))
+        {
+            Some(index) => Err(Self { index: index + 1 }),
+            None => Ok(value),
+        }
+    }
+}
+
+impl fmt::Display for Unsorted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be sorted, but element {} is out of order",
+            self.index
+        )
+    }
+}
+
+impl core::error::Error for Unsorted {}
+
+impl ErrorCode for Unsorted {
+    fn code(&self) -> &'static str {
+        "unsorted"
+    }
+}
+
+/// A predicate that holds for slices whose elements are in strictly increasing order.
+///
+/// Like [`Sorted`], this applies to anything that dereferences to a slice, but equal adjacent
+/// elements are also rejected. This is useful for e.g. timestamps or offsets, where a repeated
+/// value is as much a bug as an out-of-order one.
+///
+/// ```
+/// use check_mate::{checks::StrictlyIncreasing, Checked};
+///
+/// assert!(Checked::<&[u64], StrictlyIncreasing>::refine(&[10, 20, 30]).is_ok());
+/// assert_eq!(
+///     Checked::<&[u64], StrictlyIncreasing>::refine(&[10, 20, 20]).unwrap_err().to_string(),
+///     "must be strictly increasing, but element 2 is not greater than element 1",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct StrictlyIncreasing;
+
+impl Implies<Sorted> for StrictlyIncreasing {}
+
+#[cfg(feature = "alloc")]
+impl Implies<Unique> for StrictlyIncreasing {}
+
+#[cfg(feature = "std")]
+impl Implies<UniqueByHash> for StrictlyIncreasing {}
+
+impl<T: Deref<Target = [E]>, E: PartialOrd> Predicate<T> for StrictlyIncreasing {
+    type Err = NotIncreasing;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        Unsorted::check(value, |a, b| a < b).map_err(|Unsorted { index }| NotIncreasing { index })
+    }
+}
+
+/// The error returned when a slice is not strictly increasing.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NotIncreasing {
+    /// The index of the first element that is not greater than the element before it.
+    pub index: usize,
+}
+
+impl fmt::Display for NotIncreasing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be strictly increasing, but element {} is not greater than element {}",
+            self.index,
+            self.index - 1
+        )
+    }
+}
+
+impl core::error::Error for NotIncreasing {}
+
+impl ErrorCode for NotIncreasing {
+    fn code(&self) -> &'static str {
+        "not_increasing"
+    }
+}
+
+/// A predicate that holds for slices without duplicate elements.
+///
+/// This is enabled by the `alloc` feature, and applies to anything that dereferences to a slice of
+/// `Ord` elements. See [`UniqueByHash`] for elements that are `Hash` instead.
+///
+/// ```
+/// use check_mate::{checks::Unique, Checked};
+///
+/// type Tags = Checked<Vec<&'static str>, Unique>;
+///
+/// assert!(Tags::refine(vec!["a", "b"]).is_ok());
+/// assert_eq!(
+///     Tags::refine(vec!["a", "b", "a"]).unwrap_err().to_string(),
+///     "must be unique, but element 2 is a duplicate of element 0",
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct Unique;
+
+/// A predicate that holds for slices without duplicate elements, compared by hash.
+///
+/// This is enabled by the `std` feature, and applies to anything that dereferences to a slice of
+/// `Eq + Hash` elements.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct UniqueByHash;
+
+#[cfg(feature = "alloc")]
+impl<T: Deref<Target = [E]>, E: Ord> Predicate<T> for Unique {
+    type Err = Duplicate;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let mut seen = alloc::collections::BTreeMap::new();
+        for (index, element) in value.iter().enumerate() {
+            if let Some(original) = seen.insert(element, index) {
+                return Err(Duplicate { index, original });
+            }
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Deref<Target = [E]>, E: Eq + core::hash::Hash> Predicate<T> for UniqueByHash {
+    type Err = Duplicate;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let mut seen = std::collections::HashMap::new();
+        for (index, element) in value.iter().enumerate() {
+            if let Some(original) = seen.insert(element, index) {
+                return Err(Duplicate { index, original });
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// The error returned when a slice contains duplicate elements.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Duplicate {
+    /// The index of the first element that duplicates an earlier element.
+    pub index: usize,
+
+    /// The index of the earlier element.
+    pub original: usize,
+}
+
+impl fmt::Display for Duplicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be unique, but element {} is a duplicate of element {}",
+            self.index, self.original
+        )
+    }
+}
+
+impl core::error::Error for Duplicate {}
+
+impl ErrorCode for Duplicate {
+    fn code(&self) -> &'static str {
+        "duplicate"
+    }
+}
+
+/// A predicate that holds for nested slices whose rows all have the same length.
+///
+/// This applies to anything that dereferences to a slice of rows implementing [`Length`], such as
+/// `Vec<Vec<T>>`. If `COLUMNS` is non-zero, every row must also have exactly that length. Empty
+/// slices (with no rows) are always rectangular.
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use check_mate::{checks::Rectangular, Checked};
+///
+/// fn transpose(matrix: &Checked<Vec<Vec<u8>>, Rectangular>) -> Vec<Vec<u8>> {
+///     let columns = matrix.first().map_or(0, Vec::len);
+///     (0..columns).map(|i| matrix.iter().map(|row| row[i]).collect()).collect()
+/// }
+///
+/// let matrix = Checked::refine(vec![vec![1, 2], vec![3, 4]]).unwrap();
+/// assert_eq!(transpose(&matrix), [[1, 3], [2, 4]]);
+///
+/// assert!(Checked::<_, Rectangular>::refine(vec![vec![1, 2], vec![3]]).is_err());
+/// assert!(Checked::<_, Rectangular<3>>::refine(vec![vec![1, 2]]).is_err());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Rectangular<const COLUMNS: usize = 0>;
+
+impl<const COLUMNS: usize> Implies<Rectangular> for Rectangular<COLUMNS> {}
+
+impl<T: Deref<Target = [R]>, R: Length, const COLUMNS: usize> Predicate<T>
+    for Rectangular<COLUMNS>
+{
+    type Err = Ragged;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let expected = match (COLUMNS, value.first()) {
+            (0, None) => return Ok(value),
+            (0, Some(first)) => first.length(),
+            (columns, _) => columns,
+        };
+        for (row, element) in value.iter().enumerate() {
+            let actual = element.length();
+            if actual != expected {
+                return Err(Ragged {
+                    row,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// The error returned when a nested slice is not rectangular.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Ragged {
+    /// The index of the first row with the wrong length.
+    pub row: usize,
+
+    /// The expected row length.
+    pub expected: usize,
+
+    /// The actual row length.
+    pub actual: usize,
+}
+
+impl fmt::Display for Ragged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be rectangular, but row {} has length {} (expected {})",
+            self.row, self.actual, self.expected
+        )
+    }
+}
+
+impl core::error::Error for Ragged {}
+
+impl ErrorCode for Ragged {
+    fn code(&self) -> &'static str {
+        "ragged"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        NotIncreasing, Ragged, Rectangular, SortKey, Sorted, SortedBy, StrictlyIncreasing, Unsorted,
+    };
+    use crate::Checked;
+
+    struct Reverse;
+
+    impl SortKey<i32> for Reverse {
+        type Key = i32;
+
+        fn key(value: &i32) -> i32 {
+            -value
+        }
+    }
+
+    #[test]
+    fn sorted() {
+        assert!(Checked::<&[u8], Sorted>::refine(&[]).is_ok());
+        assert!(Checked::<&[u8], Sorted>::refine(&[1, 1, 2]).is_ok());
+        assert_eq!(
+            Checked::<&[u8], Sorted>::refine(&[1, 3, 2]).map(Checked::into_inner),
+            Err(Unsorted { index: 2 })
+        );
+        assert!(Checked::<&[f64], Sorted>::refine(&[1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn sorted_by() {
+        assert!(Checked::<&[i32], SortedBy<Reverse>>::refine(&[3, 2, 2]).is_ok());
+        assert_eq!(
+            Checked::<&[i32], SortedBy<Reverse>>::refine(&[1, 2])
+                .unwrap_err()
+                .to_string(),
+            "must be sorted, but element 1 is out of order"
+        );
+    }
+
+    #[test]
+    fn strictly_increasing() {
+        assert!(Checked::<&[u8], StrictlyIncreasing>::refine(&[]).is_ok());
+        assert_eq!(
+            Checked::<&[u8], StrictlyIncreasing>::refine(&[1, 1]).map(Checked::into_inner),
+            Err(NotIncreasing { index: 1 })
+        );
+        assert!(Checked::<&[f64], StrictlyIncreasing>::refine(&[0.0, f64::NAN]).is_err());
+
+        let increasing = Checked::<&[u8], StrictlyIncreasing>::refine(&[1, 2]).unwrap();
+        let sorted: Checked<_, Sorted> = increasing.weaken();
+        assert_eq!(*sorted, [1, 2]);
+    }
+
+    #[test]
+    fn rectangular() {
+        let empty: &[&[u8]] = &[];
+        assert!(Checked::<_, Rectangular>::refine(empty).is_ok());
+        assert!(Checked::<_, Rectangular<2>>::refine(empty).is_ok());
+        assert!(Checked::<&[[u8; 2]], Rectangular<2>>::refine(&[[1, 2], [3, 4]]).is_ok());
+        assert_eq!(
+            Checked::<&[&[u8]], Rectangular>::refine(&[&[1, 2], &[3, 4], &[5]])
+                .map(Checked::into_inner),
+            Err(Ragged {
+                row: 2,
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            Checked::<&[&[u8]], Rectangular<3>>::refine(&[&[1, 2]])
+                .unwrap_err()
+                .to_string(),
+            "must be rectangular, but row 0 has length 2 (expected 3)"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sorted_alloc() {
+        assert!(Checked::<Vec<u8>, Sorted>::refine(vec![1, 2]).is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unique() {
+        use super::{Duplicate, Unique};
+
+        assert!(Checked::<Vec<u8>, Unique>::refine(vec![3, 1, 2]).is_ok());
+        assert_eq!(
+            Checked::<&[u8], Unique>::refine(&[1, 2, 2, 1]).map(Checked::into_inner),
+            Err(Duplicate {
+                index: 2,
+                original: 1
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unique_by_hash() {
+        use super::{Duplicate, UniqueByHash};
+
+        assert!(Checked::<&[&str], UniqueByHash>::refine(&["a", "b"]).is_ok());
+        assert_eq!(
+            Checked::<&[&str], UniqueByHash>::refine(&["a", "b", "a"]).map(Checked::into_inner),
+            Err(Duplicate {
+                index: 2,
+                original: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for ISO 3166-1 alpha-2 or alpha-3 country codes.
+///
+/// This applies to anything implementing `AsRef<str>`. Codes must be two or three uppercase ASCII
+/// letters. With the `iso3166` feature enabled, codes must also be officially assigned.
+///
+/// ```
+/// use check_mate::{checks::CountryCode, Checked};
+///
+/// assert!(Checked::<_, CountryCode>::refine("GB").is_ok());
+/// assert!(Checked::<_, CountryCode>::refine("GBR").is_ok());
+/// assert!(Checked::<_, CountryCode>::refine("gb").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CountryCode;
+
+impl<T: AsRef<str>> Predicate<T> for CountryCode {
+    type Err = InvalidCountryCode;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let code = value.as_ref();
+        if !(2..=3).contains(&code.len()) || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(InvalidCountryCode::Format);
+        }
+
+        #[cfg(feature = "iso3166")]
+        if !COUNTRIES
+            .iter()
+            .any(|(alpha2, alpha3)| code == *alpha2 || code == *alpha3)
+        {
+            return Err(InvalidCountryCode::Unassigned);
+        }
+
+        Ok(value)
+    }
+}
+
+/// Officially assigned ISO 3166-1 codes, as (alpha-2, alpha-3) pairs.
+#[cfg(feature = "iso3166")]
+const COUNTRIES: &[(&str, &str)] = &[
+    ("AD", "AND"),
+    ("AE", "ARE"),
+    ("AF", "AFG"),
+    ("AG", "ATG"),
+    ("AI", "AIA"),
+    ("AL", "ALB"),
+    ("AM", "ARM"),
+    ("AO", "AGO"),
+    ("AQ", "ATA"),
+    ("AR", "ARG"),
+    ("AS", "ASM"),
+    ("AT", "AUT"),
+    ("AU", "AUS"),
+    ("AW", "ABW"),
+    ("AX", "ALA"),
+    ("AZ", "AZE"),
+    ("BA", "BIH"),
+    ("BB", "BRB"),
+    ("BD", "BGD"),
+    ("BE", "BEL"),
+    ("BF", "BFA"),
+    ("BG", "BGR"),
+    ("BH", "BHR"),
+    ("BI", "BDI"),
+    ("BJ", "BEN"),
+    ("BL", "BLM"),
+    ("BM", "BMU"),
+    ("BN", "BRN"),
+    ("BO", "BOL"),
+    ("BQ", "BES"),
+    ("BR", "BRA"),
+    ("BS", "BHS"),
+    ("BT", "BTN"),
+    ("BV", "BVT"),
+    ("BW", "BWA"),
+    ("BY", "BLR"),
+    ("BZ", "BLZ"),
+    ("CA", "CAN"),
+    ("CC", "CCK"),
+    ("CD", "COD"),
+    ("CF", "CAF"),
+    ("CG", "COG"),
+    ("CH", "CHE"),
+    ("CI", "CIV"),
+    ("CK", "COK"),
+    ("CL", "CHL"),
+    ("CM", "CMR"),
+    ("CN", "CHN"),
+    ("CO", "COL"),
+    ("CR", "CRI"),
+    ("CU", "CUB"),
+    ("CV", "CPV"),
+    ("CW", "CUW"),
+    ("CX", "CXR"),
+    ("CY", "CYP"),
+    ("CZ", "CZE"),
+    ("DE", "DEU"),
+    ("DJ", "DJI"),
+    ("DK", "DNK"),
+    ("DM", "DMA"),
+    ("DO", "DOM"),
+    ("DZ", "DZA"),
+    ("EC", "ECU"),
+    ("EE", "EST"),
+    ("EG", "EGY"),
+    ("EH", "ESH"),
+    ("ER", "ERI"),
+    ("ES", "ESP"),
+    ("ET", "ETH"),
+    ("FI", "FIN"),
+    ("FJ", "FJI"),
+    ("FK", "FLK"),
+    ("FM", "FSM"),
+    ("FO", "FRO"),
+    ("FR", "FRA"),
+    ("GA", "GAB"),
+    ("GB", "GBR"),
+    ("GD", "GRD"),
+    ("GE", "GEO"),
+    ("GF", "GUF"),
+    ("GG", "GGY"),
+    ("GH", "GHA"),
+    ("GI", "GIB"),
+    ("GL", "GRL"),
+    ("GM", "GMB"),
+    ("GN", "GIN"),
+    ("GP", "GLP"),
+    ("GQ", "GNQ"),
+    ("GR", "GRC"),
+    ("GS", "SGS"),
+    ("GT", "GTM"),
+    ("GU", "GUM"),
+    ("GW", "GNB"),
+    ("GY", "GUY"),
+    ("HK", "HKG"),
+    ("HM", "HMD"),
+    ("HN", "HND"),
+    ("HR", "HRV"),
+    ("HT", "HTI"),
+    ("HU", "HUN"),
+    ("ID", "IDN"),
+    ("IE", "IRL"),
+    ("IL", "ISR"),
+    ("IM", "IMN"),
+    ("IN", "IND"),
+    ("IO", "IOT"),
+    ("IQ", "IRQ"),
+    ("IR", "IRN"),
+    ("IS", "ISL"),
+    ("IT", "ITA"),
+    ("JE", "JEY"),
+    ("JM", "JAM"),
+    ("JO", "JOR"),
+    ("JP", "JPN"),
+    ("KE", "KEN"),
+    ("KG", "KGZ"),
+    ("KH", "KHM"),
+    ("KI", "KIR"),
+    ("KM", "COM"),
+    ("KN", "KNA"),
+    ("KP", "PRK"),
+    ("KR", "KOR"),
+    ("KW", "KWT"),
+    ("KY", "CYM"),
+    ("KZ", "KAZ"),
+    ("LA", "LAO"),
+    ("LB", "LBN"),
+    ("LC", "LCA"),
+    ("LI", "LIE"),
+    ("LK", "LKA"),
+    ("LR", "LBR"),
+    ("LS", "LSO"),
+    ("LT", "LTU"),
+    ("LU", "LUX"),
+    ("LV", "LVA"),
+    ("LY", "LBY"),
+    ("MA", "MAR"),
+    ("MC", "MCO"),
+    ("MD", "MDA"),
+    ("ME", "MNE"),
+    ("MF", "MAF"),
+    ("MG", "MDG"),
+    ("MH", "MHL"),
+    ("MK", "MKD"),
+    ("ML", "MLI"),
+    ("MM", "MMR"),
+    ("MN", "MNG"),
+    ("MO", "MAC"),
+    ("MP", "MNP"),
+    ("MQ", "MTQ"),
+    ("MR", "MRT"),
+    ("MS", "MSR"),
+    ("MT", "MLT"),
+    ("MU", "MUS"),
+    ("MV", "MDV"),
+    ("MW", "MWI"),
+    ("MX", "MEX"),
+    ("MY", "MYS"),
+    ("MZ", "MOZ"),
+    ("NA", "NAM"),
+    ("NC", "NCL"),
+    ("NE", "NER"),
+    ("NF", "NFK"),
+    ("NG", "NGA"),
+    ("NI", "NIC"),
+    ("NL", "NLD"),
+    ("NO", "NOR"),
+    ("NP", "NPL"),
+    ("NR", "NRU"),
+    ("NU", "NIU"),
+    ("NZ", "NZL"),
+    ("OM", "OMN"),
+    ("PA", "PAN"),
+    ("PE", "PER"),
+    ("PF", "PYF"),
+    ("PG", "PNG"),
+    ("PH", "PHL"),
+    ("PK", "PAK"),
+    ("PL", "POL"),
+    ("PM", "SPM"),
+    ("PN", "PCN"),
+    ("PR", "PRI"),
+    ("PS", "PSE"),
+    ("PT", "PRT"),
+    ("PW", "PLW"),
+    ("PY", "PRY"),
+    ("QA", "QAT"),
+    ("RE", "REU"),
+    ("RO", "ROU"),
+    ("RS", "SRB"),
+    ("RU", "RUS"),
+    ("RW", "RWA"),
+    ("SA", "SAU"),
+    ("SB", "SLB"),
+    ("SC", "SYC"),
+    ("SD", "SDN"),
+    ("SE", "SWE"),
+    ("SG", "SGP"),
+    ("SH", "SHN"),
+    ("SI", "SVN"),
+    ("SJ", "SJM"),
+    ("SK", "SVK"),
+    ("SL", "SLE"),
+    ("SM", "SMR"),
+    ("SN", "SEN"),
+    ("SO", "SOM"),
+    ("SR", "SUR"),
+    ("SS", "SSD"),
+    ("ST", "STP"),
+    ("SV", "SLV"),
+    ("SX", "SXM"),
+    ("SY", "SYR"),
+    ("SZ", "SWZ"),
+    ("TC", "TCA"),
+    ("TD", "TCD"),
+    ("TF", "ATF"),
+    ("TG", "TGO"),
+    ("TH", "THA"),
+    ("TJ", "TJK"),
+    ("TK", "TKL"),
+    ("TL", "TLS"),
+    ("TM", "TKM"),
+    ("TN", "TUN"),
+    ("TO", "TON"),
+    ("TR", "TUR"),
+    ("TT", "TTO"),
+    ("TV", "TUV"),
+    ("TW", "TWN"),
+    ("TZ", "TZA"),
+    ("UA", "UKR"),
+    ("UG", "UGA"),
+    ("UM", "UMI"),
+    ("US", "USA"),
+    ("UY", "URY"),
+    ("UZ", "UZB"),
+    ("VA", "VAT"),
+    ("VC", "VCT"),
+    ("VE", "VEN"),
+    ("VG", "VGB"),
+    ("VI", "VIR"),
+    ("VN", "VNM"),
+    ("VU", "VUT"),
+    ("WF", "WLF"),
+    ("WS", "WSM"),
+    ("YE", "YEM"),
+    ("YT", "MYT"),
+    ("ZA", "ZAF"),
+    ("ZM", "ZMB"),
+    ("ZW", "ZWE"),
+];
+
+/// The error returned when a string is not a valid country code.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidCountryCode {
+    /// The code was not two or three uppercase letters.
+    Format,
+
+    /// The code is not officially assigned (only with the `iso3166` feature).
+    Unassigned,
+}
+
+impl fmt::Display for InvalidCountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Format => "must be a two or three letter uppercase country code",
+            Self::Unassigned => "must be an assigned ISO 3166-1 country code",
+        })
+    }
+}
+
+impl core::error::Error for InvalidCountryCode {}
+
+impl ErrorCode for InvalidCountryCode {
+    fn code(&self) -> &'static str {
+        "invalid_country_code"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CountryCode, InvalidCountryCode};
+    use crate::Checked;
+
+    fn check(code: &str) -> Result<&str, InvalidCountryCode> {
+        Checked::<_, CountryCode>::refine(code).map(Checked::into_inner)
+    }
+
+    #[test]
+    fn country_code() {
+        assert!(check("US").is_ok());
+        assert!(check("ZWE").is_ok());
+        assert_eq!(check("U"), Err(InvalidCountryCode::Format));
+        assert_eq!(check("USAX"), Err(InvalidCountryCode::Format));
+        assert_eq!(check("Us"), Err(InvalidCountryCode::Format));
+    }
+
+    #[cfg(feature = "iso3166")]
+    #[test]
+    fn country_code_iso3166() {
+        assert_eq!(super::COUNTRIES.len(), 249);
+        assert_eq!(check("XX"), Err(InvalidCountryCode::Unassigned));
+        assert!(check("UKR").is_ok());
+        assert_eq!(check("UK"), Err(InvalidCountryCode::Unassigned));
+    }
+}
@@ -0,0 +1,201 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// Timestamps that can be compared with the current time.
+///
+/// This is used by the time-based checks, such as [`NotInFuture`]. It's implemented for
+/// [`chrono::DateTime`] with the `chrono` feature, and [`time::OffsetDateTime`] with the `time`
+/// feature.
+pub trait Timestamp {
+    /// The number of nanoseconds from the current time until the timestamp.
+    ///
+    /// This is negative if the timestamp is in the past.
+    fn nanos_from_now(&self) -> i128;
+}
+
+impl<T: Timestamp + ?Sized> Timestamp for &T {
+    fn nanos_from_now(&self) -> i128 {
+        (**self).nanos_from_now()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Timestamp for chrono::DateTime<Tz> {
+    fn nanos_from_now(&self) -> i128 {
+        let delta = self.clone().signed_duration_since(chrono::Utc::now());
+        i128::from(delta.num_seconds()) * 1_000_000_000 + i128::from(delta.subsec_nanos())
+    }
+}
+
+#[cfg(feature = "time")]
+impl Timestamp for time::OffsetDateTime {
+    fn nanos_from_now(&self) -> i128 {
+        (*self - time::OffsetDateTime::now_utc()).whole_nanoseconds()
+    }
+}
+
+/// A predicate that holds for timestamps that are not in the future.
+///
+/// This applies to anything implementing [`Timestamp`], and is useful for e.g. rejecting tokens
+/// issued in the future.
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use check_mate::{checks::NotInFuture, Checked};
+///
+/// let issued_at = chrono::Utc::now() - chrono::Duration::minutes(1);
+/// assert!(Checked::<_, NotInFuture>::refine(issued_at).is_ok());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NotInFuture;
+
+/// A predicate that holds for timestamps that are not in the past.
+///
+/// This applies to anything implementing [`Timestamp`], and is useful for e.g. rejecting expired
+/// tokens.
+#[derive(Clone, Copy, Debug)]
+pub struct NotInPast;
+
+/// A predicate that holds for timestamps within `SECONDS` seconds of the current time, in either
+/// direction.
+///
+/// This applies to anything implementing [`Timestamp`], and is useful for e.g. checking signed
+/// request timestamps while allowing for clock skew.
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// use check_mate::{checks::Within, Checked};
+///
+/// type RequestTime = Checked<chrono::DateTime<chrono::Utc>, Within<300>>;
+///
+/// assert!(RequestTime::refine(chrono::Utc::now()).is_ok());
+/// assert!(RequestTime::refine(chrono::Utc::now() + chrono::Duration::hours(1)).is_err());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Within<const SECONDS: u64>;
+
+impl<T: Timestamp> Predicate<T> for NotInFuture {
+    type Err = InvalidTime;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        if value.nanos_from_now() > 0 {
+            Err(InvalidTime::InFuture)
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl<T: Timestamp> Predicate<T> for NotInPast {
+    type Err = InvalidTime;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        if value.nanos_from_now() < 0 {
+            Err(InvalidTime::InPast)
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl<T: Timestamp, const SECONDS: u64> Predicate<T> for Within<SECONDS> {
+    type Err = InvalidTime;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        if value.nanos_from_now().unsigned_abs() > u128::from(SECONDS) * 1_000_000_000 {
+            Err(InvalidTime::OutsideWindow { seconds: SECONDS })
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+/// The error returned when a timestamp is outside of the allowed range.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidTime {
+    /// The timestamp was in the future (for [`NotInFuture`]).
+    InFuture,
+
+    /// The timestamp was in the past (for [`NotInPast`]).
+    InPast,
+
+    /// The timestamp was too far from the current time (for [`Within`]).
+    OutsideWindow {
+        /// The allowed number of seconds from the current time.
+        seconds: u64,
+    },
+}
+
+impl fmt::Display for InvalidTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InFuture => f.write_str("must not be in the future"),
+            Self::InPast => f.write_str("must not be in the past"),
+            Self::OutsideWindow { seconds } => {
+                write!(f, "must be within {seconds} seconds of the current time")
+            }
+        }
+    }
+}
+
+impl core::error::Error for InvalidTime {}
+
+impl ErrorCode for InvalidTime {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InFuture => "in_future",
+            Self::InPast => "in_past",
+            Self::OutsideWindow { .. } => "outside_window",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidTime, NotInFuture, NotInPast, Within};
+    use crate::Checked;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use chrono::{Duration, Utc};
+
+        let past = Utc::now() - Duration::seconds(10);
+        let future = Utc::now() + Duration::seconds(10);
+
+        assert!(Checked::<_, NotInFuture>::refine(past).is_ok());
+        assert_eq!(
+            Checked::<_, NotInFuture>::refine(future).map(Checked::into_inner),
+            Err(InvalidTime::InFuture)
+        );
+        assert!(Checked::<_, NotInPast>::refine(future).is_ok());
+        assert!(Checked::<_, NotInPast>::refine(past).is_err());
+        assert!(Checked::<_, Within<60>>::refine(past).is_ok());
+        assert_eq!(
+            Checked::<_, Within<5>>::refine(&future)
+                .unwrap_err()
+                .to_string(),
+            "must be within 5 seconds of the current time"
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        use time::{Duration, OffsetDateTime};
+
+        let past = OffsetDateTime::now_utc() - Duration::seconds(10);
+        let future = OffsetDateTime::now_utc() + Duration::seconds(10);
+
+        assert!(Checked::<_, NotInFuture>::refine(past).is_ok());
+        assert!(Checked::<_, NotInFuture>::refine(future).is_err());
+        assert!(Checked::<_, NotInPast>::refine(future).is_ok());
+        assert_eq!(
+            Checked::<_, Within<5>>::refine(past).map(Checked::into_inner),
+            Err(InvalidTime::OutsideWindow { seconds: 5 })
+        );
+    }
+}
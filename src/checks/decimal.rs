@@ -0,0 +1,139 @@
+use core::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for decimals with at most `S` digits after the decimal point.
+///
+/// This is enabled by the `rust_decimal` feature. Trailing zeros count towards the scale, so
+/// values may need to be [normalized](Decimal::normalize) first.
+///
+/// ```
+/// use check_mate::{checks::{Precision, Scale}, refine::And, Checked};
+/// use rust_decimal::Decimal;
+///
+/// // Fits in a `NUMERIC(10, 2)` column.
+/// type Amount = Checked<Decimal, And<Precision<10>, Scale<2>>>;
+///
+/// assert!(Amount::refine(Decimal::new(1999, 2)).is_ok());
+/// assert!(Amount::refine(Decimal::new(19999, 3)).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Scale<const S: u32>;
+
+/// A predicate that holds for decimals with at most `P` significant digits.
+///
+/// This is enabled by the `rust_decimal` feature. The precision is counted as in SQL `NUMERIC`
+/// columns, so it's the number of digits in the mantissa, but at least the
+/// [scale](Decimal::scale) (e.g. `0.05` has a precision of 2).
+#[derive(Clone, Copy, Debug)]
+pub struct Precision<const P: u32>;
+
+impl<const S: u32> Predicate<Decimal> for Scale<S> {
+    type Err = InvalidDecimal;
+
+    fn refine(value: Decimal) -> Result<Decimal, Self::Err> {
+        let actual = value.scale();
+        if actual > S {
+            Err(InvalidDecimal::Scale { max: S, actual })
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl<const P: u32> Predicate<Decimal> for Precision<P> {
+    type Err = InvalidDecimal;
+
+    fn refine(value: Decimal) -> Result<Decimal, Self::Err> {
+        let mantissa = value.mantissa().unsigned_abs();
+        let digits = mantissa.checked_ilog10().map_or(1, |log| log + 1);
+        let actual = digits.max(value.scale());
+        if actual > P {
+            Err(InvalidDecimal::Precision { max: P, actual })
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+/// The error returned when a decimal has too many digits.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidDecimal {
+    /// The decimal had too many digits after the decimal point.
+    Scale {
+        /// The maximum allowed scale.
+        max: u32,
+        /// The actual scale.
+        actual: u32,
+    },
+
+    /// The decimal had too many significant digits.
+    Precision {
+        /// The maximum allowed precision.
+        max: u32,
+        /// The actual precision.
+        actual: u32,
+    },
+}
+
+impl fmt::Display for InvalidDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Scale { max, actual } => write!(
+                f,
+                "must have at most {max} decimal places, but had {actual}"
+            ),
+            Self::Precision { max, actual } => write!(
+                f,
+                "must have at most {max} significant digits, but had {actual}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for InvalidDecimal {}
+
+impl ErrorCode for InvalidDecimal {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Scale { .. } => "excess_scale",
+            Self::Precision { .. } => "excess_precision",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{InvalidDecimal, Precision, Scale};
+    use crate::Checked;
+
+    #[test]
+    fn scale() {
+        assert!(Checked::<_, Scale<2>>::refine(Decimal::new(-123, 2)).is_ok());
+        assert!(Checked::<_, Scale<0>>::refine(Decimal::new(100, 0)).is_ok());
+        assert_eq!(
+            Checked::<_, Scale<2>>::refine(Decimal::new(1230, 3)).map(Checked::into_inner),
+            Err(InvalidDecimal::Scale { max: 2, actual: 3 })
+        );
+    }
+
+    #[test]
+    fn precision() {
+        assert!(Checked::<_, Precision<3>>::refine(Decimal::new(-999, 1)).is_ok());
+        assert!(Checked::<_, Precision<1>>::refine(Decimal::ZERO).is_ok());
+        assert_eq!(
+            Checked::<_, Precision<1>>::refine(Decimal::new(5, 2)).map(Checked::into_inner),
+            Err(InvalidDecimal::Precision { max: 1, actual: 2 })
+        );
+        assert_eq!(
+            Checked::<_, Precision<3>>::refine(Decimal::new(1000, 0))
+                .unwrap_err()
+                .to_string(),
+            "must have at most 3 significant digits, but had 4"
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use core::fmt;
+
+use crate::{Checked, DynCheck, ErrorCode};
+
+/// Digest algorithms, as types.
+///
+/// This is used to supply the algorithm for [`DigestMatches`]. Implementations are provided for
+/// [`Crc32`] (with the `crc32fast` feature) and [`Sha256`] (with the `sha2` feature).
+pub trait Digest {
+    /// The type of the digest.
+    type Output: AsRef<[u8]> + Eq;
+
+    /// Compute the digest of `bytes`.
+    fn digest(bytes: &[u8]) -> Self::Output;
+}
+
+/// The CRC-32 (IEEE) checksum, as big-endian bytes.
+///
+/// This is enabled by the `crc32fast` feature. Note that CRC-32 only detects accidental corruption,
+/// and offers no protection against deliberate tampering.
+#[cfg(feature = "crc32fast")]
+#[derive(Clone, Copy, Debug)]
+pub struct Crc32;
+
+#[cfg(feature = "crc32fast")]
+impl Digest for Crc32 {
+    type Output = [u8; 4];
+
+    fn digest(bytes: &[u8]) -> Self::Output {
+        crc32fast::hash(bytes).to_be_bytes()
+    }
+}
+
+/// The SHA-256 hash.
+///
+/// This is enabled by the `sha2` feature.
+#[cfg(feature = "sha2")]
+#[derive(Clone, Copy, Debug)]
+pub struct Sha256;
+
+#[cfg(feature = "sha2")]
+impl Digest for Sha256 {
+    type Output = [u8; 32];
+
+    fn digest(bytes: &[u8]) -> Self::Output {
+        <sha2::Sha256 as sha2::Digest>::digest(bytes).into()
+    }
+}
+
+/// A check that a payload has an expected digest.
+///
+/// Since the expected digest is only known at runtime, this is a [`DynCheck`] for anything
+/// implementing `AsRef<[u8]>`, and verified payloads can be obtained as a
+/// `Checked<T, DigestMatches<D>>` with [`refine`](Self::refine).
+///
+/// ```
+/// # #[cfg(feature = "sha2")] {
+/// use check_mate::{checks::{DigestMatches, Sha256}, Checked};
+///
+/// let expected = [
+///     0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22,
+///     0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00,
+///     0x15, 0xad,
+/// ];
+/// let check = DigestMatches::<Sha256>::new(expected);
+///
+/// let blob: Checked<Vec<u8>, DigestMatches<Sha256>> = check.refine(b"abc".to_vec()).unwrap();
+/// assert!(check.refine(b"abd".to_vec()).is_err());
+/// # }
+/// ```
+pub struct DigestMatches<D: Digest> {
+    expected: D::Output,
+}
+
+impl<D: Digest> DigestMatches<D> {
+    /// Construct a check for the given expected digest.
+    pub fn new(expected: D::Output) -> Self {
+        Self { expected }
+    }
+
+    /// The expected digest.
+    #[must_use]
+    pub fn expected(&self) -> &D::Output {
+        &self.expected
+    }
+
+    /// Check that `value` has the expected digest.
+    ///
+    /// # Errors
+    ///
+    /// This will return a [`DigestMismatch`] with the expected and actual digests if they differ.
+    pub fn refine<T: AsRef<[u8]>>(
+        &self,
+        value: T,
+    ) -> Result<Checked<T, Self>, DigestMismatch<D::Output>>
+    where
+        D::Output: Clone,
+    {
+        self.check_dyn(&value)?;
+        Ok(Checked::new_unchecked(value))
+    }
+}
+
+impl<D: Digest> fmt::Debug for DigestMatches<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DigestMatches")
+            .field("expected", &Hex(self.expected.as_ref()))
+            .finish()
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized, D: Digest> DynCheck<T> for DigestMatches<D>
+where
+    D::Output: Clone,
+{
+    type Err = DigestMismatch<D::Output>;
+
+    fn check_dyn(&self, value: &T) -> Result<(), Self::Err> {
+        let actual = D::digest(value.as_ref());
+        if actual == self.expected {
+            Ok(())
+        } else {
+            Err(DigestMismatch {
+                expected: self.expected.clone(),
+                actual,
+            })
+        }
+    }
+}
+
+/// The error returned when a payload doesn't have the expected digest.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DigestMismatch<O> {
+    /// The expected digest.
+    pub expected: O,
+
+    /// The actual digest.
+    pub actual: O,
+}
+
+impl<O: AsRef<[u8]>> fmt::Display for DigestMismatch<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must have digest {}, but had digest {}",
+            Hex(self.expected.as_ref()),
+            Hex(self.actual.as_ref())
+        )
+    }
+}
+
+impl<O: AsRef<[u8]> + fmt::Debug> core::error::Error for DigestMismatch<O> {}
+
+impl<O> ErrorCode for DigestMismatch<O> {
+    fn code(&self) -> &'static str {
+        "digest_mismatch"
+    }
+}
+
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl fmt::Debug for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Digest, DigestMatches, DigestMismatch};
+    use crate::{Checked, DynCheck};
+
+    struct Sum;
+
+    impl Digest for Sum {
+        type Output = [u8; 1];
+
+        fn digest(bytes: &[u8]) -> Self::Output {
+            [bytes.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))]
+        }
+    }
+
+    #[test]
+    fn digest_matches() {
+        let check = DigestMatches::<Sum>::new([6]);
+        assert!(check.refine(&[1, 2, 3][..]).is_ok());
+        assert!(Checked::try_from_dyn([3, 3], &check).is_ok());
+        assert_eq!(
+            check.check_dyn(&[1, 2][..]),
+            Err(DigestMismatch {
+                expected: [6],
+                actual: [3]
+            })
+        );
+        assert_eq!(
+            check.refine([0xff, 0x10]).unwrap_err().to_string(),
+            "must have digest 06, but had digest 0f"
+        );
+    }
+
+    #[cfg(feature = "crc32fast")]
+    #[test]
+    fn crc32() {
+        let check = DigestMatches::<super::Crc32>::new([0xcb, 0xf4, 0x39, 0x26]);
+        assert!(check.refine("123456789").is_ok());
+        assert!(check.refine("12345678").is_err());
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn sha256() {
+        let check = DigestMatches::<super::Sha256>::new(<super::Sha256 as Digest>::digest(b""));
+        assert_eq!(
+            format!("{check:?}"),
+            "DigestMatches { expected: \
+             e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855 }"
+        );
+        assert!(check.refine(b"").is_ok());
+    }
+}
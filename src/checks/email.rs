@@ -0,0 +1,134 @@
+use core::fmt;
+
+use super::net::is_dns_label;
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for plausible email addresses.
+///
+/// This is enabled by the `email` feature, and applies to anything implementing `AsRef<str>`.
+/// Validation is deliberately pragmatic, rather than following RFC 5322 to the letter:
+///
+/// - There must be exactly one `@`.
+/// - The local part must be 1 to 64 characters of ASCII letters, digits, and
+///   ``!#$%&'*+-/=?^_`{|}~``, optionally separated by single dots. Quoted local parts aren't
+///   supported.
+/// - The domain must be a hostname with at least two labels, each of 1 to 63 ASCII letters, digits
+///   and hyphens, and not starting or ending with a hyphen. IP address literals aren't supported.
+/// - The whole address must be at most 254 characters.
+///
+/// ```
+/// use check_mate::{checks::Email, Checked};
+///
+/// assert!(Checked::<_, Email>::refine("ferris@rust-lang.org").is_ok());
+/// assert!(Checked::<_, Email>::refine("ferris@localhost").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Email;
+
+impl<T: AsRef<str>> Predicate<T> for Email {
+    type Err = InvalidEmail;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let address = value.as_ref();
+        if address.len() > 254 {
+            return Err(InvalidEmail::TooLong);
+        }
+
+        let (local, domain) = match address.split_once('@') {
+            Some((local, domain)) if !domain.contains('@') => (local, domain),
+            _ => return Err(InvalidEmail::MissingAt),
+        };
+
+        let valid_local = local.len() <= 64
+            && local.split('.').all(|atom| {
+                !atom.is_empty()
+                    && atom
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-/=?^_`{|}~".contains(&b))
+            });
+        if !valid_local {
+            return Err(InvalidEmail::LocalPart);
+        }
+
+        if !domain.contains('.') || !domain.split('.').all(is_dns_label) {
+            return Err(InvalidEmail::Domain);
+        }
+
+        Ok(value)
+    }
+}
+
+/// The error returned when a string is not a valid email address.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidEmail {
+    /// The address did not contain exactly one `@`.
+    MissingAt,
+
+    /// The local part (before the `@`) was invalid.
+    LocalPart,
+
+    /// The domain (after the `@`) was invalid.
+    Domain,
+
+    /// The address was longer than 254 characters.
+    TooLong,
+}
+
+impl fmt::Display for InvalidEmail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingAt => "must be an email address with a single `@`",
+            Self::LocalPart => "must be an email address with a valid local part",
+            Self::Domain => "must be an email address with a valid domain",
+            Self::TooLong => "must be an email address of at most 254 characters",
+        })
+    }
+}
+
+impl core::error::Error for InvalidEmail {}
+
+impl ErrorCode for InvalidEmail {
+    fn code(&self) -> &'static str {
+        "invalid_email"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Email, InvalidEmail};
+    use crate::Checked;
+
+    fn check(address: &str) -> Result<&str, InvalidEmail> {
+        Checked::<_, Email>::refine(address).map(Checked::into_inner)
+    }
+
+    #[test]
+    fn email() {
+        assert!(check("a@b.co").is_ok());
+        assert!(check("first.last+tag@mail.example.com").is_ok());
+
+        assert_eq!(check("example.com"), Err(InvalidEmail::MissingAt));
+        assert_eq!(check("a@b@c.com"), Err(InvalidEmail::MissingAt));
+        assert_eq!(check("@example.com"), Err(InvalidEmail::LocalPart));
+        assert_eq!(check("a..b@example.com"), Err(InvalidEmail::LocalPart));
+        assert_eq!(check("a b@example.com"), Err(InvalidEmail::LocalPart));
+        assert_eq!(check("a@example"), Err(InvalidEmail::Domain));
+        assert_eq!(check("a@-example.com"), Err(InvalidEmail::Domain));
+        assert_eq!(check("a@example..com"), Err(InvalidEmail::Domain));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize() {
+        let email: Checked<String, Email> = serde_json::from_str(r#""a@b.co""#).unwrap();
+        assert_eq!(*email, "a@b.co");
+
+        let error = serde_json::from_str::<Checked<String, Email>>(r#""a@b""#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid value for Checked<String, Email>: must be an email address with a valid \
+             domain"
+        );
+    }
+}
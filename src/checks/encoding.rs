@@ -0,0 +1,173 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Check, ErrorCode};
+
+/// A check that a string is valid base64, producing the decoded bytes.
+///
+/// This is enabled by the `alloc` feature, and wraps anything implementing `AsRef<str>`. The
+/// standard alphabet (RFC 4648 §4) is used, and padding is required.
+///
+/// ```
+/// use check_mate::{checks::Base64, Checked};
+///
+/// let bytes: Checked<Vec<u8>> = Checked::try_from(Base64("aGk=")).unwrap();
+/// assert_eq!(*bytes, b"hi");
+///
+/// assert!(Checked::try_from(Base64("aGk")).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Base64<S>(pub S);
+
+/// A check that a string is valid hexadecimal, producing the decoded bytes.
+///
+/// This is enabled by the `alloc` feature, and wraps anything implementing `AsRef<str>`. Digits may
+/// be either case, and there must be an even number of them.
+///
+/// ```
+/// use check_mate::{checks::Hex, Checked};
+///
+/// let bytes: Checked<Vec<u8>> = Checked::try_from(Hex("c0FFee")).unwrap();
+/// assert_eq!(*bytes, [0xc0, 0xff, 0xee]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Hex<S>(pub S);
+
+impl<S: AsRef<str>> Check for Base64<S> {
+    type Ok = Vec<u8>;
+    type Err = DecodeError;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        let encoded = self.0.as_ref().as_bytes();
+        if encoded.len() % 4 != 0 {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        let padding = encoded
+            .iter()
+            .rev()
+            .take(2)
+            .take_while(|b| **b == b'=')
+            .count();
+        let mut decoded = Vec::with_capacity(encoded.len() / 4 * 3);
+        let (mut buffer, mut bits) = (0_u32, 0);
+        for (index, byte) in encoded[..encoded.len() - padding].iter().enumerate() {
+            let value = match byte {
+                b'A'..=b'Z' => byte - b'A',
+                b'a'..=b'z' => byte - b'a' + 26,
+                b'0'..=b'9' => byte - b'0' + 52,
+                b'+' => 62,
+                b'/' => 63,
+                _ => return Err(DecodeError::InvalidCharacter { index }),
+            };
+            buffer = buffer << 6 | u32::from(value);
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                decoded.push((buffer >> bits).to_le_bytes()[0]);
+                buffer &= (1 << bits) - 1;
+            }
+        }
+
+        Ok(decoded)
+    }
+}
+
+impl<S: AsRef<str>> Check for Hex<S> {
+    type Ok = Vec<u8>;
+    type Err = DecodeError;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        let encoded = self.0.as_ref().as_bytes();
+        if encoded.len() % 2 != 0 {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        let digit = |index: usize| match encoded[index] {
+            byte @ b'0'..=b'9' => Ok(byte - b'0'),
+            byte @ b'a'..=b'f' => Ok(byte - b'a' + 10),
+            byte @ b'A'..=b'F' => Ok(byte - b'A' + 10),
+            _ => Err(DecodeError::InvalidCharacter { index }),
+        };
+        (0..encoded.len())
+            .step_by(2)
+            .map(|index| Ok(digit(index)? << 4 | digit(index + 1)?))
+            .collect()
+    }
+}
+
+/// The error returned when a string can't be decoded.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DecodeError {
+    /// The string had an invalid length for the encoding.
+    InvalidLength,
+
+    /// The string contained a character that's not valid for the encoding.
+    InvalidCharacter {
+        /// The byte index of the first invalid character.
+        index: usize,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => f.write_str("has an invalid length for the encoding"),
+            Self::InvalidCharacter { index } => {
+                write!(f, "has an invalid character at index {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+impl ErrorCode for DecodeError {
+    fn code(&self) -> &'static str {
+        "invalid_encoding"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Base64, DecodeError, Hex};
+    use crate::Checked;
+
+    fn decode(encoded: &str) -> Result<Vec<u8>, DecodeError> {
+        Checked::try_from(Base64(encoded)).map(Checked::into_inner)
+    }
+
+    #[test]
+    fn base64() {
+        assert_eq!(decode(""), Ok(vec![]));
+        assert_eq!(decode("Zg=="), Ok(b"f".to_vec()));
+        assert_eq!(decode("Zm8="), Ok(b"fo".to_vec()));
+        assert_eq!(decode("Zm9v"), Ok(b"foo".to_vec()));
+        assert_eq!(decode("+/+/"), Ok(vec![0xfb, 0xff, 0xbf]));
+        assert_eq!(decode("Zm9"), Err(DecodeError::InvalidLength));
+        assert_eq!(
+            decode("Zm=v"),
+            Err(DecodeError::InvalidCharacter { index: 2 })
+        );
+        assert_eq!(
+            decode("Zm9-"),
+            Err(DecodeError::InvalidCharacter { index: 3 })
+        );
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(
+            Checked::try_from(Hex("00fF")).map(Checked::into_inner),
+            Ok(vec![0x00, 0xff])
+        );
+        assert_eq!(
+            Checked::try_from(Hex("abc")).unwrap_err(),
+            DecodeError::InvalidLength
+        );
+        assert_eq!(
+            Checked::try_from(Hex("0g")).unwrap_err(),
+            DecodeError::InvalidCharacter { index: 1 }
+        );
+    }
+}
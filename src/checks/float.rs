@@ -0,0 +1,237 @@
+use core::{cmp::Ordering, fmt};
+
+use super::OutOfRange;
+use crate::{Checked, ErrorCode, Implies, Predicate};
+
+/// A predicate that holds for floats that are neither infinite nor NaN.
+///
+/// Since NaN is excluded, `Checked<f32, Finite>` and `Checked<f64, Finite>` have a total order,
+/// available through [`total_cmp`](Checked::total_cmp), [`max`](Checked::max) and
+/// [`min`](Checked::min).
+///
+/// ```
+/// use check_mate::{checks::Finite, Checked};
+///
+/// let mut values = [2.5, -1.0, 0.0].map(|v| Checked::<f64, Finite>::refine(v).unwrap());
+/// values.sort_by(|a, b| a.total_cmp(b));
+/// assert_eq!(*values[0], -1.0);
+///
+/// assert!(Checked::<f64, Finite>::refine(f64::INFINITY).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Finite;
+
+/// A predicate that holds for floats that are not NaN.
+///
+/// Like [`Finite`], checked values have a total order.
+#[derive(Clone, Copy, Debug)]
+pub struct NotNan;
+
+/// A predicate that holds for probabilities (`0.0..=1.0`).
+///
+/// NaN is rejected, so like [`Finite`], checked values have a total order.
+///
+/// ```
+/// use check_mate::{checks::Probability, Checked};
+///
+/// assert!(Checked::<f64, Probability>::refine(0.25).is_ok());
+/// assert!(Checked::<f64, Probability>::refine(1.5).is_err());
+/// assert!(Checked::<f64, Probability>::refine(f64::NAN).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Probability;
+
+impl Implies<NotNan> for Finite {}
+impl Implies<Finite> for Probability {}
+impl Implies<NotNan> for Probability {}
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {$(
+        impl Predicate<$ty> for Finite {
+            type Err = NotFinite<$ty>;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                if value.is_finite() {
+                    Ok(value)
+                } else {
+                    Err(NotFinite { actual: value })
+                }
+            }
+        }
+
+        impl Predicate<$ty> for NotNan {
+            type Err = Nan;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                if value.is_nan() {
+                    Err(Nan)
+                } else {
+                    Ok(value)
+                }
+            }
+        }
+
+        impl Predicate<$ty> for Probability {
+            type Err = OutOfRange<$ty>;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                if (0.0..=1.0).contains(&value) {
+                    Ok(value)
+                } else {
+                    Err(OutOfRange { actual: value, min: 0.0, max: 1.0, exclusive: false })
+                }
+            }
+        }
+
+        impl_float_ord!($ty, Finite);
+        impl_float_ord!($ty, NotNan);
+        impl_float_ord!($ty, Probability);
+    )*};
+}
+
+macro_rules! impl_float_ord {
+    ($ty:ty, $predicate:ty) => {
+        impl Checked<$ty, $predicate> {
+            /// Compare two checked floats.
+            ///
+            /// Since the values can't be NaN, this is a total order (with `-0.0 < 0.0`), so it can be
+            /// used with e.g. [`slice::sort_by`].
+            #[must_use]
+            pub fn total_cmp(&self, other: &Self) -> Ordering {
+                (**self).total_cmp(other)
+            }
+
+            /// Return the larger of two checked floats.
+            #[must_use]
+            pub fn max(self, other: Self) -> Self {
+                if self.total_cmp(&other) == Ordering::Less {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            /// Return the smaller of two checked floats.
+            #[must_use]
+            pub fn min(self, other: Self) -> Self {
+                if self.total_cmp(&other) == Ordering::Greater {
+                    other
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+
+impl_float!(f32, f64);
+
+/// The error returned when a float is infinite or NaN.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NotFinite<F> {
+    /// The value that was checked.
+    pub actual: F,
+}
+
+impl<F: fmt::Display> fmt::Display for NotFinite<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "must be finite, but was {}", self.actual)
+    }
+}
+
+impl<F: fmt::Debug + fmt::Display> core::error::Error for NotFinite<F> {}
+
+impl<F> ErrorCode for NotFinite<F> {
+    fn code(&self) -> &'static str {
+        "not_finite"
+    }
+}
+
+/// The error returned when a float is NaN.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Nan;
+
+impl fmt::Display for Nan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must not be NaN")
+    }
+}
+
+impl core::error::Error for Nan {}
+
+impl ErrorCode for Nan {
+    fn code(&self) -> &'static str {
+        "nan"
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{Finite, Nan, NotFinite, NotNan, Probability};
+    use crate::checks::OutOfRange;
+    use crate::Checked;
+
+    #[test]
+    fn finite() {
+        assert_eq!(Checked::<f64, Finite>::refine(1.5).as_deref(), Ok(&1.5));
+        assert_eq!(
+            Checked::<f32, Finite>::refine(f32::NEG_INFINITY).map(Checked::into_inner),
+            Err(NotFinite {
+                actual: f32::NEG_INFINITY
+            })
+        );
+        assert_eq!(
+            Checked::<f64, Finite>::refine(f64::NAN)
+                .unwrap_err()
+                .to_string(),
+            "must be finite, but was NaN"
+        );
+    }
+
+    #[test]
+    fn not_nan() {
+        assert!(Checked::<f64, NotNan>::refine(f64::INFINITY).is_ok());
+        assert_eq!(
+            Checked::<f32, NotNan>::refine(f32::NAN).map(Checked::into_inner),
+            Err(Nan)
+        );
+
+        let finite = Checked::<f64, Finite>::refine(2.0).unwrap();
+        let not_nan: Checked<f64, NotNan> = finite.weaken();
+        assert_eq!(*not_nan, 2.0);
+    }
+
+    #[test]
+    fn ord() {
+        let a = Checked::<f64, NotNan>::refine(-0.0).unwrap();
+        let b = Checked::<f64, NotNan>::refine(f64::INFINITY).unwrap();
+        assert_eq!(a.total_cmp(&b), core::cmp::Ordering::Less);
+        assert_eq!(*a.clone().max(b.clone()), f64::INFINITY);
+        assert_eq!(*a.min(b), -0.0);
+
+        let mut values = [3.0, -1.0, 2.0].map(|v| Checked::<f32, Finite>::refine(v).unwrap());
+        values.sort_by(Checked::<f32, Finite>::total_cmp);
+        assert_eq!(values.map(Checked::into_inner), [-1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn probability() {
+        assert!(Checked::<f32, Probability>::refine(0.0).is_ok());
+        assert!(Checked::<f32, Probability>::refine(1.0).is_ok());
+        assert_eq!(
+            Checked::<f64, Probability>::refine(-0.1).map(Checked::into_inner),
+            Err(OutOfRange {
+                actual: -0.1,
+                min: 0.0,
+                max: 1.0,
+                exclusive: false
+            })
+        );
+        assert!(Checked::<f64, Probability>::refine(f64::NAN).is_err());
+
+        let p = Checked::<f64, Probability>::refine(0.5).unwrap();
+        let finite: Checked<f64, Finite> = p.weaken();
+        assert_eq!(*finite, 0.5);
+    }
+}
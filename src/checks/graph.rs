@@ -0,0 +1,323 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// Directed graphs, as adjacency lists.
+///
+/// This is used by [`Acyclic`]. It's implemented for slices and `Vec`s of successor lists, where
+/// nodes are identified by their index (e.g. `Vec<Vec<usize>>`), and for `BTreeMap`s (and, with
+/// the `std` feature, `HashMap`s) from each node to its successors.
+pub trait Graph {
+    /// The type that identifies nodes.
+    type Node: Clone;
+
+    /// The successors of each node, by index.
+    ///
+    /// # Errors
+    ///
+    /// This should return [`InvalidGraph::DanglingEdge`] if an edge refers to a node that isn't in
+    /// the graph.
+    fn adjacency(&self) -> Result<Vec<Vec<usize>>, InvalidGraph<Self::Node>>;
+
+    /// The node with the given index in [`adjacency`](Self::adjacency).
+    fn node(&self, index: usize) -> Self::Node;
+}
+
+impl<G: Graph + ?Sized> Graph for &G {
+    type Node = G::Node;
+
+    fn adjacency(&self) -> Result<Vec<Vec<usize>>, InvalidGraph<Self::Node>> {
+        (**self).adjacency()
+    }
+
+    fn node(&self, index: usize) -> Self::Node {
+        (**self).node(index)
+    }
+}
+
+impl<R: AsRef<[usize]>> Graph for [R] {
+    type Node = usize;
+
+    fn adjacency(&self) -> Result<Vec<Vec<usize>>, InvalidGraph<Self::Node>> {
+        self.iter()
+            .enumerate()
+            .map(|(from, successors)| {
+                let successors = successors.as_ref();
+                match successors.iter().find(|to| **to >= self.len()) {
+                    Some(to) => Err(InvalidGraph::DanglingEdge { from, to: *to }),
+                    None => Ok(successors.to_vec()),
+                }
+            })
+            .collect()
+    }
+
+    fn node(&self, index: usize) -> Self::Node {
+        index
+    }
+}
+
+impl<R: AsRef<[usize]>> Graph for Vec<R> {
+    type Node = usize;
+
+    fn adjacency(&self) -> Result<Vec<Vec<usize>>, InvalidGraph<Self::Node>> {
+        self.as_slice().adjacency()
+    }
+
+    fn node(&self, index: usize) -> Self::Node {
+        index
+    }
+}
+
+impl<K: Ord + Clone, R: AsRef<[K]>> Graph for BTreeMap<K, R> {
+    type Node = K;
+
+    fn adjacency(&self) -> Result<Vec<Vec<usize>>, InvalidGraph<Self::Node>> {
+        let indices: BTreeMap<&K, usize> = self.keys().zip(0..).collect();
+        self.iter()
+            .map(|(from, successors)| {
+                successors
+                    .as_ref()
+                    .iter()
+                    .map(|to| {
+                        indices
+                            .get(to)
+                            .copied()
+                            .ok_or_else(|| InvalidGraph::DanglingEdge {
+                                from: from.clone(),
+                                to: to.clone(),
+                            })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn node(&self, index: usize) -> Self::Node {
+        self.keys().nth(index).expect("index in bounds").clone()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, R, S> Graph for std::collections::HashMap<K, R, S>
+where
+    K: Eq + core::hash::Hash + Clone,
+    R: AsRef<[K]>,
+    S: core::hash::BuildHasher,
+{
+    type Node = K;
+
+    fn adjacency(&self) -> Result<Vec<Vec<usize>>, InvalidGraph<Self::Node>> {
+        let indices: std::collections::HashMap<&K, usize> = self.keys().zip(0..).collect();
+        self.iter()
+            .map(|(from, successors)| {
+                successors
+                    .as_ref()
+                    .iter()
+                    .map(|to| {
+                        indices
+                            .get(to)
+                            .copied()
+                            .ok_or_else(|| InvalidGraph::DanglingEdge {
+                                from: from.clone(),
+                                to: to.clone(),
+                            })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn node(&self, index: usize) -> Self::Node {
+        self.keys().nth(index).expect("index in bounds").clone()
+    }
+}
+
+/// A predicate that holds for directed graphs without cycles.
+///
+/// This is enabled by the `alloc` feature, and applies to anything implementing [`Graph`]. Every
+/// edge must refer to a node in the graph, so for maps every successor must also be a key. When a
+/// graph has a cycle, one is reported in the error.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use check_mate::{checks::Acyclic, Checked};
+///
+/// let tasks = BTreeMap::from([
+///     ("build", vec!["fetch"]),
+///     ("fetch", vec![]),
+///     ("test", vec!["build"]),
+/// ]);
+/// assert!(Checked::<_, Acyclic>::refine(tasks).is_ok());
+///
+/// let graph = vec![vec![1], vec![2], vec![0]];
+/// assert_eq!(
+///     Checked::<_, Acyclic>::refine(graph).unwrap_err().to_string(),
+///     "must be acyclic, but contains the cycle [0, 1, 2]",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Acyclic;
+
+impl<G: Graph> Predicate<G> for Acyclic {
+    type Err = InvalidGraph<G::Node>;
+
+    fn refine(value: G) -> Result<G, Self::Err> {
+        match find_cycle(&value.adjacency()?) {
+            Some(cycle) => Err(InvalidGraph::Cycle {
+                cycle: cycle.into_iter().map(|index| value.node(index)).collect(),
+            }),
+            None => Ok(value),
+        }
+    }
+}
+
+/// Find a cycle with an iterative depth-first search.
+fn find_cycle(adjacency: &[Vec<usize>]) -> Option<Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        Unvisited,
+        OnPath,
+        Done,
+    }
+
+    let mut states = vec![State::Unvisited; adjacency.len()];
+    for root in 0..adjacency.len() {
+        if states[root] != State::Unvisited {
+            continue;
+        }
+        states[root] = State::OnPath;
+
+        // Each entry is a node on the current path, and the index of its next edge to follow.
+        let mut path = vec![(root, 0)];
+        while let Some((node, edge)) = path.last_mut() {
+            let Some(&next) = adjacency[*node].get(*edge) else {
+                states[*node] = State::Done;
+                path.pop();
+                continue;
+            };
+            *edge += 1;
+            match states[next] {
+                State::Unvisited => {
+                    states[next] = State::OnPath;
+                    path.push((next, 0));
+                }
+                State::OnPath => {
+                    let start = path.iter().position(|(node, _)| *node == next)?;
+                    return Some(path[start..].iter().map(|(node, _)| *node).collect());
+                }
+                State::Done => {}
+            }
+        }
+    }
+    None
+}
+
+/// The error returned when a graph is not a valid DAG.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidGraph<N> {
+    /// The graph contained a cycle.
+    Cycle {
+        /// The nodes in the cycle, in order. Each node has an edge to the next, and the last node
+        /// has an edge to the first.
+        cycle: Vec<N>,
+    },
+
+    /// An edge referred to a node that isn't in the graph.
+    DanglingEdge {
+        /// The node the edge is from.
+        from: N,
+
+        /// The missing node the edge is to.
+        to: N,
+    },
+}
+
+impl<N: fmt::Debug> fmt::Display for InvalidGraph<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cycle { cycle } => write!(f, "must be acyclic, but contains the cycle {cycle:?}"),
+            Self::DanglingEdge { from, to } => {
+                write!(
+                    f,
+                    "must only have edges between nodes, but {from:?} has an edge to {to:?}"
+                )
+            }
+        }
+    }
+}
+
+impl<N: fmt::Debug> core::error::Error for InvalidGraph<N> {}
+
+impl<N> ErrorCode for InvalidGraph<N> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Cycle { .. } => "cyclic",
+            Self::DanglingEdge { .. } => "dangling_edge",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{Acyclic, InvalidGraph};
+    use crate::Checked;
+
+    #[test]
+    fn acyclic() {
+        assert!(Checked::<Vec<Vec<usize>>, Acyclic>::refine(vec![]).is_ok());
+        assert!(Checked::<_, Acyclic>::refine(vec![vec![1, 2], vec![2], vec![]]).is_ok());
+        assert_eq!(
+            Checked::<_, Acyclic>::refine(vec![vec![], vec![1]]).map(Checked::into_inner),
+            Err(InvalidGraph::Cycle { cycle: vec![1] })
+        );
+        assert_eq!(
+            Checked::<&[&[usize]], Acyclic>::refine(&[&[1], &[2, 3], &[], &[4], &[1]])
+                .map(Checked::into_inner),
+            Err(InvalidGraph::Cycle {
+                cycle: vec![1, 3, 4]
+            })
+        );
+        assert_eq!(
+            Checked::<_, Acyclic>::refine(vec![vec![0, 2]]).map(Checked::into_inner),
+            Err(InvalidGraph::DanglingEdge { from: 0, to: 2 })
+        );
+    }
+
+    #[test]
+    fn map() {
+        let graph = BTreeMap::from([("a", vec!["b"]), ("b", vec!["c"]), ("c", vec!["a"])]);
+        assert_eq!(
+            Checked::<_, Acyclic>::refine(&graph).map(Checked::into_inner),
+            Err(InvalidGraph::Cycle {
+                cycle: vec!["a", "b", "c"]
+            })
+        );
+
+        let graph = BTreeMap::from([("a", ["b"])]);
+        assert_eq!(
+            Checked::<_, Acyclic>::refine(graph)
+                .unwrap_err()
+                .to_string(),
+            "must only have edges between nodes, but \"a\" has an edge to \"b\""
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_map() {
+        use std::collections::HashMap;
+
+        let graph = HashMap::from([(1, vec![2, 3]), (2, vec![3]), (3, vec![])]);
+        assert!(Checked::<_, Acyclic>::refine(graph).is_ok());
+
+        let graph = HashMap::from([(1, vec![2]), (2, vec![2])]);
+        assert_eq!(
+            Checked::<_, Acyclic>::refine(graph).map(Checked::into_inner),
+            Err(InvalidGraph::Cycle { cycle: vec![2] })
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use core::fmt;
+
+use crate::{ErrorCode, Implies, Predicate};
+
+/// A predicate that holds for ASCII identifiers.
+///
+/// This applies to anything implementing `AsRef<str>`. An identifier is an ASCII letter or `_`,
+/// followed by any number of ASCII letters, digits and `_`s. This is the common subset of most
+/// programming languages' identifier rules; see [`CIdentifier`] and [`RustIdentifier`] to also
+/// reject keywords.
+///
+/// ```
+/// use check_mate::{checks::Identifier, Checked};
+///
+/// assert!(Checked::<_, Identifier>::refine("_user_id2").is_ok());
+/// assert_eq!(
+///     Checked::<_, Identifier>::refine("2fast").unwrap_err().to_string(),
+///     "must be an identifier, but found '2' at index 0",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Identifier;
+
+/// A predicate that holds for C identifiers.
+///
+/// Like [`Identifier`], but keywords (as of C23) are also rejected.
+#[derive(Clone, Copy, Debug)]
+pub struct CIdentifier;
+
+/// A predicate that holds for Rust identifiers.
+///
+/// Like [`Identifier`], but strict and reserved keywords (as of the 2024 edition) are also
+/// rejected, as is `_` on its own. Raw identifiers (e.g. `r#type`) and non-ASCII identifiers are
+/// not supported.
+///
+/// ```
+/// use check_mate::{checks::RustIdentifier, Checked};
+///
+/// assert!(Checked::<_, RustIdentifier>::refine("kind").is_ok());
+/// assert_eq!(
+///     Checked::<_, RustIdentifier>::refine("type").unwrap_err().to_string(),
+///     "must be an identifier, but \"type\" is a keyword",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RustIdentifier;
+
+impl Implies<Identifier> for CIdentifier {}
+
+impl Implies<Identifier> for RustIdentifier {}
+
+impl Implies<super::Ascii> for Identifier {}
+
+impl Implies<super::NonEmpty> for Identifier {}
+
+const C_KEYWORDS: &[&str] = &[
+    "_Alignas",
+    "_Alignof",
+    "_Atomic",
+    "_BitInt",
+    "_Bool",
+    "_Complex",
+    "_Decimal128",
+    "_Decimal32",
+    "_Decimal64",
+    "_Generic",
+    "_Imaginary",
+    "_Noreturn",
+    "_Static_assert",
+    "_Thread_local",
+    "alignas",
+    "alignof",
+    "auto",
+    "bool",
+    "break",
+    "case",
+    "char",
+    "const",
+    "constexpr",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "nullptr",
+    "register",
+    "restrict",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "static_assert",
+    "struct",
+    "switch",
+    "thread_local",
+    "true",
+    "typedef",
+    "typeof",
+    "typeof_unqual",
+    "union",
+    "unsigned",
+    "void",
+    "volatile",
+    "while",
+];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "Self", "_", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn check<T: AsRef<str>>(value: T, keywords: &[&'static str]) -> Result<T, InvalidIdentifier> {
+    let string = value.as_ref();
+    let mut chars = string.char_indices();
+    match chars.next() {
+        None => return Err(InvalidIdentifier::Empty),
+        Some((_, c)) if c.is_ascii_alphabetic() || c == '_' => {}
+        Some((index, found)) => return Err(InvalidIdentifier::IllegalChar { index, found }),
+    }
+    if let Some((index, found)) = chars.find(|(_, c)| !(c.is_ascii_alphanumeric() || *c == '_')) {
+        return Err(InvalidIdentifier::IllegalChar { index, found });
+    }
+    match keywords.binary_search(&string) {
+        Ok(index) => Err(InvalidIdentifier::Keyword {
+            keyword: keywords[index],
+        }),
+        Err(_) => Ok(value),
+    }
+}
+
+impl<T: AsRef<str>> Predicate<T> for Identifier {
+    type Err = InvalidIdentifier;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        check(value, &[])
+    }
+}
+
+impl<T: AsRef<str>> Predicate<T> for CIdentifier {
+    type Err = InvalidIdentifier;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        check(value, C_KEYWORDS)
+    }
+}
+
+impl<T: AsRef<str>> Predicate<T> for RustIdentifier {
+    type Err = InvalidIdentifier;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        check(value, RUST_KEYWORDS)
+    }
+}
+
+/// The error returned when a string is not a valid identifier.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidIdentifier {
+    /// The string was empty.
+    Empty,
+
+    /// The string contained a character that isn't allowed (at that position).
+    IllegalChar {
+        /// The byte index of the character.
+        index: usize,
+
+        /// The character.
+        found: char,
+    },
+
+    /// The string was a keyword.
+    Keyword {
+        /// The keyword.
+        keyword: &'static str,
+    },
+}
+
+impl fmt::Display for InvalidIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("must be an identifier, but was empty"),
+            Self::IllegalChar { index, found } => {
+                write!(
+                    f,
+                    "must be an identifier, but found {found:?} at index {index}"
+                )
+            }
+            Self::Keyword { keyword } => {
+                write!(f, "must be an identifier, but {keyword:?} is a keyword")
+            }
+        }
+    }
+}
+
+impl core::error::Error for InvalidIdentifier {}
+
+impl ErrorCode for InvalidIdentifier {
+    fn code(&self) -> &'static str {
+        "invalid_identifier"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CIdentifier, Identifier, InvalidIdentifier, RustIdentifier, C_KEYWORDS, RUST_KEYWORDS,
+    };
+    use crate::Checked;
+
+    #[test]
+    fn keywords_sorted() {
+        assert!(C_KEYWORDS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(RUST_KEYWORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn identifier() {
+        assert!(Checked::<_, Identifier>::refine("_").is_ok());
+        assert!(Checked::<_, Identifier>::refine("type").is_ok());
+        assert_eq!(
+            Checked::<_, Identifier>::refine("").map(Checked::into_inner),
+            Err(InvalidIdentifier::Empty)
+        );
+        assert_eq!(
+            Checked::<_, Identifier>::refine("a-b").map(Checked::into_inner),
+            Err(InvalidIdentifier::IllegalChar {
+                index: 1,
+                found: '-'
+            })
+        );
+        assert!(Checked::<_, Identifier>::refine("café").is_err());
+    }
+
+    #[test]
+    fn keywords() {
+        assert!(Checked::<_, CIdentifier>::refine("fn").is_ok());
+        assert_eq!(
+            Checked::<_, CIdentifier>::refine("_Bool").map(Checked::into_inner),
+            Err(InvalidIdentifier::Keyword { keyword: "_Bool" })
+        );
+        assert!(Checked::<_, RustIdentifier>::refine("int").is_ok());
+        assert!(Checked::<_, RustIdentifier>::refine("_").is_err());
+        assert!(Checked::<_, RustIdentifier>::refine("Self").is_err());
+        assert!(Checked::<_, RustIdentifier>::refine("self_").is_ok());
+    }
+}
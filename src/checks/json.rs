@@ -0,0 +1,91 @@
+use core::fmt;
+
+use crate::{Checked, ErrorCode, Predicate};
+
+/// A predicate that holds for strings containing a single, well-formed JSON document.
+///
+/// This is enabled by the `serde_json` feature, and applies to anything implementing
+/// `AsRef<str>`. Checked values can be converted into a [`serde_json::Value`].
+///
+/// ```
+/// use check_mate::{checks::Json, Checked};
+///
+/// let document = Checked::<_, Json>::refine(r#"{"enabled": true}"#).unwrap();
+/// let value = serde_json::Value::from(document);
+/// assert_eq!(value["enabled"], true);
+///
+/// assert_eq!(
+///     Checked::<_, Json>::refine("[1, 2,]").unwrap_err().to_string(),
+///     "must be valid JSON, but found an error at line 1, column 7",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Json;
+
+impl<T: AsRef<str>> Predicate<T> for Json {
+    type Err = InvalidJson;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        match serde_json::from_str::<serde_json::Value>(value.as_ref()) {
+            Ok(_) => Ok(value),
+            Err(error) => Err(InvalidJson {
+                line: error.line(),
+                column: error.column(),
+            }),
+        }
+    }
+}
+
+impl<T: AsRef<str>> From<Checked<T, Json>> for serde_json::Value {
+    fn from(value: Checked<T, Json>) -> Self {
+        serde_json::from_str(value.into_inner().as_ref()).expect("checked to be valid JSON")
+    }
+}
+
+/// The error returned when a string is not valid JSON.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidJson {
+    /// The line of the error (starting at 1).
+    pub line: usize,
+
+    /// The column of the error (starting at 1).
+    pub column: usize,
+}
+
+impl fmt::Display for InvalidJson {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be valid JSON, but found an error at line {}, column {}",
+            self.line, self.column
+        )
+    }
+}
+
+impl core::error::Error for InvalidJson {}
+
+impl ErrorCode for InvalidJson {
+    fn code(&self) -> &'static str {
+        "invalid_json"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidJson, Json};
+    use crate::Checked;
+
+    #[test]
+    fn json() {
+        assert!(Checked::<_, Json>::refine("null").is_ok());
+        assert!(Checked::<_, Json>::refine(String::from(" [1, 2] ")).is_ok());
+        assert_eq!(
+            Checked::<_, Json>::refine("[1] [2]").map(Checked::into_inner),
+            Err(InvalidJson { line: 1, column: 5 })
+        );
+        assert!(Checked::<_, Json>::refine("").is_err());
+
+        let value = serde_json::Value::from(Checked::<_, Json>::refine("[1]").unwrap());
+        assert_eq!(value, serde_json::json!([1]));
+    }
+}
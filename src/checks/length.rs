@@ -0,0 +1,415 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// Values with a length.
+///
+/// This is used by the length-based checks, such as [`NonEmpty`]. Strings are measured in `char`s,
+/// and collections in elements.
+pub trait Length {
+    /// The length of the value.
+    fn length(&self) -> usize;
+
+    /// Whether the value is empty.
+    ///
+    /// By default this checks whether [`length`](Self::length) is `0`, but implementations may be
+    /// able to do this more efficiently.
+    fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+}
+
+impl<L: Length + ?Sized> Length for &L {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+impl Length for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
+impl<T> Length for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> Length for [T; N] {
+    fn length(&self) -> usize {
+        N
+    }
+}
+
+macro_rules! impl_length {
+    ($(#[$attr:meta])* $($ty:ident)::+ $(<$($param:ident),*>)?) => {
+        $(#[$attr])*
+        impl$(<$($param),*>)? Length for $($ty)::+$(<$($param),*>)? {
+            fn length(&self) -> usize {
+                self.len()
+            }
+
+            fn is_empty(&self) -> bool {
+                $($ty)::+::is_empty(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl Length for alloc::string::String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_str().is_empty()
+    }
+}
+
+impl_length!(#[cfg(feature = "alloc")] alloc::vec::Vec<T>);
+impl_length!(#[cfg(feature = "alloc")] alloc::collections::VecDeque<T>);
+impl_length!(#[cfg(feature = "alloc")] alloc::collections::BTreeSet<T>);
+impl_length!(#[cfg(feature = "alloc")] alloc::collections::BTreeMap<K, V>);
+impl_length!(#[cfg(feature = "std")] std::collections::HashSet<T, S>);
+impl_length!(#[cfg(feature = "std")] std::collections::HashMap<K, V, S>);
+
+/// A predicate that holds for values that are not empty.
+///
+/// This applies to strings, slices, arrays, and collections (anything implementing [`Length`]).
+#[derive(Clone, Copy, Debug)]
+pub struct NonEmpty;
+
+impl<T: Length> Predicate<T> for NonEmpty {
+    type Err = Empty;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        if value.is_empty() {
+            Err(Empty)
+        } else {
+            Ok(value)
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    fn constrain_schema(schema: &mut schemars::Schema) {
+        crate::schema::length(schema, Some(1), None);
+    }
+}
+
+/// The error returned when a value is empty.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Empty;
+
+impl fmt::Display for Empty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must not be empty")
+    }
+}
+
+impl core::error::Error for Empty {}
+
+impl ErrorCode for Empty {
+    fn code(&self) -> &'static str {
+        "empty"
+    }
+}
+
+/// A predicate that holds for values with a length of at least `N`.
+///
+/// Like [`NonEmpty`], this applies to anything implementing [`Length`], so strings are measured in
+/// `char`s.
+#[derive(Clone, Copy, Debug)]
+pub struct MinLen<const N: usize>;
+
+/// A predicate that holds for values with a length of at most `N`.
+///
+/// For strings, this limits the number of `char`s. Use [`MaxBytes`] to limit the size of the
+/// encoded string instead (e.g. to fit a database column).
+#[derive(Clone, Copy, Debug)]
+pub struct MaxLen<const N: usize>;
+
+/// A predicate that holds for values with a length between `MIN` and `MAX` (inclusive).
+///
+/// ```
+/// use check_mate::{checks::LenBetween, Checked};
+///
+/// type Username<'a> = Checked<&'a str, LenBetween<3, 32>>;
+///
+/// assert!(Username::refine("ferris").is_ok());
+/// assert_eq!(
+///     Username::refine("fe").unwrap_err().to_string(),
+///     "must have length between 3 and 32, but had length 2",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LenBetween<const MIN: usize, const MAX: usize>;
+
+impl<T: Length, const N: usize> Predicate<T> for MinLen<N> {
+    type Err = InvalidLength;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        InvalidLength::check(value, Some(N), None)
+    }
+
+    #[cfg(feature = "schemars")]
+    fn constrain_schema(schema: &mut schemars::Schema) {
+        crate::schema::length(schema, Some(N), None);
+    }
+}
+
+impl<T: Length, const N: usize> Predicate<T> for MaxLen<N> {
+    type Err = InvalidLength;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        InvalidLength::check(value, None, Some(N))
+    }
+
+    #[cfg(feature = "schemars")]
+    fn constrain_schema(schema: &mut schemars::Schema) {
+        crate::schema::length(schema, None, Some(N));
+    }
+}
+
+impl<T: Length, const MIN: usize, const MAX: usize> Predicate<T> for LenBetween<MIN, MAX> {
+    type Err = InvalidLength;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        InvalidLength::check(value, Some(MIN), Some(MAX))
+    }
+
+    #[cfg(feature = "schemars")]
+    fn constrain_schema(schema: &mut schemars::Schema) {
+        crate::schema::length(schema, Some(MIN), Some(MAX));
+    }
+}
+
+/// The error returned when a value's length is outside of the allowed range.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidLength {
+    /// The actual length.
+    pub actual: usize,
+
+    /// The minimum allowed length, if there is one.
+    pub min: Option<usize>,
+
+    /// The maximum allowed length, if there is one.
+    pub max: Option<usize>,
+}
+
+impl InvalidLength {
+    pub(crate) fn check<T: Length>(
+        value: T,
+        min: Option<usize>,
+        max: Option<usize>,
+    ) -> Result<T, Self> {
+        let actual = value.length();
+        if min.is_some_and(|min| actual < min) || max.is_some_and(|max| actual > max) {
+            Err(Self { actual, min, max })
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl fmt::Display for InvalidLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual = self.actual;
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => write!(f, "must have length between {min} and {max}")?,
+            (Some(min), None) => write!(f, "must have length at least {min}")?,
+            (None, Some(max)) => write!(f, "must have length at most {max}")?,
+            (None, None) => f.write_str("has an invalid length")?,
+        }
+        write!(f, ", but had length {actual}")
+    }
+}
+
+impl core::error::Error for InvalidLength {}
+
+impl ErrorCode for InvalidLength {
+    fn code(&self) -> &'static str {
+        if self.min.is_some_and(|min| self.actual < min) {
+            "too_short"
+        } else {
+            "too_long"
+        }
+    }
+}
+
+/// A predicate that holds for strings and byte slices of at most `N` bytes.
+///
+/// This applies to anything implementing `AsRef<[u8]>`, such as `&str`, `String`, `&[u8]` and
+/// `Vec<u8>`. Unlike [`MaxLen`], strings are measured in (UTF-8) bytes rather than `char`s.
+///
+/// ```
+/// use check_mate::{checks::MaxBytes, Checked};
+///
+/// assert!(Checked::<_, MaxBytes<4>>::refine("abcd").is_ok());
+/// assert_eq!(
+///     Checked::<_, MaxBytes<4>>::refine("abcé").unwrap_err().to_string(),
+///     "must be at most 4 bytes, but was 5 bytes (1 too many)",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MaxBytes<const N: usize>;
+
+/// A predicate that holds for byte buffers of at most `N` bytes.
+///
+/// This is an alias of [`MaxBytes`], for buffers such as `Vec<u8>` or `bytes::Bytes` (anything
+/// implementing `AsRef<[u8]>`), e.g. to reject oversized network frames.
+///
+/// ```
+/// use check_mate::{checks::MaxSize, Checked};
+///
+/// const MAX_FRAME: usize = 16 * 1024;
+///
+/// type Frame = Checked<Vec<u8>, MaxSize<MAX_FRAME>>;
+///
+/// assert!(Frame::refine(vec![0; MAX_FRAME]).is_ok());
+/// assert!(Frame::refine(vec![0; MAX_FRAME + 1]).is_err());
+/// ```
+pub type MaxSize<const N: usize> = MaxBytes<N>;
+
+impl<T: AsRef<[u8]>, const N: usize> Predicate<T> for MaxBytes<N> {
+    type Err = TooManyBytes;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let actual = value.as_ref().len();
+        if actual > N {
+            Err(TooManyBytes { actual, max: N })
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+/// The error returned when a value has more bytes than allowed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TooManyBytes {
+    /// The actual number of bytes.
+    pub actual: usize,
+
+    /// The maximum allowed number of bytes.
+    pub max: usize,
+}
+
+impl fmt::Display for TooManyBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be at most {} bytes, but was {} bytes ({} too many)",
+            self.max,
+            self.actual,
+            self.actual - self.max
+        )
+    }
+}
+
+impl core::error::Error for TooManyBytes {}
+
+impl ErrorCode for TooManyBytes {
+    fn code(&self) -> &'static str {
+        "too_many_bytes"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Empty, InvalidLength, LenBetween, MaxBytes, MaxLen, MinLen, NonEmpty, TooManyBytes,
+    };
+    use crate::{Checked, ErrorCode};
+
+    #[test]
+    fn non_empty() {
+        assert_eq!(Checked::<_, NonEmpty>::refine("a").as_deref(), Ok(&"a"));
+        assert_eq!(Checked::<_, NonEmpty>::refine("").as_deref(), Err(&Empty));
+
+        assert!(Checked::<_, NonEmpty>::refine(&[1][..]).is_ok());
+        assert!(Checked::<&[u8], NonEmpty>::refine(&[]).is_err());
+        assert!(Checked::<_, NonEmpty>::refine([0; 0]).is_err());
+    }
+
+    #[test]
+    fn bounded_length() {
+        assert!(Checked::<_, MinLen<2>>::refine("ab").is_ok());
+        assert!(Checked::<_, MaxLen<2>>::refine(&[1, 2, 3][..]).is_err());
+
+        let error = Checked::<_, LenBetween<3, 4>>::refine("héllo")
+            .map(Checked::into_inner)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            InvalidLength {
+                actual: 5,
+                min: Some(3),
+                max: Some(4)
+            }
+        );
+        assert_eq!(error.code(), "too_long");
+
+        let error = Checked::<_, MinLen<1>>::refine("").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "must have length at least 1, but had length 0"
+        );
+        assert_eq!(error.code(), "too_short");
+    }
+
+    #[test]
+    fn max_bytes() {
+        assert!(Checked::<_, MaxBytes<0>>::refine("").is_ok());
+        assert!(Checked::<_, MaxLen<2>>::refine("éé").is_ok());
+        assert_eq!(
+            Checked::<_, MaxBytes<2>>::refine("éé").map(Checked::into_inner),
+            Err(TooManyBytes { actual: 4, max: 2 })
+        );
+        assert!(Checked::<_, MaxBytes<2>>::refine(&[1, 2, 3][..]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn non_empty_alloc() {
+        use std::collections::BTreeMap;
+
+        assert!(Checked::<_, NonEmpty>::refine(String::from("a")).is_ok());
+        assert!(Checked::<_, NonEmpty>::refine(String::new()).is_err());
+        assert!(Checked::<_, NonEmpty>::refine(vec![1]).is_ok());
+        assert!(Checked::<Vec<u8>, NonEmpty>::refine(vec![]).is_err());
+        assert!(Checked::<BTreeMap<u8, u8>, NonEmpty>::refine(BTreeMap::new()).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bounded_length_string() {
+        assert!(Checked::<String, MaxLen<3>>::refine("ééé".into()).is_ok());
+        assert_eq!(
+            Checked::<String, MinLen<4>>::refine("ééé".into()).map(Checked::into_inner),
+            Err(InvalidLength {
+                actual: 3,
+                min: Some(4),
+                max: None
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn non_empty_std() {
+        use std::collections::HashSet;
+
+        assert!(Checked::<_, NonEmpty>::refine(HashSet::from([1])).is_ok());
+        assert!(Checked::<HashSet<u8>, NonEmpty>::refine(HashSet::new()).is_err());
+    }
+}
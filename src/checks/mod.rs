@@ -0,0 +1,113 @@
+//! Built-in checks for common invariants.
+//!
+//! The checks in this module are [`Predicate`](crate::Predicate)s, so they're used as the second
+//! type parameter of [`Checked`](crate::Checked):
+//!
+//! ```
+//! use check_mate::{checks::NonEmpty, Checked};
+//!
+//! let name: Checked<&str, NonEmpty> = Checked::refine("ferris").unwrap();
+//! assert!(Checked::<&str, NonEmpty>::refine("").is_err());
+//! ```
+//!
+//! Checks that parse or decode their input, such as [`Utf8`], are instead wrappers implementing
+//! [`Check`](crate::Check), so that the checked value is the parsed form.
+
+pub mod charset;
+mod checksum;
+mod collection;
+mod country;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod datetime;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+mod digest;
+#[cfg(feature = "email")]
+mod email;
+#[cfg(feature = "alloc")]
+mod encoding;
+mod float;
+#[cfg(feature = "alloc")]
+mod graph;
+mod identifier;
+#[cfg(feature = "serde_json")]
+mod json;
+mod length;
+mod net;
+mod numeric;
+mod parse;
+#[cfg(feature = "alloc")]
+mod password;
+mod path;
+#[cfg(feature = "regex")]
+mod pattern;
+mod phone;
+mod pointer;
+mod sign;
+mod text;
+#[cfg(feature = "unicode-normalization")]
+mod unicode;
+mod uuid;
+mod version;
+
+pub use self::uuid::{InvalidUuid, Uuid};
+pub use checksum::{InvalidLuhn, Luhn};
+#[cfg(feature = "alloc")]
+pub use collection::Unique;
+#[cfg(feature = "std")]
+pub use collection::UniqueByHash;
+pub use collection::{
+    Duplicate, NotIncreasing, Ragged, Rectangular, SortKey, Sorted, SortedBy, StrictlyIncreasing,
+    Unsorted,
+};
+pub use country::{CountryCode, InvalidCountryCode};
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use datetime::{InvalidTime, NotInFuture, NotInPast, Timestamp, Within};
+#[cfg(feature = "rust_decimal")]
+pub use decimal::{InvalidDecimal, Precision, Scale};
+#[cfg(feature = "crc32fast")]
+pub use digest::Crc32;
+#[cfg(feature = "sha2")]
+pub use digest::Sha256;
+pub use digest::{Digest, DigestMatches, DigestMismatch};
+#[cfg(feature = "email")]
+pub use email::{Email, InvalidEmail};
+#[cfg(feature = "alloc")]
+pub use encoding::{Base64, DecodeError, Hex};
+pub use float::{Finite, Nan, NotFinite, NotNan, Probability};
+#[cfg(feature = "alloc")]
+pub use graph::{Acyclic, Graph, InvalidGraph};
+pub use identifier::{CIdentifier, Identifier, InvalidIdentifier, RustIdentifier};
+#[cfg(feature = "serde_json")]
+pub use json::{InvalidJson, Json};
+pub use length::{
+    Empty, InvalidLength, LenBetween, Length, MaxBytes, MaxLen, MaxSize, MinLen, NonEmpty,
+    TooManyBytes,
+};
+pub use net::{
+    Cidr, DnsLabel, Hostname, InvalidCidr, InvalidHostname, InvalidMacAddr, IpAddr, Ipv4, Ipv6,
+    MacAddr,
+};
+pub use numeric::{
+    Aligned, Bounded, BoundedExclusive, BoundedU, Misaligned, MultipleOf, NonZero, NotMultipleOf,
+    NotPowerOfTwo, OutOfRange, Percent, Port, PowerOfTwo, Zero,
+};
+#[cfg(feature = "url")]
+pub use parse::Url;
+pub use parse::Utf8;
+#[cfg(feature = "alloc")]
+pub use password::{CharClass, PasswordError, PasswordPolicy, Requirement};
+pub use path::{Filename, InvalidFilename, SafeRelativePath, UnsafePath};
+#[cfg(feature = "regex")]
+pub use pattern::{Matches, Mismatch, Pattern};
+pub use phone::{InvalidPhoneNumber, PhoneNumber};
+pub use pointer::{InvalidJsonPointer, JsonPointer};
+pub use sign::{Negative, NonNegative, Positive, SignError};
+pub use text::{
+    Ascii, AsciiLowercase, AsciiPrintable, AsciiUppercase, Blank, IllegalChar, InvalidSlug,
+    Lowercase, NoControlChars, NonAscii, NonBlank, OnlyChars, Slug, Trimmed, Untrimmed, Uppercase,
+    WrongCase,
+};
+#[cfg(feature = "unicode-normalization")]
+pub use unicode::{Nfc, NotNfc};
+pub use version::{InvalidSemver, Semver};
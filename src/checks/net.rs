@@ -0,0 +1,380 @@
+use core::fmt;
+
+use crate::{Check, ErrorCode, Implies, Predicate};
+
+/// A predicate that holds for RFC 1123 hostnames.
+///
+/// This applies to anything implementing `AsRef<str>`. A hostname is one or more [`DnsLabel`]s
+/// separated by dots, with an optional trailing dot, and is at most 253 characters (excluding the
+/// trailing dot).
+///
+/// ```
+/// use check_mate::{checks::Hostname, Checked};
+///
+/// assert!(Checked::<_, Hostname>::refine("db-1.internal.example.com").is_ok());
+/// assert!(Checked::<_, Hostname>::refine("db_1.internal").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Hostname;
+
+/// A predicate that holds for a single DNS label.
+///
+/// This applies to anything implementing `AsRef<str>`. A label is 1 to 63 ASCII letters, digits and
+/// hyphens, and must not start or end with a hyphen.
+#[derive(Clone, Copy, Debug)]
+pub struct DnsLabel;
+
+impl Implies<Hostname> for DnsLabel {}
+
+impl<T: AsRef<str>> Predicate<T> for Hostname {
+    type Err = InvalidHostname;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let hostname = value.as_ref();
+        let hostname = hostname.strip_suffix('.').unwrap_or(hostname);
+        if hostname.len() > 253 {
+            return Err(InvalidHostname::TooLong);
+        }
+        match hostname.split('.').position(|label| !is_dns_label(label)) {
+            Some(label) => Err(InvalidHostname::Label { label }),
+            None => Ok(value),
+        }
+    }
+}
+
+impl<T: AsRef<str>> Predicate<T> for DnsLabel {
+    type Err = InvalidHostname;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        if is_dns_label(value.as_ref()) {
+            Ok(value)
+        } else {
+            Err(InvalidHostname::Label { label: 0 })
+        }
+    }
+}
+
+pub(super) fn is_dns_label(label: &str) -> bool {
+    (1..=63).contains(&label.len())
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+/// The error returned when a string is not a valid hostname or DNS label.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidHostname {
+    /// The hostname was longer than 253 characters.
+    TooLong,
+
+    /// A label was invalid.
+    Label {
+        /// The index of the first invalid label.
+        label: usize,
+    },
+}
+
+impl fmt::Display for InvalidHostname {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong => f.write_str("must be a hostname of at most 253 characters"),
+            Self::Label { label } => write!(
+                f,
+                "must be a valid hostname, but label {label} is empty, too long, or contains \\
+                 invalid characters"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for InvalidHostname {}
+
+impl ErrorCode for InvalidHostname {
+    fn code(&self) -> &'static str {
+        "invalid_hostname"
+    }
+}
+
+/// A check that a string is an IPv4 or IPv6 address, producing the parsed [`core::net::IpAddr`].
+///
+/// This wraps anything implementing `AsRef<str>`.
+///
+/// ```
+/// use check_mate::{checks::IpAddr, Checked};
+///
+/// let addr: Checked<core::net::IpAddr> = Checked::try_from(IpAddr("::1")).unwrap();
+/// assert!(addr.is_loopback());
+///
+/// assert!(Checked::try_from(IpAddr("localhost")).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IpAddr<S>(pub S);
+
+/// A check that a string is an IPv4 address, producing the parsed [`core::net::Ipv4Addr`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Ipv4<S>(pub S);
+
+/// A check that a string is an IPv6 address, producing the parsed [`core::net::Ipv6Addr`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Ipv6<S>(pub S);
+
+/// A check that a string is a network in CIDR notation, producing the parsed address and prefix
+/// length.
+///
+/// Both IPv4 (e.g. `10.0.0.0/8`) and IPv6 (e.g. `fd00::/8`) networks are accepted. The prefix
+/// length must be at most 32 or 128 respectively. Host bits are allowed to be set, so
+/// `10.1.2.3/8` is valid.
+///
+/// ```
+/// use core::net::{IpAddr, Ipv4Addr};
+///
+/// use check_mate::{checks::Cidr, Checked};
+///
+/// let network: Checked<(IpAddr, u8)> = Checked::try_from(Cidr("10.0.0.0/8")).unwrap();
+/// assert_eq!(*network, (IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8));
+///
+/// assert!(Checked::try_from(Cidr("10.0.0.0/33")).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Cidr<S>(pub S);
+
+macro_rules! impl_ip {
+    ($($check:ident => $ty:ty),*) => {$(
+        impl<S: AsRef<str>> Check for $check<S> {
+            type Ok = $ty;
+            type Err = core::net::AddrParseError;
+
+            fn check(self) -> Result<Self::Ok, Self::Err> {
+                self.0.as_ref().parse()
+            }
+        }
+    )*};
+}
+
+impl_ip!(
+    IpAddr => core::net::IpAddr,
+    Ipv4 => core::net::Ipv4Addr,
+    Ipv6 => core::net::Ipv6Addr
+);
+
+impl<S: AsRef<str>> Check for Cidr<S> {
+    type Ok = (core::net::IpAddr, u8);
+    type Err = InvalidCidr;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        let (addr, prefix) = self
+            .0
+            .as_ref()
+            .split_once('/')
+            .ok_or(InvalidCidr::MissingPrefix)?;
+        let addr: core::net::IpAddr = addr.parse().map_err(InvalidCidr::Address)?;
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        match prefix.parse::<u8>() {
+            Ok(length) if length <= max && !prefix.starts_with('+') => Ok((addr, length)),
+            _ => Err(InvalidCidr::Prefix),
+        }
+    }
+}
+
+impl ErrorCode for core::net::AddrParseError {
+    fn code(&self) -> &'static str {
+        "invalid_ip_address"
+    }
+}
+
+/// The error returned when a string is not a network in CIDR notation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidCidr {
+    /// There was no `/` separating the address and prefix length.
+    MissingPrefix,
+
+    /// The address was invalid.
+    Address(core::net::AddrParseError),
+
+    /// The prefix length was not a number, or was too long for the address.
+    Prefix,
+}
+
+impl fmt::Display for InvalidCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => f.write_str("must be a network in CIDR notation"),
+            Self::Address(error) => write!(f, "must have a valid network address: {error}"),
+            Self::Prefix => f.write_str("must have a valid prefix length"),
+        }
+    }
+}
+
+impl core::error::Error for InvalidCidr {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Address(error) => Some(error),
+            Self::MissingPrefix | Self::Prefix => None,
+        }
+    }
+}
+
+impl ErrorCode for InvalidCidr {
+    fn code(&self) -> &'static str {
+        "invalid_cidr"
+    }
+}
+
+/// A check that a string is a MAC address, producing its six octets.
+///
+/// Octets must be two hex digits (of either case), separated consistently by either `:` or `-`.
+///
+/// ```
+/// use check_mate::{checks::MacAddr, Checked};
+///
+/// let addr: Checked<[u8; 6]> = Checked::try_from(MacAddr("00:1a:2B:3c:4D:5e")).unwrap();
+/// assert_eq!(*addr, [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+///
+/// assert!(Checked::try_from(MacAddr("00-1a-2b-3c-4d-5e")).is_ok());
+/// assert!(Checked::try_from(MacAddr("00:1a-2b:3c:4d:5e")).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MacAddr<S>(pub S);
+
+impl<S: AsRef<str>> Check for MacAddr<S> {
+    type Ok = [u8; 6];
+    type Err = InvalidMacAddr;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        let bytes = self.0.as_ref().as_bytes();
+        if bytes.len() != 17 {
+            return Err(InvalidMacAddr);
+        }
+        let separator = bytes[2];
+        if separator != b':' && separator != b'-' {
+            return Err(InvalidMacAddr);
+        }
+
+        let mut octets = [0; 6];
+        for (i, octet) in octets.iter_mut().enumerate() {
+            let start = i * 3;
+            if i > 0 && bytes[start - 1] != separator {
+                return Err(InvalidMacAddr);
+            }
+            *octet = (hex_digit(bytes[start])? << 4) | hex_digit(bytes[start + 1])?;
+        }
+        Ok(octets)
+    }
+}
+
+fn hex_digit(byte: u8) -> Result<u8, InvalidMacAddr> {
+    match byte {
+        b'0'..=b'9' => Ok(byte - b'0'),
+        b'a'..=b'f' => Ok(byte - b'a' + 10),
+        b'A'..=b'F' => Ok(byte - b'A' + 10),
+        _ => Err(InvalidMacAddr),
+    }
+}
+
+/// The error returned when a string is not a MAC address.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidMacAddr;
+
+impl fmt::Display for InvalidMacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must be a MAC address")
+    }
+}
+
+impl core::error::Error for InvalidMacAddr {}
+
+impl ErrorCode for InvalidMacAddr {
+    fn code(&self) -> &'static str {
+        "invalid_mac_address"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::{
+        Cidr, DnsLabel, Hostname, InvalidCidr, InvalidHostname, InvalidMacAddr, Ipv4, Ipv6, MacAddr,
+    };
+    use crate::Checked;
+
+    fn check(value: &str) -> Result<&str, InvalidHostname> {
+        Checked::<_, Hostname>::refine(value).map(Checked::into_inner)
+    }
+
+    #[test]
+    fn hostname() {
+        assert!(check("localhost").is_ok());
+        assert!(check("example.com.").is_ok());
+        assert!(check("1.2.3.4").is_ok());
+        assert_eq!(check(""), Err(InvalidHostname::Label { label: 0 }));
+        assert_eq!(check("a..b"), Err(InvalidHostname::Label { label: 1 }));
+        assert_eq!(check("a.-b"), Err(InvalidHostname::Label { label: 1 }));
+        assert_eq!(check(&["a"; 128].join(".")), Err(InvalidHostname::TooLong));
+    }
+
+    #[test]
+    fn dns_label() {
+        assert!(Checked::<_, DnsLabel>::refine("a-1").is_ok());
+        assert!(Checked::<_, DnsLabel>::refine("a.b").is_err());
+        assert!(Checked::<_, DnsLabel>::refine("a".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn ip() {
+        let addr: Checked<IpAddr> = Checked::try_from(super::IpAddr("127.0.0.1")).unwrap();
+        assert_eq!(*addr, Ipv4Addr::LOCALHOST);
+
+        assert!(Checked::try_from(Ipv4("::1")).is_err());
+        assert_eq!(
+            *Checked::try_from(Ipv6("::1")).unwrap(),
+            Ipv6Addr::LOCALHOST
+        );
+    }
+
+    #[test]
+    fn cidr() {
+        assert_eq!(
+            *Checked::try_from(Cidr("fd00::/128")).unwrap(),
+            (IpAddr::V6("fd00::".parse().unwrap()), 128)
+        );
+        assert_eq!(
+            Checked::try_from(Cidr("10.0.0.0")).unwrap_err(),
+            InvalidCidr::MissingPrefix
+        );
+        assert!(matches!(
+            Checked::try_from(Cidr("10.0.0/8")).unwrap_err(),
+            InvalidCidr::Address(_)
+        ));
+        assert_eq!(
+            Checked::try_from(Cidr("10.0.0.0/+8")).unwrap_err(),
+            InvalidCidr::Prefix
+        );
+        assert_eq!(
+            Checked::try_from(Cidr("::/129")).unwrap_err(),
+            InvalidCidr::Prefix
+        );
+    }
+
+    #[test]
+    fn mac_addr() {
+        assert_eq!(
+            *Checked::try_from(MacAddr("FF-ff-00-01-a0-0A")).unwrap(),
+            [0xff, 0xff, 0x00, 0x01, 0xa0, 0x0a]
+        );
+        for invalid in [
+            "",
+            "00:11:22:33:44",
+            "00:11:22:33:44:55:",
+            "0011.2233.4455",
+            "00:11:22:33:44:5g",
+        ] {
+            assert_eq!(
+                Checked::try_from(MacAddr(invalid)).unwrap_err(),
+                InvalidMacAddr
+            );
+        }
+    }
+}
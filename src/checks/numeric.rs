@@ -0,0 +1,702 @@
+use core::{convert::TryFrom, fmt, num};
+
+use crate::{Checked, ErrorCode, Predicate};
+
+/// A predicate that holds for integers between `MIN` and `MAX` (inclusive).
+///
+/// This applies to all primitive integers except `u128`, which can't be represented as an `i128`
+/// (see [`BoundedU`] for that).
+///
+/// ```
+/// use check_mate::{checks::Bounded, Checked};
+///
+/// type Port = Checked<u16, Bounded<1, 65535>>;
+///
+/// assert!(Port::refine(8080).is_ok());
+/// assert!(Port::refine(0).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Bounded<const MIN: i128, const MAX: i128>;
+
+/// A predicate that holds for integers strictly between `MIN` and `MAX` (exclusive).
+///
+/// Like [`Bounded`], this applies to all primitive integers except `u128`.
+#[derive(Clone, Copy, Debug)]
+pub struct BoundedExclusive<const MIN: i128, const MAX: i128>;
+
+/// A predicate that holds for unsigned integers between `MIN` and `MAX` (inclusive).
+///
+/// This applies to all primitive unsigned integers, including `u128`.
+#[derive(Clone, Copy, Debug)]
+pub struct BoundedU<const MIN: u128, const MAX: u128>;
+
+macro_rules! impl_bounded {
+    ($($ty:ty),*) => {$(
+        impl<const MIN: i128, const MAX: i128> Predicate<$ty> for Bounded<MIN, MAX> {
+            type Err = OutOfRange<i128>;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                // `isize` and `usize` don't implement `From`, but always fit in an `i128`.
+                let actual = i128::try_from(value).unwrap_or(i128::MAX);
+                if (MIN..=MAX).contains(&actual) {
+                    Ok(value)
+                } else {
+                    Err(OutOfRange { actual, min: MIN, max: MAX, exclusive: false })
+                }
+            }
+
+            #[cfg(feature = "schemars")]
+            fn constrain_schema(schema: &mut schemars::Schema) {
+                use serde_json::Number;
+                crate::schema::number(schema, "minimum", Number::from_i128(MIN));
+                crate::schema::number(schema, "maximum", Number::from_i128(MAX));
+            }
+        }
+
+        impl<const MIN: i128, const MAX: i128> Predicate<$ty> for BoundedExclusive<MIN, MAX> {
+            type Err = OutOfRange<i128>;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                let actual = i128::try_from(value).unwrap_or(i128::MAX);
+                if MIN < actual && actual < MAX {
+                    Ok(value)
+                } else {
+                    Err(OutOfRange { actual, min: MIN, max: MAX, exclusive: true })
+                }
+            }
+
+            #[cfg(feature = "schemars")]
+            fn constrain_schema(schema: &mut schemars::Schema) {
+                use serde_json::Number;
+                crate::schema::number(schema, "exclusiveMinimum", Number::from_i128(MIN));
+                crate::schema::number(schema, "exclusiveMaximum", Number::from_i128(MAX));
+            }
+        }
+    )*};
+}
+
+impl_bounded!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_bounded_u {
+    ($($ty:ty),*) => {$(
+        impl<const MIN: u128, const MAX: u128> Predicate<$ty> for BoundedU<MIN, MAX> {
+            type Err = OutOfRange<u128>;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                let actual = u128::try_from(value).unwrap_or(u128::MAX);
+                if (MIN..=MAX).contains(&actual) {
+                    Ok(value)
+                } else {
+                    Err(OutOfRange { actual, min: MIN, max: MAX, exclusive: false })
+                }
+            }
+
+            #[cfg(feature = "schemars")]
+            fn constrain_schema(schema: &mut schemars::Schema) {
+                use serde_json::Number;
+                crate::schema::number(schema, "minimum", Number::from_u128(MIN));
+                crate::schema::number(schema, "maximum", Number::from_u128(MAX));
+            }
+        }
+    )*};
+}
+
+impl_bounded_u!(u8, u16, u32, u64, u128, usize);
+
+/// A predicate that holds for TCP/UDP port numbers (`1..=65535`).
+///
+/// Setting `WELL_KNOWN` to `false` excludes the well-known ports (`1..=1023`), which usually
+/// require elevated privileges to bind. Setting `DYNAMIC` to `false` excludes the dynamic (or
+/// ephemeral) ports (`49152..=65535`), which the operating system assigns to outgoing connections.
+///
+/// Like [`Bounded`], this applies to all primitive integers except `u128`, so that out-of-range
+/// values read into a wider type are reported rather than truncated.
+///
+/// ```
+/// use check_mate::{checks::Port, Checked};
+///
+/// assert!(Checked::<u16, Port>::refine(80).is_ok());
+/// assert!(Checked::<u16, Port>::refine(0).is_err());
+/// assert!(Checked::<u32, Port>::refine(70000).is_err());
+///
+/// type Unprivileged = Port<false>;
+/// assert!(Checked::<u16, Unprivileged>::refine(80).is_err());
+/// assert!(Checked::<u16, Unprivileged>::refine(8080).is_ok());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Port<const WELL_KNOWN: bool = true, const DYNAMIC: bool = true>;
+
+impl<const WELL_KNOWN: bool, const DYNAMIC: bool> Port<WELL_KNOWN, DYNAMIC> {
+    const MIN: i128 = if WELL_KNOWN { 1 } else { 1024 };
+    const MAX: i128 = if DYNAMIC { 65535 } else { 49151 };
+}
+
+macro_rules! impl_port {
+    ($($ty:ty),*) => {$(
+        impl<const WELL_KNOWN: bool, const DYNAMIC: bool> Predicate<$ty>
+            for Port<WELL_KNOWN, DYNAMIC>
+        {
+            type Err = OutOfRange<i128>;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                let actual = i128::try_from(value).unwrap_or(i128::MAX);
+                if (Self::MIN..=Self::MAX).contains(&actual) {
+                    Ok(value)
+                } else {
+                    Err(OutOfRange { actual, min: Self::MIN, max: Self::MAX, exclusive: false })
+                }
+            }
+
+            #[cfg(feature = "schemars")]
+            fn constrain_schema(schema: &mut schemars::Schema) {
+                use serde_json::Number;
+                crate::schema::number(schema, "minimum", Number::from_i128(Self::MIN));
+                crate::schema::number(schema, "maximum", Number::from_i128(Self::MAX));
+            }
+        }
+    )*};
+}
+
+impl_port!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+/// A predicate that holds for percentages (`0..=100`).
+///
+/// This applies to all primitive integers except `u128`, and to `f32` and `f64` (for which NaN is
+/// rejected).
+///
+/// ```
+/// use check_mate::{checks::Percent, Checked};
+///
+/// assert!(Checked::<u8, Percent>::refine(100).is_ok());
+/// assert!(Checked::<f64, Percent>::refine(12.5).is_ok());
+/// assert_eq!(
+///     Checked::<f64, Percent>::refine(100.5).unwrap_err().to_string(),
+///     "must be between 0 and 100 (inclusive), but was 100.5",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Percent;
+
+macro_rules! impl_percent {
+    ($($ty:ty),*) => {$(
+        impl Predicate<$ty> for Percent {
+            type Err = OutOfRange<i128>;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                let actual = i128::try_from(value).unwrap_or(i128::MAX);
+                if (0..=100).contains(&actual) {
+                    Ok(value)
+                } else {
+                    Err(OutOfRange { actual, min: 0, max: 100, exclusive: false })
+                }
+            }
+
+            #[cfg(feature = "schemars")]
+            fn constrain_schema(schema: &mut schemars::Schema) {
+                crate::schema::percent(schema);
+            }
+        }
+    )*};
+}
+
+impl_percent!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_percent_float {
+    ($($ty:ty),*) => {$(
+        impl Predicate<$ty> for Percent {
+            type Err = OutOfRange<$ty>;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                if (0.0..=100.0).contains(&value) {
+                    Ok(value)
+                } else {
+                    Err(OutOfRange { actual: value, min: 0.0, max: 100.0, exclusive: false })
+                }
+            }
+
+            #[cfg(feature = "schemars")]
+            fn constrain_schema(schema: &mut schemars::Schema) {
+                crate::schema::percent(schema);
+            }
+        }
+    )*};
+}
+
+impl_percent_float!(f32, f64);
+
+/// The error returned when a number is outside of the allowed range.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct OutOfRange<N> {
+    /// The actual value.
+    pub actual: N,
+
+    /// The lower bound.
+    pub min: N,
+
+    /// The upper bound.
+    pub max: N,
+
+    /// Whether the bounds are exclusive.
+    pub exclusive: bool,
+}
+
+impl<N: fmt::Display> fmt::Display for OutOfRange<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be between {} and {} ({}), but was {}",
+            self.min,
+            self.max,
+            if self.exclusive {
+                "exclusive"
+            } else {
+                "inclusive"
+            },
+            self.actual,
+        )
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> core::error::Error for OutOfRange<N> {}
+
+impl<N> ErrorCode for OutOfRange<N> {
+    fn code(&self) -> &'static str {
+        "out_of_range"
+    }
+}
+
+/// A predicate that holds for integers that are not zero.
+///
+/// This applies to all primitive integers. Checked values can be converted to and from the
+/// corresponding [`core::num`] non-zero types (e.g. `Checked<u32, NonZero>` and
+/// [`NonZeroU32`](core::num::NonZeroU32)).
+///
+/// ```
+/// use core::num::NonZeroU32;
+/// use check_mate::{checks::NonZero, Checked};
+///
+/// let n = Checked::<u32, NonZero>::refine(3).unwrap();
+/// assert_eq!(NonZeroU32::from(n).get(), 3);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NonZero;
+
+macro_rules! impl_non_zero {
+    ($($ty:ty => $non_zero:ty),*) => {$(
+        impl Predicate<$ty> for NonZero {
+            type Err = Zero;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                if value == 0 {
+                    Err(Zero)
+                } else {
+                    Ok(value)
+                }
+            }
+        }
+
+        impl From<Checked<$ty, NonZero>> for $non_zero {
+            fn from(value: Checked<$ty, NonZero>) -> Self {
+                Self::new(value.into_inner()).expect("checked to be non-zero")
+            }
+        }
+
+        impl From<$non_zero> for Checked<$ty, NonZero> {
+            fn from(value: $non_zero) -> Self {
+                Checked::new_unchecked(value.get())
+            }
+        }
+    )*};
+}
+
+impl_non_zero!(
+    i8 => num::NonZeroI8,
+    i16 => num::NonZeroI16,
+    i32 => num::NonZeroI32,
+    i64 => num::NonZeroI64,
+    i128 => num::NonZeroI128,
+    isize => num::NonZeroIsize,
+    u8 => num::NonZeroU8,
+    u16 => num::NonZeroU16,
+    u32 => num::NonZeroU32,
+    u64 => num::NonZeroU64,
+    u128 => num::NonZeroU128,
+    usize => num::NonZeroUsize
+);
+
+/// The error returned when a number is zero.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Zero;
+
+impl fmt::Display for Zero {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must not be zero")
+    }
+}
+
+impl core::error::Error for Zero {}
+
+impl ErrorCode for Zero {
+    fn code(&self) -> &'static str {
+        "zero"
+    }
+}
+
+/// A predicate that holds for integers that are powers of two.
+///
+/// This applies to all primitive integers. Zero and negative numbers are never powers of two.
+///
+/// ```
+/// use check_mate::{checks::PowerOfTwo, Checked};
+///
+/// type Capacity = Checked<usize, PowerOfTwo>;
+///
+/// assert!(Capacity::refine(1024).is_ok());
+/// assert!(Capacity::refine(1000).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PowerOfTwo;
+
+/// A predicate that holds for unsigned integers that are multiples of `N`.
+///
+/// This applies to all primitive unsigned integers, and is intended for buffer lengths and offsets.
+/// `N` is usually a power of two, but this isn't required. If `N` is `0`, only `0` is aligned. See
+/// [`MultipleOf`] for signed integers.
+///
+/// ```
+/// use check_mate::{checks::Aligned, Checked};
+///
+/// assert!(Checked::<u64, Aligned<4096>>::refine(8192).is_ok());
+/// assert!(Checked::<u64, Aligned<4096>>::refine(4097).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Aligned<const N: usize>;
+
+/// A predicate that holds for integers that are multiples of `N`.
+///
+/// This applies to all primitive integers, and matches JSON Schema's `multipleOf` keyword for
+/// integers. Negative values are multiples of `N` if their absolute value is. If `N` is `0`, only
+/// `0` is a multiple.
+///
+/// ```
+/// use check_mate::{checks::MultipleOf, Checked};
+///
+/// type PageSize = Checked<u32, MultipleOf<512>>;
+///
+/// assert!(PageSize::refine(4096).is_ok());
+/// assert_eq!(
+///     PageSize::refine(1000).unwrap_err().to_string(),
+///     "must be a multiple of 512, but was 1000",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MultipleOf<const N: u64>;
+
+macro_rules! impl_power_of_two {
+    (unsigned: $($unsigned:ty),*; signed: $($signed:ty),*) => {
+        $(
+            impl Predicate<$unsigned> for PowerOfTwo {
+                type Err = NotPowerOfTwo;
+
+                fn refine(value: $unsigned) -> Result<$unsigned, Self::Err> {
+                    if value.is_power_of_two() {
+                        Ok(value)
+                    } else {
+                        Err(NotPowerOfTwo)
+                    }
+                }
+            }
+
+            impl<const N: usize> Predicate<$unsigned> for Aligned<N> {
+                type Err = Misaligned;
+
+                fn refine(value: $unsigned) -> Result<$unsigned, Self::Err> {
+                    // `usize` doesn't implement `From`, but always fits in a `u128`.
+                    let actual = u128::try_from(value).unwrap_or(u128::MAX);
+                    if actual.is_multiple_of(u128::try_from(N).unwrap_or(u128::MAX)) {
+                        Ok(value)
+                    } else {
+                        Err(Misaligned { alignment: N })
+                    }
+                }
+            }
+        )*
+        $(
+            impl Predicate<$signed> for PowerOfTwo {
+                type Err = NotPowerOfTwo;
+
+                fn refine(value: $signed) -> Result<$signed, Self::Err> {
+                    if value > 0 && value.unsigned_abs().is_power_of_two() {
+                        Ok(value)
+                    } else {
+                        Err(NotPowerOfTwo)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_power_of_two!(
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! impl_multiple_of {
+    (unsigned: $($unsigned:ty),*; signed: $($signed:ty),*) => {
+        $(
+            impl<const N: u64> Predicate<$unsigned> for MultipleOf<N> {
+                type Err = NotMultipleOf;
+
+                fn refine(value: $unsigned) -> Result<$unsigned, Self::Err> {
+                    let actual = u128::try_from(value).unwrap_or(u128::MAX);
+                    NotMultipleOf::check(actual.is_multiple_of(N.into()), value, N)
+                }
+
+                #[cfg(feature = "schemars")]
+                fn constrain_schema(schema: &mut schemars::Schema) {
+                    crate::schema::multiple_of(schema, N);
+                }
+            }
+        )*
+        $(
+            impl<const N: u64> Predicate<$signed> for MultipleOf<N> {
+                type Err = NotMultipleOf;
+
+                fn refine(value: $signed) -> Result<$signed, Self::Err> {
+                    let actual = u128::try_from(value.unsigned_abs()).unwrap_or(u128::MAX);
+                    NotMultipleOf::check(actual.is_multiple_of(N.into()), value, N)
+                }
+
+                #[cfg(feature = "schemars")]
+                fn constrain_schema(schema: &mut schemars::Schema) {
+                    crate::schema::multiple_of(schema, N);
+                }
+            }
+        )*
+    };
+}
+
+impl_multiple_of!(
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize
+);
+
+/// The error returned when a number is not a power of two.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NotPowerOfTwo;
+
+impl fmt::Display for NotPowerOfTwo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must be a power of two")
+    }
+}
+
+impl core::error::Error for NotPowerOfTwo {}
+
+impl ErrorCode for NotPowerOfTwo {
+    fn code(&self) -> &'static str {
+        "not_power_of_two"
+    }
+}
+
+/// The error returned when a number is not a multiple of the required alignment.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Misaligned {
+    /// The required alignment.
+    pub alignment: usize,
+}
+
+impl fmt::Display for Misaligned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "must be a multiple of {}", self.alignment)
+    }
+}
+
+impl core::error::Error for Misaligned {}
+
+impl ErrorCode for Misaligned {
+    fn code(&self) -> &'static str {
+        "misaligned"
+    }
+}
+
+/// The error returned when an integer is not a multiple of the required factor.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NotMultipleOf {
+    /// The actual value.
+    pub actual: i128,
+
+    /// The required factor.
+    pub factor: u64,
+}
+
+impl NotMultipleOf {
+    fn check<N: Copy>(multiple: bool, value: N, factor: u64) -> Result<N, Self>
+    where
+        i128: TryFrom<N>,
+    {
+        if multiple {
+            Ok(value)
+        } else {
+            let actual = i128::try_from(value).unwrap_or(i128::MAX);
+            Err(Self { actual, factor })
+        }
+    }
+}
+
+impl fmt::Display for NotMultipleOf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be a multiple of {}, but was {}",
+            self.factor, self.actual
+        )
+    }
+}
+
+impl core::error::Error for NotMultipleOf {}
+
+impl ErrorCode for NotMultipleOf {
+    fn code(&self) -> &'static str {
+        "not_multiple_of"
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use core::num::{NonZeroI8, NonZeroU64};
+
+    use super::{
+        Aligned, Bounded, BoundedExclusive, BoundedU, Misaligned, MultipleOf, NonZero,
+        NotMultipleOf, NotPowerOfTwo, OutOfRange, Percent, Port, PowerOfTwo, Zero,
+    };
+    use crate::Checked;
+
+    #[test]
+    fn bounded() {
+        assert!(Checked::<u16, Bounded<1, 65535>>::refine(1).is_ok());
+        assert!(Checked::<u16, Bounded<1, 65535>>::refine(65535).is_ok());
+        assert_eq!(
+            Checked::<u16, Bounded<1, 65535>>::refine(0).err(),
+            Some(OutOfRange {
+                actual: 0,
+                min: 1,
+                max: 65535,
+                exclusive: false
+            })
+        );
+        assert!(Checked::<i8, Bounded<-10, 10>>::refine(-10).is_ok());
+        assert!(Checked::<i8, Bounded<-10, 10>>::refine(-11).is_err());
+        assert!(Checked::<u64, Bounded<0, 10>>::refine(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn bounded_exclusive() {
+        assert!(Checked::<i32, BoundedExclusive<0, 10>>::refine(1).is_ok());
+        assert!(Checked::<i32, BoundedExclusive<0, 10>>::refine(0).is_err());
+        assert_eq!(
+            Checked::<i32, BoundedExclusive<0, 10>>::refine(10)
+                .unwrap_err()
+                .to_string(),
+            "must be between 0 and 10 (exclusive), but was 10"
+        );
+    }
+
+    #[test]
+    fn bounded_u() {
+        assert!(Checked::<u128, BoundedU<0, { u128::MAX }>>::refine(u128::MAX).is_ok());
+        assert!(Checked::<u128, BoundedU<1, 2>>::refine(3).is_err());
+    }
+
+    #[test]
+    fn non_zero() {
+        assert_eq!(Checked::<i8, NonZero>::refine(-1).as_deref(), Ok(&-1));
+        assert_eq!(Checked::<u64, NonZero>::refine(0).as_deref(), Err(&Zero));
+
+        let n = Checked::<u64, NonZero>::refine(7).unwrap();
+        assert_eq!(NonZeroU64::from(n), NonZeroU64::new(7).unwrap());
+
+        let n: Checked<i8, NonZero> = NonZeroI8::new(-3).unwrap().into();
+        assert_eq!(*n, -3);
+    }
+
+    #[test]
+    fn power_of_two() {
+        assert!(Checked::<u8, PowerOfTwo>::refine(128).is_ok());
+        assert!(Checked::<u128, PowerOfTwo>::refine(1 << 100).is_ok());
+        assert!(Checked::<i32, PowerOfTwo>::refine(1).is_ok());
+        assert_eq!(
+            Checked::<u32, PowerOfTwo>::refine(0).map(Checked::into_inner),
+            Err(NotPowerOfTwo)
+        );
+        assert!(Checked::<i64, PowerOfTwo>::refine(-4).is_err());
+        assert!(Checked::<i8, PowerOfTwo>::refine(i8::MIN).is_err());
+    }
+
+    #[test]
+    fn aligned() {
+        assert!(Checked::<usize, Aligned<8>>::refine(0).is_ok());
+        assert!(Checked::<u16, Aligned<3>>::refine(9).is_ok());
+        assert_eq!(
+            Checked::<u32, Aligned<16>>::refine(24).map(Checked::into_inner),
+            Err(Misaligned { alignment: 16 })
+        );
+        assert!(Checked::<u8, Aligned<0>>::refine(0).is_ok());
+        assert!(Checked::<u8, Aligned<0>>::refine(1).is_err());
+    }
+
+    #[test]
+    fn multiple_of() {
+        assert!(Checked::<u8, MultipleOf<3>>::refine(0).is_ok());
+        assert!(Checked::<i16, MultipleOf<3>>::refine(-9).is_ok());
+        assert!(Checked::<u128, MultipleOf<{ u64::MAX }>>::refine(u128::MAX).is_ok());
+        assert!(Checked::<i8, MultipleOf<2>>::refine(i8::MIN).is_ok());
+        assert_eq!(
+            Checked::<i64, MultipleOf<10>>::refine(-15).map(Checked::into_inner),
+            Err(NotMultipleOf {
+                actual: -15,
+                factor: 10
+            })
+        );
+        assert!(Checked::<u32, MultipleOf<0>>::refine(0).is_ok());
+        assert!(Checked::<u32, MultipleOf<0>>::refine(1).is_err());
+    }
+
+    #[test]
+    fn port() {
+        assert!(Checked::<u16, Port>::refine(65535).is_ok());
+        assert_eq!(
+            Checked::<i32, Port>::refine(-1).err(),
+            Some(OutOfRange {
+                actual: -1,
+                min: 1,
+                max: 65535,
+                exclusive: false
+            })
+        );
+        assert!(Checked::<u16, Port<false>>::refine(1023).is_err());
+        assert!(Checked::<u16, Port<false>>::refine(1024).is_ok());
+        assert!(Checked::<u16, Port<true, false>>::refine(22).is_ok());
+        assert!(Checked::<u16, Port<true, false>>::refine(49152).is_err());
+        assert!(Checked::<u64, Port<false, false>>::refine(49151).is_ok());
+    }
+
+    #[test]
+    fn percent() {
+        assert!(Checked::<i32, Percent>::refine(0).is_ok());
+        assert!(Checked::<i32, Percent>::refine(-1).is_err());
+        assert!(Checked::<u64, Percent>::refine(101).is_err());
+        assert_eq!(
+            Checked::<f32, Percent>::refine(-0.5).err(),
+            Some(OutOfRange {
+                actual: -0.5,
+                min: 0.0,
+                max: 100.0,
+                exclusive: false
+            })
+        );
+        assert!(Checked::<f64, Percent>::refine(f64::NAN).is_err());
+    }
+}
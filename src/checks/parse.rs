@@ -0,0 +1,124 @@
+use core::str::Utf8Error;
+
+use crate::{Check, ErrorCode};
+
+/// A check that bytes are valid UTF-8, producing the decoded string.
+///
+/// This wraps `&[u8]` or (with the `alloc` feature) `Vec<u8>`, and checks to `&str` or `String`
+/// respectively, without copying.
+///
+/// ```
+/// use check_mate::{checks::Utf8, Checked};
+///
+/// let text: Checked<&str> = Checked::try_from(Utf8(&b"hello"[..])).unwrap();
+/// assert_eq!(*text, "hello");
+///
+/// assert!(Checked::try_from(Utf8(&b"\xff"[..])).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Utf8<B>(pub B);
+
+impl<'a> Check for Utf8<&'a [u8]> {
+    type Ok = &'a str;
+    type Err = Utf8Error;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        core::str::from_utf8(self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Check for Utf8<alloc::vec::Vec<u8>> {
+    type Ok = alloc::string::String;
+    type Err = alloc::string::FromUtf8Error;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        alloc::string::String::from_utf8(self.0)
+    }
+}
+
+impl ErrorCode for Utf8Error {
+    fn code(&self) -> &'static str {
+        "invalid_utf8"
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ErrorCode for alloc::string::FromUtf8Error {
+    fn code(&self) -> &'static str {
+        "invalid_utf8"
+    }
+}
+
+/// A check that a string is a valid URL, producing the parsed [`url::Url`].
+///
+/// This is enabled by the `url` feature, and wraps anything implementing `AsRef<str>`. Only
+/// absolute URLs are accepted.
+///
+/// ```
+/// use check_mate::{checks::Url, Checked};
+///
+/// let url: Checked<url::Url> = Checked::try_from(Url("https://example.com/a/../b")).unwrap();
+/// assert_eq!(url.path(), "/b");
+///
+/// assert!(Checked::try_from(Url("/relative")).is_err());
+/// ```
+#[cfg(feature = "url")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Url<S>(pub S);
+
+#[cfg(feature = "url")]
+impl<S: AsRef<str>> Check for Url<S> {
+    type Ok = url::Url;
+    type Err = url::ParseError;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        url::Url::parse(self.0.as_ref())
+    }
+}
+
+#[cfg(feature = "url")]
+impl ErrorCode for url::ParseError {
+    fn code(&self) -> &'static str {
+        "invalid_url"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Utf8;
+    use crate::{Checked, ErrorCode};
+
+    #[test]
+    fn utf8() {
+        let text: Checked<&str> = Checked::try_from(Utf8("héllo".as_bytes())).unwrap();
+        assert_eq!(*text, "héllo");
+
+        let error = Checked::try_from(Utf8(&b"ab\xc3"[..])).unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.code(), "invalid_utf8");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn utf8_alloc() {
+        let text: Checked<String> = Checked::try_from(Utf8(b"hello".to_vec())).unwrap();
+        assert_eq!(*text, "hello");
+
+        let error = Checked::try_from(Utf8(vec![0xff])).unwrap_err();
+        assert_eq!(error.into_bytes(), [0xff]);
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn url() {
+        use super::Url;
+
+        let url: Checked<url::Url> = Checked::try_from(Url("http://example.com:8080")).unwrap();
+        assert_eq!(url.port(), Some(8080));
+
+        let error = Checked::try_from(Url(String::from("example.com"))).unwrap_err();
+        assert_eq!(error, url::ParseError::RelativeUrlWithoutBase);
+        assert_eq!(error.code(), "invalid_url");
+    }
+}
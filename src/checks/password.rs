@@ -0,0 +1,229 @@
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use core::fmt;
+
+use crate::{DynCheck, ErrorCode};
+
+/// A password policy, configured at runtime.
+///
+/// This is enabled by the `alloc` feature. Since the policy is only known at runtime, it's a
+/// [`DynCheck`] rather than a [`Predicate`](crate::Predicate), and values are checked with
+/// [`Checked::try_from_dyn`](crate::Checked::try_from_dyn). When a password fails, the error lists
+/// every requirement that wasn't met, rather than just the first.
+///
+/// ```
+/// use check_mate::{checks::{CharClass, PasswordPolicy}, Checked};
+///
+/// let policy = PasswordPolicy::new()
+///     .min_length(10)
+///     .require(CharClass::Uppercase)
+///     .require(CharClass::Digit)
+///     .deny(["Password123"]);
+///
+/// assert!(Checked::try_from_dyn("correct Horse battery 9", &policy).is_ok());
+/// assert_eq!(
+///     Checked::try_from_dyn("hunter2", &policy).unwrap_err().to_string(),
+///     "password must have at least 10 characters, and contain an uppercase letter",
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PasswordPolicy {
+    min_length: usize,
+    required: Vec<CharClass>,
+    denied: BTreeSet<String>,
+}
+
+impl PasswordPolicy {
+    /// Construct a policy that accepts any password.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require passwords to have at least `min_length` characters.
+    #[must_use]
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// Require passwords to contain at least one character of the given class.
+    #[must_use]
+    pub fn require(mut self, class: CharClass) -> Self {
+        if !self.required.contains(&class) {
+            self.required.push(class);
+        }
+        self
+    }
+
+    /// Reject the given passwords (ignoring case), e.g. commonly used passwords.
+    #[must_use]
+    pub fn deny<I>(mut self, passwords: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.denied.extend(
+            passwords
+                .into_iter()
+                .map(|password| password.as_ref().to_lowercase()),
+        );
+        self
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> DynCheck<T> for PasswordPolicy {
+    type Err = PasswordError;
+
+    fn check_dyn(&self, value: &T) -> Result<(), Self::Err> {
+        let password = value.as_ref();
+        let mut unmet = Vec::new();
+
+        if password.chars().count() < self.min_length {
+            unmet.push(Requirement::MinLength(self.min_length));
+        }
+        for class in &self.required {
+            if !password.chars().any(|c| class.contains(c)) {
+                unmet.push(Requirement::Class(*class));
+            }
+        }
+        if self.denied.contains(&password.to_lowercase()) {
+            unmet.push(Requirement::NotDenied);
+        }
+
+        if unmet.is_empty() {
+            Ok(())
+        } else {
+            Err(PasswordError { unmet })
+        }
+    }
+}
+
+/// Classes of characters that a [`PasswordPolicy`] can require.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CharClass {
+    /// Lowercase letters.
+    Lowercase,
+
+    /// Uppercase letters.
+    Uppercase,
+
+    /// ASCII digits.
+    Digit,
+
+    /// Anything other than letters and digits (including whitespace).
+    Symbol,
+}
+
+impl CharClass {
+    fn contains(self, c: char) -> bool {
+        match self {
+            Self::Lowercase => c.is_lowercase(),
+            Self::Uppercase => c.is_uppercase(),
+            Self::Digit => c.is_ascii_digit(),
+            Self::Symbol => !c.is_alphanumeric(),
+        }
+    }
+}
+
+/// A requirement of a [`PasswordPolicy`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Requirement {
+    /// The password must have at least this many characters.
+    MinLength(usize),
+
+    /// The password must contain a character of this class.
+    Class(CharClass),
+
+    /// The password must not be on the deny list.
+    NotDenied,
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MinLength(min) => write!(f, "have at least {min} characters"),
+            Self::Class(CharClass::Lowercase) => f.write_str("contain a lowercase letter"),
+            Self::Class(CharClass::Uppercase) => f.write_str("contain an uppercase letter"),
+            Self::Class(CharClass::Digit) => f.write_str("contain a digit"),
+            Self::Class(CharClass::Symbol) => f.write_str("contain a symbol"),
+            Self::NotDenied => f.write_str("not be a commonly used password"),
+        }
+    }
+}
+
+/// The error returned when a password doesn't meet a [`PasswordPolicy`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PasswordError {
+    unmet: Vec<Requirement>,
+}
+
+impl PasswordError {
+    /// The requirements that weren't met.
+    ///
+    /// This is never empty.
+    #[must_use]
+    pub fn unmet(&self) -> &[Requirement] {
+        &self.unmet
+    }
+}
+
+impl fmt::Display for PasswordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("password must ")?;
+        for (i, requirement) in self.unmet.iter().enumerate() {
+            match i {
+                0 => {}
+                _ if i + 1 == self.unmet.len() => f.write_str(", and ")?,
+                _ => f.write_str(", ")?,
+            }
+            write!(f, "{requirement}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for PasswordError {}
+
+impl ErrorCode for PasswordError {
+    fn code(&self) -> &'static str {
+        "weak_password"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharClass, PasswordPolicy, Requirement};
+    use crate::{Checked, DynCheck};
+
+    #[test]
+    fn password_policy() {
+        let policy = PasswordPolicy::new()
+            .min_length(8)
+            .require(CharClass::Lowercase)
+            .require(CharClass::Symbol)
+            .deny(["Tr0ub4dor&3"]);
+
+        assert!(Checked::<String, _>::try_from_dyn("ab cd ef".into(), &policy).is_ok());
+        assert!(policy.check_dyn("x").is_err());
+
+        let error = policy.check_dyn("TR0UB4DOR&3").unwrap_err();
+        assert_eq!(
+            error.unmet(),
+            [
+                Requirement::Class(CharClass::Lowercase),
+                Requirement::NotDenied
+            ]
+        );
+
+        let error = policy.check_dyn("ABC").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "password must have at least 8 characters, contain a lowercase letter, and contain a \
+             symbol"
+        );
+
+        assert!(PasswordPolicy::new().check_dyn("").is_ok());
+    }
+}
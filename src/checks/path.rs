@@ -0,0 +1,270 @@
+use core::fmt;
+
+use crate::{ErrorCode, Implies, Predicate};
+
+/// A predicate that holds for relative paths that can't escape the directory they're joined to.
+///
+/// This applies to `&str`, and `String` with the `alloc` feature, or `&Path` and `PathBuf` with
+/// the `std` feature. Both `/` and `\` are treated as separators, regardless of platform, and the
+/// path must not be:
+///
+/// - Empty.
+/// - Absolute, including Windows drive prefixes such as `C:`.
+/// - Contain any `..` components.
+/// - Contain NUL bytes.
+///
+/// ```
+/// use check_mate::{checks::SafeRelativePath, Checked};
+///
+/// assert!(Checked::<_, SafeRelativePath>::refine("images/logo.png").is_ok());
+/// assert!(Checked::<_, SafeRelativePath>::refine("../etc/passwd").is_err());
+/// assert!(Checked::<_, SafeRelativePath>::refine("/etc/passwd").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SafeRelativePath;
+
+fn check_relative_path(path: &[u8]) -> Result<(), UnsafePath> {
+    if path.is_empty() {
+        return Err(UnsafePath::Empty);
+    }
+    if path.contains(&0) {
+        return Err(UnsafePath::Nul);
+    }
+    if matches!(path, [b'/' | b'\\', ..] | [_, b':', ..]) {
+        return Err(UnsafePath::Absolute);
+    }
+    if path
+        .split(|b| matches!(b, b'/' | b'\\'))
+        .any(|component| component == b"..")
+    {
+        return Err(UnsafePath::ParentDir);
+    }
+    Ok(())
+}
+
+/// A predicate that holds for safe, portable filenames.
+///
+/// This applies to the same types as [`SafeRelativePath`]. A filename is a single path component,
+/// so it must not:
+///
+/// - Be empty, `.` or `..`.
+/// - Be longer than 255 bytes.
+/// - Contain `/`, `\`, NUL or other control characters, or any of `<>:"|?*` (which Windows
+///   disallows).
+/// - End with `.` or a space.
+/// - Be a reserved Windows device name, such as `CON` or `lpt1.txt`.
+///
+/// ```
+/// use check_mate::{checks::Filename, Checked};
+///
+/// assert!(Checked::<_, Filename>::refine("report-2024.pdf").is_ok());
+/// assert!(Checked::<_, Filename>::refine("reports/2024.pdf").is_err());
+/// assert!(Checked::<_, Filename>::refine("aux.pdf").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Filename;
+
+impl Implies<SafeRelativePath> for Filename {}
+
+const RESERVED_NAMES: &[&[u8]] = &[
+    b"CON", b"PRN", b"AUX", b"NUL", b"COM1", b"COM2", b"COM3", b"COM4", b"COM5", b"COM6", b"COM7",
+    b"COM8", b"COM9", b"LPT1", b"LPT2", b"LPT3", b"LPT4", b"LPT5", b"LPT6", b"LPT7", b"LPT8",
+    b"LPT9",
+];
+
+fn check_filename(name: &[u8]) -> Result<(), InvalidFilename> {
+    if matches!(name, b"" | b"." | b"..") {
+        return Err(InvalidFilename::Empty);
+    }
+    if name.len() > 255 {
+        return Err(InvalidFilename::TooLong);
+    }
+    if let Some(index) = name
+        .iter()
+        .position(|b| b.is_ascii_control() || b"/\\<>:\"|?*".contains(b))
+    {
+        return Err(InvalidFilename::IllegalChar { index });
+    }
+    if matches!(name, [.., b'.' | b' ']) {
+        return Err(InvalidFilename::TrailingChar);
+    }
+    let stem = name.split(|b| *b == b'.').next().unwrap_or(name);
+    if RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+    {
+        return Err(InvalidFilename::Reserved);
+    }
+    Ok(())
+}
+
+macro_rules! impl_path {
+    ($($(#[$attr:meta])* $ty:ty, $path:ident => $bytes:expr;)*) => {$(
+        $(#[$attr])*
+        impl Predicate<$ty> for SafeRelativePath {
+            type Err = UnsafePath;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                let $path = &value;
+                check_relative_path($bytes)?;
+                Ok(value)
+            }
+        }
+
+        $(#[$attr])*
+        impl Predicate<$ty> for Filename {
+            type Err = InvalidFilename;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                let $path = &value;
+                check_filename($bytes)?;
+                Ok(value)
+            }
+        }
+    )*};
+}
+
+impl_path! {
+    &str, path => path.as_bytes();
+    #[cfg(feature = "alloc")]
+    alloc::string::String, path => path.as_bytes();
+    #[cfg(feature = "std")]
+    &std::path::Path, path => path.as_os_str().as_encoded_bytes();
+    #[cfg(feature = "std")]
+    std::path::PathBuf, path => path.as_os_str().as_encoded_bytes();
+}
+
+/// The error returned when a path is not safe.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UnsafePath {
+    /// The path was empty.
+    Empty,
+
+    /// The path contained a NUL byte.
+    Nul,
+
+    /// The path was absolute.
+    Absolute,
+
+    /// The path contained a `..` component.
+    ParentDir,
+}
+
+impl fmt::Display for UnsafePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "must not be empty",
+            Self::Nul => "must not contain NUL bytes",
+            Self::Absolute => "must be a relative path",
+            Self::ParentDir => "must not contain `..` components",
+        })
+    }
+}
+
+impl core::error::Error for UnsafePath {}
+
+impl ErrorCode for UnsafePath {
+    fn code(&self) -> &'static str {
+        "unsafe_path"
+    }
+}
+
+/// The error returned when a string is not a valid filename.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidFilename {
+    /// The filename was empty, `.` or `..`.
+    Empty,
+
+    /// The filename was longer than 255 bytes.
+    TooLong,
+
+    /// The filename contained a separator or other disallowed character.
+    IllegalChar {
+        /// The byte index of the first disallowed character.
+        index: usize,
+    },
+
+    /// The filename ended with `.` or a space.
+    TrailingChar,
+
+    /// The filename was a reserved Windows device name.
+    Reserved,
+}
+
+impl fmt::Display for InvalidFilename {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("must be a filename, not empty, `.` or `..`"),
+            Self::TooLong => f.write_str("must be a filename of at most 255 bytes"),
+            Self::IllegalChar { index } => {
+                write!(
+                    f,
+                    "must be a filename, but has an illegal character at index {index}"
+                )
+            }
+            Self::TrailingChar => f.write_str("must not end with `.` or a space"),
+            Self::Reserved => f.write_str("must not be a reserved device name"),
+        }
+    }
+}
+
+impl core::error::Error for InvalidFilename {}
+
+impl ErrorCode for InvalidFilename {
+    fn code(&self) -> &'static str {
+        "invalid_filename"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Filename, InvalidFilename, SafeRelativePath, UnsafePath};
+    use crate::Checked;
+
+    fn check(path: &str) -> Result<&str, UnsafePath> {
+        Checked::<_, SafeRelativePath>::refine(path).map(Checked::into_inner)
+    }
+
+    #[test]
+    fn safe_relative_path() {
+        assert!(check("a").is_ok());
+        assert!(check("a/./b..c/").is_ok());
+        assert_eq!(check(""), Err(UnsafePath::Empty));
+        assert_eq!(check("a\0b"), Err(UnsafePath::Nul));
+        assert_eq!(check("\\\\server\\share"), Err(UnsafePath::Absolute));
+        assert_eq!(check("C:foo"), Err(UnsafePath::Absolute));
+        assert_eq!(check("a/../../b"), Err(UnsafePath::ParentDir));
+        assert_eq!(check("a\\.."), Err(UnsafePath::ParentDir));
+    }
+
+    #[test]
+    fn filename() {
+        let check = |name| Checked::<&str, Filename>::refine(name).map(Checked::into_inner);
+
+        assert!(check(".gitignore").is_ok());
+        assert!(check("console.log").is_ok());
+        assert_eq!(check(".."), Err(InvalidFilename::Empty));
+        let long = "a".repeat(256);
+        assert_eq!(check(&long), Err(InvalidFilename::TooLong));
+        assert_eq!(check("a/b"), Err(InvalidFilename::IllegalChar { index: 1 }));
+        assert_eq!(check("a?"), Err(InvalidFilename::IllegalChar { index: 1 }));
+        assert_eq!(
+            check("a\tb"),
+            Err(InvalidFilename::IllegalChar { index: 1 })
+        );
+        assert_eq!(check("a."), Err(InvalidFilename::TrailingChar));
+        assert_eq!(check("Com1.txt"), Err(InvalidFilename::Reserved));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn safe_relative_path_std() {
+        use std::path::{Path, PathBuf};
+
+        assert!(Checked::<_, SafeRelativePath>::refine(Path::new("a/b")).is_ok());
+        assert_eq!(
+            Checked::<_, SafeRelativePath>::refine(PathBuf::from("/a")).map(Checked::into_inner),
+            Err(UnsafePath::Absolute)
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use core::{fmt, marker::PhantomData};
+
+use regex::Regex;
+
+use crate::{ErrorCode, Predicate};
+
+/// Regular expressions, as types.
+///
+/// This is used to supply the pattern for [`Matches`]. Implementations will usually return a
+/// lazily-compiled static, so the pattern is only compiled once.
+///
+/// ```
+/// use std::sync::LazyLock;
+///
+/// use check_mate::{checks::{Matches, Pattern}, Checked};
+/// use regex::Regex;
+///
+/// struct UsernamePattern;
+///
+/// impl Pattern for UsernamePattern {
+///     fn regex() -> &'static Regex {
+///         static REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new("^[a-z_]+$").unwrap());
+///         &REGEX
+///     }
+/// }
+///
+/// type Username = Checked<String, Matches<UsernamePattern>>;
+///
+/// assert!(Username::refine("ferris".into()).is_ok());
+/// assert_eq!(
+///     Username::refine("Ferris".into()).unwrap_err().to_string(),
+///     "must match `^[a-z_]+$`",
+/// );
+/// ```
+pub trait Pattern {
+    /// The regular expression.
+    fn regex() -> &'static Regex;
+}
+
+/// A predicate that holds for strings matching the [`Pattern`] `P`.
+///
+/// This is enabled by the `regex` feature, and applies to anything implementing `AsRef<str>`. Note
+/// that the pattern is not implicitly anchored, so it should usually start with `^` and end with
+/// `$`.
+pub struct Matches<P>(PhantomData<fn() -> P>);
+
+impl<T: AsRef<str>, P: Pattern> Predicate<T> for Matches<P> {
+    type Err = Mismatch;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let regex = P::regex();
+        if regex.is_match(value.as_ref()) {
+            Ok(value)
+        } else {
+            Err(Mismatch {
+                pattern: regex.as_str(),
+            })
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    fn constrain_schema(schema: &mut schemars::Schema) {
+        schema.insert("pattern".into(), P::regex().as_str().into());
+    }
+}
+
+/// The error returned when a string doesn't match a pattern.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Mismatch {
+    /// The pattern that wasn't matched.
+    pub pattern: &'static str,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "must match `{}`", self.pattern)
+    }
+}
+
+impl core::error::Error for Mismatch {}
+
+impl ErrorCode for Mismatch {
+    fn code(&self) -> &'static str {
+        "mismatch"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use regex::Regex;
+
+    use super::{Matches, Mismatch, Pattern};
+    use crate::Checked;
+
+    struct Digits;
+
+    impl Pattern for Digits {
+        fn regex() -> &'static Regex {
+            static REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new("^[0-9]+$").unwrap());
+            &REGEX
+        }
+    }
+
+    #[test]
+    fn matches() {
+        assert!(Checked::<_, Matches<Digits>>::refine("123").is_ok());
+        assert_eq!(
+            Checked::<_, Matches<Digits>>::refine("12a").map(Checked::into_inner),
+            Err(Mismatch {
+                pattern: "^[0-9]+$"
+            })
+        );
+    }
+}
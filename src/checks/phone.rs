@@ -0,0 +1,98 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for phone numbers in E.164 format.
+///
+/// This applies to anything implementing `AsRef<str>`. The number must be a `+` followed by 1 to
+/// 15 digits, the first of which is not `0`, without any spaces or punctuation.
+///
+/// With the `phonenumber` feature enabled, numbers are additionally validated against the
+/// numbering plan of their country, using the [`phonenumber`] crate.
+///
+/// ```
+/// use check_mate::{checks::PhoneNumber, Checked};
+///
+/// assert!(Checked::<_, PhoneNumber>::refine("+442079460958").is_ok());
+/// assert!(Checked::<_, PhoneNumber>::refine("020 7946 0958").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PhoneNumber;
+
+impl<T: AsRef<str>> Predicate<T> for PhoneNumber {
+    type Err = InvalidPhoneNumber;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let number = value.as_ref();
+        let well_formed = match number.strip_prefix('+').map(str::as_bytes) {
+            Some(digits @ [b'1'..=b'9', ..]) => {
+                digits.len() <= 15 && digits.iter().all(u8::is_ascii_digit)
+            }
+            _ => false,
+        };
+        if !well_formed {
+            return Err(InvalidPhoneNumber::Format);
+        }
+
+        #[cfg(feature = "phonenumber")]
+        if !phonenumber::parse(None, number).is_ok_and(|number| phonenumber::is_valid(&number)) {
+            return Err(InvalidPhoneNumber::Unassigned);
+        }
+
+        Ok(value)
+    }
+}
+
+/// The error returned when a string is not a valid phone number.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidPhoneNumber {
+    /// The number was not in E.164 format.
+    Format,
+
+    /// The number is not valid in its country's numbering plan (only with the `phonenumber`
+    /// feature).
+    Unassigned,
+}
+
+impl fmt::Display for InvalidPhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Format => "must be a phone number in E.164 format",
+            Self::Unassigned => "must be a valid phone number",
+        })
+    }
+}
+
+impl core::error::Error for InvalidPhoneNumber {}
+
+impl ErrorCode for InvalidPhoneNumber {
+    fn code(&self) -> &'static str {
+        "invalid_phone_number"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidPhoneNumber, PhoneNumber};
+    use crate::Checked;
+
+    fn check(number: &str) -> Result<&str, InvalidPhoneNumber> {
+        Checked::<_, PhoneNumber>::refine(number).map(Checked::into_inner)
+    }
+
+    #[test]
+    fn phone_number() {
+        assert!(check("+14155552671").is_ok());
+        assert_eq!(check("14155552671"), Err(InvalidPhoneNumber::Format));
+        assert_eq!(check("+04155552671"), Err(InvalidPhoneNumber::Format));
+        assert_eq!(check("+1 415 555 2671"), Err(InvalidPhoneNumber::Format));
+        assert_eq!(check("+1234567890123456"), Err(InvalidPhoneNumber::Format));
+    }
+
+    #[cfg(feature = "phonenumber")]
+    #[test]
+    fn phone_number_plan() {
+        assert_eq!(check("+4400"), Err(InvalidPhoneNumber::Unassigned));
+    }
+}
@@ -0,0 +1,104 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for JSON Pointers ([RFC 6901]).
+///
+/// This applies to anything implementing `AsRef<str>`. A JSON Pointer is either empty (referring
+/// to the whole document), or a sequence of reference tokens each prefixed with `/`. Within a
+/// token, `~` must be escaped as `~0` and `/` as `~1`.
+///
+/// Only the syntax is checked, so the pointer may not resolve in a particular document.
+///
+/// ```
+/// use check_mate::{checks::JsonPointer, Checked};
+///
+/// assert!(Checked::<_, JsonPointer>::refine("/users/0/a~1b").is_ok());
+/// assert_eq!(
+///     Checked::<_, JsonPointer>::refine("/users/~2").unwrap_err().to_string(),
+///     "must be a JSON Pointer, but has an invalid escape at index 7",
+/// );
+/// ```
+///
+/// [RFC 6901]: https://www.rfc-editor.org/rfc/rfc6901
+#[derive(Clone, Copy, Debug)]
+pub struct JsonPointer;
+
+impl<T: AsRef<str>> Predicate<T> for JsonPointer {
+    type Err = InvalidJsonPointer;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let bytes = value.as_ref().as_bytes();
+        if bytes.first().is_some_and(|b| *b != b'/') {
+            return Err(InvalidJsonPointer::MissingSlash);
+        }
+        let escape = bytes.iter().enumerate().position(|(index, byte)| {
+            *byte == b'~' && !matches!(bytes.get(index + 1), Some(b'0' | b'1'))
+        });
+        match escape {
+            Some(index) => Err(InvalidJsonPointer::Escape { index }),
+            None => Ok(value),
+        }
+    }
+}
+
+/// The error returned when a string is not a JSON Pointer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidJsonPointer {
+    /// The string was not empty, but didn't start with `/`.
+    MissingSlash,
+
+    /// The string contained a `~` that wasn't followed by `0` or `1`.
+    Escape {
+        /// The byte index of the `~`.
+        index: usize,
+    },
+}
+
+impl fmt::Display for InvalidJsonPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSlash => f.write_str("must be a JSON Pointer, but doesn't start with '/'"),
+            Self::Escape { index } => write!(
+                f,
+                "must be a JSON Pointer, but has an invalid escape at index {index}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for InvalidJsonPointer {}
+
+impl ErrorCode for InvalidJsonPointer {
+    fn code(&self) -> &'static str {
+        "invalid_json_pointer"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidJsonPointer, JsonPointer};
+    use crate::Checked;
+
+    fn check(value: &str) -> Result<&str, InvalidJsonPointer> {
+        Checked::<_, JsonPointer>::refine(value).map(Checked::into_inner)
+    }
+
+    #[test]
+    fn json_pointer() {
+        for valid in ["", "/", "//", "/a b", "/~0~1", "/é"] {
+            assert_eq!(check(valid), Ok(valid));
+        }
+        assert_eq!(check("a/b"), Err(InvalidJsonPointer::MissingSlash));
+        assert_eq!(check("/a~"), Err(InvalidJsonPointer::Escape { index: 2 }));
+        assert_eq!(check("/~01~"), Err(InvalidJsonPointer::Escape { index: 4 }));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn resolves() {
+        let pointer = Checked::<_, JsonPointer>::refine("/a~1b/1").unwrap();
+        let document = serde_json::json!({ "a/b": [1, 2] });
+        assert_eq!(document.pointer(&pointer), Some(&serde_json::json!(2)));
+    }
+}
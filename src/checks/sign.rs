@@ -0,0 +1,172 @@
+use core::fmt;
+
+use crate::{ErrorCode, Implies, Predicate};
+
+/// A predicate that holds for numbers greater than zero.
+///
+/// This applies to all primitive integers and floats. NaN is never positive.
+///
+/// ```
+/// use check_mate::{checks::Positive, Checked};
+///
+/// type Price = Checked<f64, Positive>;
+///
+/// assert!(Price::refine(9.99).is_ok());
+/// assert!(Price::refine(0.0).is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Positive;
+
+/// A predicate that holds for numbers less than zero.
+///
+/// This applies to signed integers and floats. NaN is never negative.
+#[derive(Clone, Copy, Debug)]
+pub struct Negative;
+
+/// A predicate that holds for numbers greater than or equal to zero.
+///
+/// This applies to all primitive integers and floats. NaN is never non-negative, but `-0.0` is.
+#[derive(Clone, Copy, Debug)]
+pub struct NonNegative;
+
+impl Implies<NonNegative> for Positive {}
+
+macro_rules! impl_positive {
+    ($zero:literal => $($ty:ty),*) => {$(
+        impl Predicate<$ty> for Positive {
+            type Err = SignError;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                if value > $zero {
+                    Ok(value)
+                } else {
+                    Err(SignError::NotPositive)
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($zero:literal => $($ty:ty),*) => {$(
+        impl Predicate<$ty> for NonNegative {
+            type Err = SignError;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                if value >= $zero {
+                    Ok(value)
+                } else {
+                    Err(SignError::Negative)
+                }
+            }
+        }
+
+        impl Predicate<$ty> for Negative {
+            type Err = SignError;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                if value < $zero {
+                    Ok(value)
+                } else {
+                    Err(SignError::NotNegative)
+                }
+            }
+        }
+    )*};
+}
+
+impl_positive!(0 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_positive!(0.0 => f32, f64);
+impl_signed!(0 => i8, i16, i32, i64, i128, isize);
+impl_signed!(0.0 => f32, f64);
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {$(
+        impl Predicate<$ty> for NonNegative {
+            type Err = SignError;
+
+            fn refine(value: $ty) -> Result<$ty, Self::Err> {
+                Ok(value)
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// The error returned when a number has the wrong sign.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SignError {
+    /// The number was not positive (for [`Positive`]).
+    NotPositive,
+
+    /// The number was not negative (for [`Negative`]).
+    NotNegative,
+
+    /// The number was negative (for [`NonNegative`]).
+    Negative,
+}
+
+impl fmt::Display for SignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotPositive => "must be positive",
+            Self::NotNegative => "must be negative",
+            Self::Negative => "must not be negative",
+        })
+    }
+}
+
+impl core::error::Error for SignError {}
+
+impl ErrorCode for SignError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::NotPositive => "not_positive",
+            Self::NotNegative => "not_negative",
+            Self::Negative => "negative",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Negative, NonNegative, Positive, SignError};
+    use crate::Checked;
+
+    #[test]
+    fn positive() {
+        assert_eq!(Checked::<u8, Positive>::refine(1).as_deref(), Ok(&1));
+        assert_eq!(
+            Checked::<i32, Positive>::refine(0).map(Checked::into_inner),
+            Err(SignError::NotPositive)
+        );
+        assert!(Checked::<f64, Positive>::refine(f64::NAN).is_err());
+
+        let positive = Checked::<i64, Positive>::refine(5).unwrap();
+        let non_negative: Checked<i64, NonNegative> = positive.weaken();
+        assert_eq!(*non_negative, 5);
+    }
+
+    #[test]
+    fn negative() {
+        assert!(Checked::<i8, Negative>::refine(-1).is_ok());
+        assert!(Checked::<f32, Negative>::refine(-0.0).is_err());
+        assert_eq!(
+            Checked::<f64, Negative>::refine(2.0)
+                .unwrap_err()
+                .to_string(),
+            "must be negative"
+        );
+    }
+
+    #[test]
+    fn non_negative() {
+        assert!(Checked::<isize, NonNegative>::refine(0).is_ok());
+        assert!(Checked::<f64, NonNegative>::refine(-0.0).is_ok());
+        assert_eq!(
+            Checked::<i16, NonNegative>::refine(-3).map(Checked::into_inner),
+            Err(SignError::Negative)
+        );
+    }
+}
@@ -0,0 +1,618 @@
+use core::fmt;
+
+use super::charset::CharSet;
+use crate::{ErrorCode, Implies, Normalize, Predicate};
+
+/// A predicate that holds for strings and byte slices containing only ASCII.
+///
+/// This applies to anything implementing `AsRef<[u8]>`, such as `&str`, `String`, `&[u8]` and
+/// `Vec<u8>`.
+///
+/// ```
+/// use check_mate::{checks::Ascii, Checked};
+///
+/// assert!(Checked::<_, Ascii>::refine("GET").is_ok());
+/// assert_eq!(
+///     Checked::<_, Ascii>::refine("café").unwrap_err().to_string(),
+///     "must be ASCII, but found byte 0xc3 at index 3",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Ascii;
+
+/// A predicate that holds for strings and byte slices containing only printable ASCII.
+///
+/// Printable ASCII is the graphic characters and space (`0x20..=0x7e`), so control characters
+/// (including tabs and newlines) are rejected.
+#[derive(Clone, Copy, Debug)]
+pub struct AsciiPrintable;
+
+impl Implies<Ascii> for AsciiPrintable {}
+
+impl<T: AsRef<[u8]>> Predicate<T> for Ascii {
+    type Err = NonAscii;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        NonAscii::check(value, false, |byte| byte.is_ascii())
+    }
+}
+
+impl<T: AsRef<[u8]>> Predicate<T> for AsciiPrintable {
+    type Err = NonAscii;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        NonAscii::check(value, true, |byte| byte.is_ascii_graphic() || byte == b' ')
+    }
+}
+
+/// The error returned when a value contains a disallowed byte.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NonAscii {
+    /// The index of the first disallowed byte.
+    pub index: usize,
+
+    /// The first disallowed byte.
+    pub byte: u8,
+
+    /// Whether only printable ASCII was allowed.
+    pub printable: bool,
+}
+
+impl NonAscii {
+    fn check<T: AsRef<[u8]>>(
+        value: T,
+        printable: bool,
+        allowed: impl Fn(u8) -> bool,
+    ) -> Result<T, Self> {
+        match value.as_ref().iter().position(|byte| !allowed(*byte)) {
+            Some(index) => Err(Self {
+                index,
+                byte: value.as_ref()[index],
+                printable,
+            }),
+            None => Ok(value),
+        }
+    }
+}
+
+impl fmt::Display for NonAscii {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be {}ASCII, but found byte {:#04x} at index {}",
+            if self.printable { "printable " } else { "" },
+            self.byte,
+            self.index
+        )
+    }
+}
+
+impl core::error::Error for NonAscii {}
+
+impl ErrorCode for NonAscii {
+    fn code(&self) -> &'static str {
+        "non_ascii"
+    }
+}
+
+/// A predicate that holds for strings without leading or trailing whitespace.
+///
+/// This applies to anything implementing `AsRef<str>`. `&str` and (with the `alloc` feature)
+/// `String` can also be [normalized](crate::Checked::normalize) by trimming them.
+#[derive(Clone, Copy, Debug)]
+pub struct Trimmed;
+
+impl<T: AsRef<str>> Predicate<T> for Trimmed {
+    type Err = Untrimmed;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let string = value.as_ref();
+        if string.trim().len() == string.len() {
+            Ok(value)
+        } else {
+            Err(Untrimmed)
+        }
+    }
+}
+
+impl<'a> Normalize<&'a str> for Trimmed {
+    fn normalize(value: &'a str) -> &'a str {
+        value.trim()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Normalize<alloc::string::String> for Trimmed {
+    fn normalize(mut value: alloc::string::String) -> alloc::string::String {
+        value.truncate(value.trim_end().len());
+        value.drain(..value.len() - value.trim_start().len());
+        value
+    }
+}
+
+/// The error returned when a string has leading or trailing whitespace.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Untrimmed;
+
+impl fmt::Display for Untrimmed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must not have leading or trailing whitespace")
+    }
+}
+
+impl core::error::Error for Untrimmed {}
+
+impl ErrorCode for Untrimmed {
+    fn code(&self) -> &'static str {
+        "untrimmed"
+    }
+}
+
+/// A predicate that holds for strings containing at least one non-whitespace character.
+///
+/// This applies to anything implementing `AsRef<str>`. Unlike [`NonEmpty`](super::NonEmpty),
+/// strings containing only whitespace are rejected, which is usually what's meant by a "required"
+/// text field.
+///
+/// ```
+/// use check_mate::{checks::NonBlank, Checked};
+///
+/// assert!(Checked::<_, NonBlank>::refine(" a ").is_ok());
+/// assert!(Checked::<_, NonBlank>::refine(" \n").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NonBlank;
+
+impl Implies<super::NonEmpty> for NonBlank {}
+
+impl<T: AsRef<str>> Predicate<T> for NonBlank {
+    type Err = Blank;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        if value.as_ref().trim().is_empty() {
+            Err(Blank)
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+/// The error returned when a string is empty or only whitespace.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Blank;
+
+impl fmt::Display for Blank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must not be blank")
+    }
+}
+
+impl core::error::Error for Blank {}
+
+impl ErrorCode for Blank {
+    fn code(&self) -> &'static str {
+        "blank"
+    }
+}
+
+/// A predicate that holds for strings without control characters.
+///
+/// This applies to anything implementing `AsRef<str>`, and rejects the C0 and C1 control
+/// characters (including DEL), such as the escape character used by terminal escape sequences.
+/// Setting `ALLOW_WHITESPACE` to `true` allows tabs and newlines (`\t` and `\n`), e.g. for
+/// multi-line text.
+///
+/// ```
+/// use check_mate::{checks::NoControlChars, Checked};
+///
+/// assert!(Checked::<_, NoControlChars>::refine("Ferris").is_ok());
+/// assert!(Checked::<_, NoControlChars>::refine("\u{1b}[2J").is_err());
+/// assert!(Checked::<_, NoControlChars>::refine("a\nb").is_err());
+/// assert!(Checked::<_, NoControlChars<true>>::refine("a\nb").is_ok());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NoControlChars<const ALLOW_WHITESPACE: bool = false>;
+
+impl Implies<NoControlChars<true>> for NoControlChars<false> {}
+
+impl<T: AsRef<str>, const ALLOW_WHITESPACE: bool> Predicate<T>
+    for NoControlChars<ALLOW_WHITESPACE>
+{
+    type Err = IllegalChar;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        match value
+            .as_ref()
+            .char_indices()
+            .find(|(_, c)| c.is_control() && !(ALLOW_WHITESPACE && matches!(c, '\t' | '\n')))
+        {
+            Some((index, found)) => Err(IllegalChar { index, found }),
+            None => Ok(value),
+        }
+    }
+}
+
+/// A predicate that holds for strings without uppercase characters.
+///
+/// This applies to anything implementing `AsRef<str>`, and uses Unicode's definition of uppercase.
+/// Characters without case, such as digits and punctuation, are allowed.
+///
+/// ```
+/// use check_mate::{checks::Lowercase, Checked};
+///
+/// assert!(Checked::<_, Lowercase>::refine("straße-1").is_ok());
+/// assert!(Checked::<_, Lowercase>::refine("Straße").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Lowercase;
+
+/// A predicate that holds for strings without lowercase characters.
+///
+/// Like [`Lowercase`], this uses Unicode's definition of case.
+#[derive(Clone, Copy, Debug)]
+pub struct Uppercase;
+
+/// A predicate that holds for strings without ASCII uppercase characters.
+///
+/// Non-ASCII characters are ignored.
+#[derive(Clone, Copy, Debug)]
+pub struct AsciiLowercase;
+
+/// A predicate that holds for strings without ASCII lowercase characters.
+///
+/// Non-ASCII characters are ignored, so e.g. currency codes can be checked with
+/// `And<Ascii, AsciiUppercase>`.
+#[derive(Clone, Copy, Debug)]
+pub struct AsciiUppercase;
+
+impl Implies<AsciiLowercase> for Lowercase {}
+
+impl Implies<AsciiUppercase> for Uppercase {}
+
+macro_rules! impl_case {
+    ($($predicate:ty => $lowercase:literal, $disallowed:ident;)*) => {$(
+        impl<T: AsRef<str>> Predicate<T> for $predicate {
+            type Err = WrongCase;
+
+            fn refine(value: T) -> Result<T, Self::Err> {
+                match value.as_ref().char_indices().find(|(_, c)| c.$disallowed()) {
+                    Some((index, found)) => Err(WrongCase {
+                        index,
+                        found,
+                        lowercase: $lowercase,
+                    }),
+                    None => Ok(value),
+                }
+            }
+        }
+    )*};
+}
+
+impl_case! {
+    Lowercase => true, is_uppercase;
+    Uppercase => false, is_lowercase;
+    AsciiLowercase => true, is_ascii_uppercase;
+    AsciiUppercase => false, is_ascii_lowercase;
+}
+
+/// The error returned when a string contains a character of the wrong case.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WrongCase {
+    /// The byte index of the first disallowed character.
+    pub index: usize,
+
+    /// The first disallowed character.
+    pub found: char,
+
+    /// Whether the string should have been lowercase (otherwise uppercase).
+    pub lowercase: bool,
+}
+
+impl fmt::Display for WrongCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must be {}, but found {:?} at index {}",
+            if self.lowercase {
+                "lowercase"
+            } else {
+                "uppercase"
+            },
+            self.found,
+            self.index
+        )
+    }
+}
+
+impl core::error::Error for WrongCase {}
+
+impl ErrorCode for WrongCase {
+    fn code(&self) -> &'static str {
+        if self.lowercase {
+            "not_lowercase"
+        } else {
+            "not_uppercase"
+        }
+    }
+}
+
+/// A predicate that holds for URL-safe slugs.
+///
+/// This applies to anything implementing `AsRef<str>`. A slug is one or more words of lowercase
+/// ASCII letters and digits, separated by single hyphens.
+///
+/// ```
+/// use check_mate::{checks::Slug, Checked};
+///
+/// assert!(Checked::<_, Slug>::refine("hello-world-2").is_ok());
+/// assert!(Checked::<_, Slug>::refine("-hello--World").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Slug;
+
+impl Implies<Ascii> for Slug {}
+
+impl Implies<AsciiPrintable> for Slug {}
+
+impl Implies<AsciiLowercase> for Slug {}
+
+impl Implies<Lowercase> for Slug {}
+
+impl Implies<Trimmed> for Slug {}
+
+impl Implies<super::NonEmpty> for Slug {}
+
+impl<T: AsRef<str>> Predicate<T> for Slug {
+    type Err = InvalidSlug;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let valid = value.as_ref().split('-').all(|word| {
+            !word.is_empty()
+                && word
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        });
+        if valid {
+            Ok(value)
+        } else {
+            Err(InvalidSlug)
+        }
+    }
+}
+
+/// The error returned when a string is not a valid slug.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidSlug;
+
+impl fmt::Display for InvalidSlug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must be lowercase letters and digits, separated by single hyphens")
+    }
+}
+
+impl core::error::Error for InvalidSlug {}
+
+impl ErrorCode for InvalidSlug {
+    fn code(&self) -> &'static str {
+        "invalid_slug"
+    }
+}
+
+/// A predicate that holds for strings containing only characters from the [`CharSet`] `S`.
+///
+/// This applies to anything implementing `AsRef<str>`. Some common sets are provided in
+/// [`charset`](super::charset).
+///
+/// ```
+/// use check_mate::{checks::{charset::AsciiAlphanumeric, OnlyChars}, Checked};
+///
+/// assert!(Checked::<_, OnlyChars<AsciiAlphanumeric>>::refine("abc123").is_ok());
+/// assert_eq!(
+///     Checked::<_, OnlyChars<AsciiAlphanumeric>>::refine("abc-123").unwrap_err().to_string(),
+///     "must not contain '-' (at index 3)",
+/// );
+/// ```
+pub struct OnlyChars<S>(core::marker::PhantomData<fn() -> S>);
+
+impl<T: AsRef<str>, S: CharSet> Predicate<T> for OnlyChars<S> {
+    type Err = IllegalChar;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        match value
+            .as_ref()
+            .char_indices()
+            .find(|(_, c)| !S::contains(*c))
+        {
+            Some((index, found)) => Err(IllegalChar { index, found }),
+            None => Ok(value),
+        }
+    }
+}
+
+/// The error returned when a string contains a character that isn't allowed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IllegalChar {
+    /// The byte index of the first illegal character.
+    pub index: usize,
+
+    /// The first illegal character.
+    pub found: char,
+}
+
+impl fmt::Display for IllegalChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "must not contain {:?} (at index {})",
+            self.found, self.index
+        )
+    }
+}
+
+impl core::error::Error for IllegalChar {}
+
+impl ErrorCode for IllegalChar {
+    fn code(&self) -> &'static str {
+        "illegal_char"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::charset::{Alphanumeric, AsciiDigit},
+        Ascii, AsciiLowercase, AsciiPrintable, AsciiUppercase, Blank, IllegalChar, InvalidSlug,
+        Lowercase, NoControlChars, NonAscii, NonBlank, OnlyChars, Slug, Trimmed, Untrimmed,
+        Uppercase, WrongCase,
+    };
+    use crate::Checked;
+
+    #[test]
+    fn ascii() {
+        assert!(Checked::<_, Ascii>::refine("a\tb").is_ok());
+        assert!(Checked::<_, Ascii>::refine(&b"\x7f"[..]).is_ok());
+        assert_eq!(
+            Checked::<_, Ascii>::refine(&b"ab\xff"[..]).map(Checked::into_inner),
+            Err(NonAscii {
+                index: 2,
+                byte: 0xff,
+                printable: false
+            })
+        );
+    }
+
+    #[test]
+    fn ascii_printable() {
+        assert!(Checked::<_, AsciiPrintable>::refine("Hello, world!").is_ok());
+        assert_eq!(
+            Checked::<_, AsciiPrintable>::refine("a\nb").map(Checked::into_inner),
+            Err(NonAscii {
+                index: 1,
+                byte: b'\n',
+                printable: true
+            })
+        );
+
+        let printable = Checked::<_, AsciiPrintable>::refine("abc").unwrap();
+        let ascii: Checked<_, Ascii> = printable.weaken();
+        assert_eq!(*ascii, "abc");
+    }
+
+    #[test]
+    fn trimmed() {
+        assert!(Checked::<_, Trimmed>::refine("a b").is_ok());
+        assert_eq!(
+            Checked::<_, Trimmed>::refine("a\n").map(Checked::into_inner),
+            Err(Untrimmed)
+        );
+        assert_eq!(
+            Checked::<_, Trimmed>::normalize("\t a b ").as_deref(),
+            Ok(&"a b")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn trimmed_alloc() {
+        assert!(Checked::<String, Trimmed>::refine(" a".into()).is_err());
+        assert_eq!(
+            Checked::<String, Trimmed>::normalize("  a b\n".into())
+                .unwrap()
+                .into_inner(),
+            "a b"
+        );
+    }
+
+    #[test]
+    fn non_blank() {
+        assert!(Checked::<_, NonBlank>::refine("a").is_ok());
+        assert_eq!(
+            Checked::<_, NonBlank>::refine("").map(Checked::into_inner),
+            Err(Blank)
+        );
+        assert_eq!(
+            Checked::<_, NonBlank>::refine("\t\u{3000}").map(Checked::into_inner),
+            Err(Blank)
+        );
+
+        let non_blank = Checked::<_, NonBlank>::refine(" a").unwrap();
+        let non_empty: Checked<_, crate::checks::NonEmpty> = non_blank.weaken();
+        assert_eq!(*non_empty, " a");
+    }
+
+    #[test]
+    fn no_control_chars() {
+        assert!(Checked::<_, NoControlChars>::refine("").is_ok());
+        assert_eq!(
+            Checked::<_, NoControlChars>::refine("ab\u{85}").map(Checked::into_inner),
+            Err(IllegalChar {
+                index: 2,
+                found: '\u{85}'
+            })
+        );
+        assert!(Checked::<_, NoControlChars>::refine("\t").is_err());
+        assert!(Checked::<_, NoControlChars<true>>::refine("a\tb\n").is_ok());
+        assert!(Checked::<_, NoControlChars<true>>::refine("a\r\n").is_err());
+        assert!(Checked::<_, NoControlChars<true>>::refine("\u{7f}").is_err());
+
+        let strict = Checked::<_, NoControlChars>::refine("a").unwrap();
+        let _: Checked<_, NoControlChars<true>> = strict.weaken();
+    }
+
+    #[test]
+    fn case() {
+        assert!(Checked::<_, Lowercase>::refine("ab-1").is_ok());
+        assert_eq!(
+            Checked::<_, Lowercase>::refine("aÉ").map(Checked::into_inner),
+            Err(WrongCase {
+                index: 1,
+                found: 'É',
+                lowercase: true
+            })
+        );
+        assert!(Checked::<_, AsciiLowercase>::refine("aÉ").is_ok());
+
+        assert!(Checked::<_, Uppercase>::refine("GBP").is_ok());
+        assert!(Checked::<_, Uppercase>::refine("GBé").is_err());
+        assert!(Checked::<_, AsciiUppercase>::refine("GBé").is_ok());
+        assert_eq!(
+            Checked::<_, AsciiUppercase>::refine("Gb")
+                .unwrap_err()
+                .to_string(),
+            "must be uppercase, but found 'b' at index 1"
+        );
+    }
+
+    #[test]
+    fn slug() {
+        assert!(Checked::<_, Slug>::refine("a").is_ok());
+        assert!(Checked::<_, Slug>::refine("2024-a1-b").is_ok());
+        for invalid in ["", "-a", "a-", "a--b", "a_b", "A", "é"] {
+            assert_eq!(
+                Checked::<_, Slug>::refine(invalid).map(Checked::into_inner),
+                Err(InvalidSlug),
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn only_chars() {
+        assert!(Checked::<_, OnlyChars<Alphanumeric>>::refine("héllo1").is_ok());
+        assert!(Checked::<_, OnlyChars<AsciiDigit>>::refine("").is_ok());
+        assert_eq!(
+            Checked::<_, OnlyChars<AsciiDigit>>::refine("12é3").map(Checked::into_inner),
+            Err(IllegalChar {
+                index: 2,
+                found: 'é'
+            })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ascii_alloc() {
+        assert!(Checked::<String, Ascii>::refine("abc".into()).is_ok());
+        assert!(Checked::<Vec<u8>, Ascii>::refine(vec![0x80]).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for strings in Unicode Normalization Form C (NFC).
+///
+/// This is enabled by the `unicode-normalization` feature, and applies to anything implementing
+/// `AsRef<str>`. Strings that look the same can have different encodings (e.g. `"é"` can be one
+/// code point, or `"e"` followed by a combining accent), so identity-sensitive strings such as
+/// usernames should be normalized before being compared or stored. With the `alloc` feature,
+/// `String`s can be [normalized](crate::Checked::normalize) into NFC.
+///
+/// ```
+/// use check_mate::{checks::Nfc, Checked};
+///
+/// assert!(Checked::<_, Nfc>::refine("caf\u{e9}").is_ok());
+/// assert!(Checked::<_, Nfc>::refine("cafe\u{301}").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Nfc;
+
+impl<T: AsRef<str>> Predicate<T> for Nfc {
+    type Err = NotNfc;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        if unicode_normalization::is_nfc(value.as_ref()) {
+            Ok(value)
+        } else {
+            Err(NotNfc)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl crate::Normalize<alloc::string::String> for Nfc {
+    fn normalize(value: alloc::string::String) -> alloc::string::String {
+        use unicode_normalization::UnicodeNormalization;
+
+        if unicode_normalization::is_nfc(&value) {
+            value
+        } else {
+            value.nfc().collect()
+        }
+    }
+}
+
+/// The error returned when a string is not in NFC.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NotNfc;
+
+impl fmt::Display for NotNfc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must be in Unicode Normalization Form C")
+    }
+}
+
+impl core::error::Error for NotNfc {}
+
+impl ErrorCode for NotNfc {
+    fn code(&self) -> &'static str {
+        "not_nfc"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Nfc, NotNfc};
+    use crate::Checked;
+
+    #[test]
+    fn nfc() {
+        assert!(Checked::<_, Nfc>::refine("").is_ok());
+        assert!(Checked::<_, Nfc>::refine("\u{212b}ngstr\u{f6}m").is_err());
+        assert_eq!(
+            Checked::<_, Nfc>::refine("o\u{308}").map(Checked::into_inner),
+            Err(NotNfc)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn normalize() {
+        assert_eq!(
+            Checked::<String, Nfc>::normalize("cafe\u{301}".into())
+                .unwrap()
+                .into_inner(),
+            "caf\u{e9}"
+        );
+    }
+}
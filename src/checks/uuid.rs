@@ -0,0 +1,142 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for UUIDs in canonical text form.
+///
+/// This applies to anything implementing `AsRef<str>`. The canonical form is 32 hexadecimal digits
+/// in groups of 8-4-4-4-12, separated by hyphens (e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`).
+/// Either case is accepted, but not mixed within the string.
+///
+/// If `V` is non-zero, the UUID must also have that version. The default of `0` accepts any
+/// version.
+///
+/// With the `uuid` feature enabled, checked values can be converted into [`uuid::Uuid`]s.
+///
+/// ```
+/// use check_mate::{checks::Uuid, Checked};
+///
+/// type UserId<'a> = Checked<&'a str, Uuid<4>>;
+///
+/// assert!(UserId::refine("67e55044-10b1-426f-9247-bb680e5fe0c8").is_ok());
+/// assert!(UserId::refine("67e55044-10b1-126f-9247-bb680e5fe0c8").is_err());
+/// assert!(Checked::<_, Uuid>::refine("67e5504410b1426f9247bb680e5fe0c8").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Uuid<const V: u8 = 0>;
+
+impl<T: AsRef<str>, const V: u8> Predicate<T> for Uuid<V> {
+    type Err = InvalidUuid;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let text = value.as_ref().as_bytes();
+        let well_formed = text.len() == 36
+            && text.iter().enumerate().all(|(i, b)| match i {
+                8 | 13 | 18 | 23 => *b == b'-',
+                _ => b.is_ascii_hexdigit(),
+            })
+            && !(text.iter().any(u8::is_ascii_lowercase)
+                && text.iter().any(u8::is_ascii_uppercase));
+        if !well_formed {
+            return Err(InvalidUuid::Format);
+        }
+
+        let actual = match text[14] {
+            digit @ b'0'..=b'9' => digit - b'0',
+            letter => letter.to_ascii_lowercase() - b'a' + 10,
+        };
+        if V != 0 && V != actual {
+            return Err(InvalidUuid::Version {
+                expected: V,
+                actual,
+            });
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<T: AsRef<str>, const V: u8> From<crate::Checked<T, Uuid<V>>> for uuid::Uuid {
+    fn from(value: crate::Checked<T, Uuid<V>>) -> Self {
+        uuid::Uuid::parse_str(value.into_inner().as_ref()).expect("checked to be a valid UUID")
+    }
+}
+
+/// The error returned when a string is not a valid UUID.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidUuid {
+    /// The string was not in canonical form.
+    Format,
+
+    /// The UUID had the wrong version.
+    Version {
+        /// The required version.
+        expected: u8,
+        /// The actual version.
+        actual: u8,
+    },
+}
+
+impl fmt::Display for InvalidUuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Format => f.write_str("must be a UUID in canonical form"),
+            Self::Version { expected, actual } => {
+                write!(
+                    f,
+                    "must be a version {expected} UUID, but was version {actual}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for InvalidUuid {}
+
+impl ErrorCode for InvalidUuid {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Format => "invalid_uuid",
+            Self::Version { .. } => "wrong_uuid_version",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidUuid, Uuid};
+    use crate::Checked;
+
+    #[test]
+    fn uuid() {
+        assert!(Checked::<_, Uuid>::refine("67E55044-10B1-426F-9247-BB680E5FE0C8").is_ok());
+        assert!(Checked::<_, Uuid<1>>::refine("c232ab00-9414-11ec-b3c8-9f6bdeced846").is_ok());
+        assert_eq!(
+            Checked::<_, Uuid>::refine("67e55044-10b1-426F-9247-bb680e5fe0c8")
+                .map(Checked::into_inner),
+            Err(InvalidUuid::Format)
+        );
+        assert_eq!(
+            Checked::<_, Uuid>::refine("{67e55044-10b1-426f-9247-bb680e5fe0c8}")
+                .map(Checked::into_inner),
+            Err(InvalidUuid::Format)
+        );
+        assert_eq!(
+            Checked::<_, Uuid<7>>::refine("67e55044-10b1-426f-9247-bb680e5fe0c8")
+                .map(Checked::into_inner),
+            Err(InvalidUuid::Version {
+                expected: 7,
+                actual: 4
+            })
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn into_uuid() {
+        let id = Checked::<_, Uuid<4>>::refine("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let id = uuid::Uuid::from(id);
+        assert_eq!(id.get_version_num(), 4);
+    }
+}
@@ -0,0 +1,123 @@
+use core::fmt;
+
+use crate::{ErrorCode, Predicate};
+
+/// A predicate that holds for semantic version strings.
+///
+/// This applies to anything implementing `AsRef<str>`, and follows the
+/// [Semantic Versioning 2.0.0](https://semver.org) grammar: `MAJOR.MINOR.PATCH`, with optional
+/// `-pre.release` and `+build.metadata` identifiers. Numeric parts must not have leading zeros.
+///
+/// With the `semver` feature enabled, checked values can be converted into [`semver::Version`]s.
+///
+/// ```
+/// use check_mate::{checks::Semver, Checked};
+///
+/// assert!(Checked::<_, Semver>::refine("1.0.0-alpha.1+build.5").is_ok());
+/// assert!(Checked::<_, Semver>::refine("1.0").is_err());
+/// assert!(Checked::<_, Semver>::refine("01.0.0").is_err());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Semver;
+
+impl<T: AsRef<str>> Predicate<T> for Semver {
+    type Err = InvalidSemver;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let version = value.as_ref();
+        let (version, build) = match version.split_once('+') {
+            Some((version, build)) => (version, Some(build)),
+            None => (version, None),
+        };
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (version, None),
+        };
+
+        let mut numbers = core.split('.');
+        let valid = numbers.by_ref().take(3).filter(|n| is_number(n)).count() == 3
+            && numbers.next().is_none()
+            && pre.is_none_or(|pre| {
+                pre.split('.')
+                    .all(|id| is_identifier(id) && (is_number(id) || !is_digits(id)))
+            })
+            && build.is_none_or(|build| build.split('.').all(is_identifier));
+
+        if valid {
+            Ok(value)
+        } else {
+            Err(InvalidSemver)
+        }
+    }
+}
+
+fn is_digits(part: &str) -> bool {
+    !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_number(part: &str) -> bool {
+    is_digits(part) && (part == "0" || !part.starts_with('0'))
+}
+
+fn is_identifier(part: &str) -> bool {
+    !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+#[cfg(feature = "semver")]
+impl<T: AsRef<str>> From<crate::Checked<T, Semver>> for semver::Version {
+    fn from(value: crate::Checked<T, Semver>) -> Self {
+        semver::Version::parse(value.into_inner().as_ref()).expect("checked to be a valid version")
+    }
+}
+
+/// The error returned when a string is not a valid semantic version.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidSemver;
+
+impl fmt::Display for InvalidSemver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("must be a semantic version")
+    }
+}
+
+impl core::error::Error for InvalidSemver {}
+
+impl ErrorCode for InvalidSemver {
+    fn code(&self) -> &'static str {
+        "invalid_semver"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Semver;
+    use crate::Checked;
+
+    fn is_valid(version: &str) -> bool {
+        Checked::<_, Semver>::refine(version).is_ok()
+    }
+
+    #[test]
+    fn semver() {
+        assert!(is_valid("0.0.0"));
+        assert!(is_valid("10.20.30-rc.1.x-y"));
+        assert!(is_valid("1.0.0+001.sha-5114f85"));
+        assert!(is_valid("1.0.0-0A.is.legal"));
+
+        assert!(!is_valid("1.2.3.4"));
+        assert!(!is_valid("1.2.03"));
+        assert!(!is_valid("1.2.3-01"));
+        assert!(!is_valid("1.2.3-"));
+        assert!(!is_valid("1.2.3-a..b"));
+        assert!(!is_valid("1.2.3+"));
+        assert!(!is_valid("v1.2.3"));
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn into_version() {
+        let version = Checked::<_, Semver>::refine("1.2.3-beta").unwrap();
+        let version = semver::Version::from(version);
+        assert_eq!((version.major, version.pre.as_str()), (1, "beta"));
+    }
+}
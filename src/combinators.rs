@@ -0,0 +1,888 @@
+//! Combinators for adapting and composing [`Check`] implementations.
+//!
+//! Combinators are usually constructed using the methods on [`CheckExt`], which is implemented for
+//! every [`Check`].
+//!
+//! [`And`], [`Or`], and [`Not`] also implement [`Predicate`] when their components do, so they can
+//! be used to declare compound refinements such as `Checked<u32, And<NonZero, Even>>`. [`All`] and
+//! [`Any`] lift predicates over elements to predicates over collections, such as
+//! `Checked<Vec<u32>, All<NonZero>>`.
+
+use core::{fmt, iter::FromIterator, marker::PhantomData};
+
+use crate::{Check, Checked, ErrorCode, Predicate};
+
+/// Extension methods for [`Check`] implementations.
+pub trait CheckExt: Check + Sized {
+    /// Map the error returned by the check using `f`.
+    ///
+    /// This is useful for reusing existing checks in code with a unified error type.
+    ///
+    /// ```
+    /// use check_mate::{Check, CheckExt, Checked};
+    ///
+    /// struct Even(u32);
+    ///
+    /// impl Check for Even {
+    ///     type Ok = u32;
+    ///     type Err = &'static str;
+    ///
+    ///     fn check(self) -> Result<Self::Ok, Self::Err> {
+    ///         if self.0 % 2 == 0 { Ok(self.0) } else { Err("odd") }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Invalid(&'static str),
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Checked::try_from(Even(3).map_err(Error::Invalid)).err(),
+    ///     Some(Error::Invalid("odd")),
+    /// );
+    /// ```
+    fn map_err<F, E>(self, f: F) -> MapErr<Self, F>
+    where
+        F: FnOnce(Self::Err) -> E,
+    {
+        MapErr { check: self, f }
+    }
+
+    /// Conjoin this check with `other`.
+    ///
+    /// The resulting check passes if both checks pass, and returns both `Ok` values. `self` is
+    /// checked first, and `other` is only checked if `self` passes.
+    ///
+    /// ```
+    /// use check_mate::{combinators::AndError, Check, CheckExt, Checked};
+    ///
+    /// struct NonEmpty<'a>(&'a str);
+    ///
+    /// impl<'a> Check for NonEmpty<'a> {
+    ///     type Ok = &'a str;
+    ///     type Err = &'static str;
+    ///
+    ///     fn check(self) -> Result<Self::Ok, Self::Err> {
+    ///         if self.0.is_empty() { Err("empty") } else { Ok(self.0) }
+    ///     }
+    /// }
+    ///
+    /// struct Ascii<'a>(&'a str);
+    ///
+    /// impl<'a> Check for Ascii<'a> {
+    ///     type Ok = &'a str;
+    ///     type Err = &'static str;
+    ///
+    ///     fn check(self) -> Result<Self::Ok, Self::Err> {
+    ///         if self.0.is_ascii() { Ok(self.0) } else { Err("not ascii") }
+    ///     }
+    /// }
+    ///
+    /// let input = "héllo";
+    /// assert_eq!(
+    ///     Checked::try_from(NonEmpty(input).and(Ascii(input))).err(),
+    ///     Some(AndError::Right("not ascii")),
+    /// );
+    /// ```
+    fn and<B: Check>(self, other: B) -> And<Self, B> {
+        And(self, other)
+    }
+
+    /// Disjoin this check with `other`.
+    ///
+    /// The resulting check passes if either check passes, returning the `Ok` value of the first
+    /// check that passed. `other` is only checked if `self` fails, and if both checks fail then
+    /// both errors are returned.
+    ///
+    /// ```
+    /// use check_mate::{combinators::OrError, Check, CheckExt, Checked};
+    ///
+    /// struct Decimal<'a>(&'a str);
+    ///
+    /// impl<'a> Check for Decimal<'a> {
+    ///     type Ok = u32;
+    ///     type Err = &'static str;
+    ///
+    ///     fn check(self) -> Result<Self::Ok, Self::Err> {
+    ///         self.0.parse().map_err(|_| "not decimal")
+    ///     }
+    /// }
+    ///
+    /// struct Hex<'a>(&'a str);
+    ///
+    /// impl<'a> Check for Hex<'a> {
+    ///     type Ok = u32;
+    ///     type Err = &'static str;
+    ///
+    ///     fn check(self) -> Result<Self::Ok, Self::Err> {
+    ///         u32::from_str_radix(self.0, 16).map_err(|_| "not hex")
+    ///     }
+    /// }
+    ///
+    /// let id = |input| Checked::try_from(Decimal(input).or(Hex(input)));
+    /// assert_eq!(id("10").as_deref(), Ok(&10));
+    /// assert_eq!(id("ff").as_deref(), Ok(&255));
+    /// assert_eq!(
+    ///     id("zz").err(),
+    ///     Some(OrError { left: "not decimal", right: "not hex" }),
+    /// );
+    /// ```
+    fn or<B: Check<Ok = Self::Ok>>(self, other: B) -> Or<Self, B> {
+        Or(self, other)
+    }
+
+    /// Negate this check.
+    ///
+    /// The resulting check passes if `self` fails, and fails with `error` if `self` passes. Since
+    /// the value is consumed by `self`, the resulting `Ok` value is `self`'s error. Checks intended
+    /// to be negated may want to return the checked value as their error.
+    ///
+    /// ```
+    /// use check_mate::{Check, CheckExt, Checked};
+    ///
+    /// struct Denied<'a>(&'a str);
+    ///
+    /// impl<'a> Check for Denied<'a> {
+    ///     type Ok = ();
+    ///     type Err = &'a str;
+    ///
+    ///     fn check(self) -> Result<Self::Ok, Self::Err> {
+    ///         match self.0 {
+    ///             "root" | "admin" => Ok(()),
+    ///             name => Err(name),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let username = |name| Checked::try_from(Denied(name).not("username is reserved"));
+    /// assert_eq!(username("ferris").as_deref(), Ok(&"ferris"));
+    /// assert_eq!(username("root").err(), Some("username is reserved"));
+    /// ```
+    fn not<E>(self, error: E) -> Not<Self, E> {
+        Not(self, error)
+    }
+
+    /// Chain another check onto this one.
+    ///
+    /// If `self` passes, its `Ok` value is passed to `f` to construct the next check, and the
+    /// resulting check's `Ok` value is that of the next check. This allows pipelines that parse a
+    /// value and then refine it further.
+    ///
+    /// ```
+    /// use check_mate::{combinators::ThenError, Check, CheckExt, Checked};
+    ///
+    /// struct Utf8(Vec<u8>);
+    ///
+    /// impl Check for Utf8 {
+    ///     type Ok = String;
+    ///     type Err = &'static str;
+    ///
+    ///     fn check(self) -> Result<Self::Ok, Self::Err> {
+    ///         String::from_utf8(self.0).map_err(|_| "invalid utf-8")
+    ///     }
+    /// }
+    ///
+    /// struct Short(String);
+    ///
+    /// impl Check for Short {
+    ///     type Ok = String;
+    ///     type Err = &'static str;
+    ///
+    ///     fn check(self) -> Result<Self::Ok, Self::Err> {
+    ///         if self.0.chars().count() <= 5 { Ok(self.0) } else { Err("too long") }
+    ///     }
+    /// }
+    ///
+    /// let name = |bytes: &[u8]| Checked::try_from(Utf8(bytes.to_vec()).then(Short));
+    /// assert_eq!(name(b"hello").as_deref().map(String::as_str), Ok("hello"));
+    /// assert_eq!(name(b"\xff").err(), Some(ThenError::First("invalid utf-8")));
+    /// assert_eq!(name(b"hello world").err(), Some(ThenError::Second("too long")));
+    /// ```
+    fn then<F, B>(self, f: F) -> Then<Self, F>
+    where
+        F: FnOnce(Self::Ok) -> B,
+        B: Check,
+    {
+        Then(self, f)
+    }
+}
+
+impl<C: Check> CheckExt for C {}
+
+/// A check with a mapped error.
+///
+/// This is created by [`CheckExt::map_err`].
+#[derive(Clone, Copy, Debug)]
+pub struct MapErr<C, F> {
+    check: C,
+    f: F,
+}
+
+impl<C, F, E> Check for MapErr<C, F>
+where
+    C: Check,
+    F: FnOnce(C::Err) -> E,
+{
+    type Ok = C::Ok;
+    type Err = E;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        self.check.check().map_err(self.f)
+    }
+}
+
+/// A conjunction of two checks.
+///
+/// This is created by [`CheckExt::and`].
+#[derive(Clone, Copy, Debug)]
+pub struct And<A, B>(A, B);
+
+impl<A: Check, B: Check> Check for And<A, B> {
+    type Ok = (A::Ok, B::Ok);
+    type Err = AndError<A::Err, B::Err>;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        let a = self.0.check().map_err(AndError::Left)?;
+        let b = self.1.check().map_err(AndError::Right)?;
+        Ok((a, b))
+    }
+}
+
+impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for And<A, B> {
+    type Err = AndError<A::Err, B::Err>;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let value = A::refine(value).map_err(AndError::Left)?;
+        B::refine(value).map_err(AndError::Right)
+    }
+
+    #[cfg(feature = "schemars")]
+    fn constrain_schema(schema: &mut schemars::Schema) {
+        A::constrain_schema(schema);
+        B::constrain_schema(schema);
+    }
+}
+
+impl<T, A, B> Checked<T, And<A, B>> {
+    /// Split a conjunction into proofs for each component, by reference.
+    ///
+    /// This allows functions that only require one of the component invariants to accept the
+    /// weaker proof, without checking the value again.
+    ///
+    /// ```
+    /// use check_mate::{combinators::And, Checked, Predicate};
+    ///
+    /// struct NonZero;
+    ///
+    /// impl Predicate<u32> for NonZero {
+    ///     type Err = &'static str;
+    ///
+    ///     fn refine(value: u32) -> Result<u32, Self::Err> {
+    ///         if value == 0 { Err("zero") } else { Ok(value) }
+    ///     }
+    /// }
+    ///
+    /// struct Even;
+    ///
+    /// impl Predicate<u32> for Even {
+    ///     type Err = &'static str;
+    ///
+    ///     fn refine(value: u32) -> Result<u32, Self::Err> {
+    ///         if value % 2 == 0 { Ok(value) } else { Err("odd") }
+    ///     }
+    /// }
+    ///
+    /// fn halve(n: Checked<&u32, Even>) -> u32 {
+    ///     **n / 2
+    /// }
+    ///
+    /// let n = Checked::<u32, And<NonZero, Even>>::refine(8).unwrap();
+    /// let (_, even) = n.split_ref();
+    /// assert_eq!(halve(even), 4);
+    /// ```
+    pub fn split_ref(&self) -> (Checked<&T, A>, Checked<&T, B>) {
+        (
+            Checked::new_unchecked(&**self),
+            Checked::new_unchecked(&**self),
+        )
+    }
+
+    /// Keep only the proof for the left-hand component.
+    pub fn into_left(self) -> Checked<T, A> {
+        Checked::new_unchecked(self.into_inner())
+    }
+
+    /// Keep only the proof for the right-hand component.
+    pub fn into_right(self) -> Checked<T, B> {
+        Checked::new_unchecked(self.into_inner())
+    }
+}
+
+/// The error returned when an [`And`] check fails, identifying which side failed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AndError<A, B> {
+    /// The left-hand check failed.
+    Left(A),
+
+    /// The right-hand check failed.
+    Right(B),
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for AndError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left(error) => error.fmt(f),
+            Self::Right(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<A, B> core::error::Error for AndError<A, B>
+where
+    A: core::error::Error + 'static,
+    B: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Left(error) => Some(error),
+            Self::Right(error) => Some(error),
+        }
+    }
+}
+
+impl<A: ErrorCode, B: ErrorCode> ErrorCode for AndError<A, B> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Left(error) => error.code(),
+            Self::Right(error) => error.code(),
+        }
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        match self {
+            Self::Left(error) => error.numeric_code(),
+            Self::Right(error) => error.numeric_code(),
+        }
+    }
+}
+
+/// A disjunction of two checks.
+///
+/// This is created by [`CheckExt::or`].
+#[derive(Clone, Copy, Debug)]
+pub struct Or<A, B>(A, B);
+
+impl<A, B> Check for Or<A, B>
+where
+    A: Check,
+    B: Check<Ok = A::Ok>,
+{
+    type Ok = A::Ok;
+    type Err = OrError<A::Err, B::Err>;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        match self.0.check() {
+            Ok(value) => Ok(value),
+            Err(left) => self.1.check().map_err(|right| OrError { left, right }),
+        }
+    }
+}
+
+/// When used as a [`Predicate`], the value must be `Clone` so that it can be passed to the
+/// right-hand predicate if the left-hand predicate fails.
+impl<T, A, B> Predicate<T> for Or<A, B>
+where
+    T: Clone,
+    A: Predicate<T>,
+    B: Predicate<T>,
+{
+    type Err = OrError<A::Err, B::Err>;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        match A::refine(value.clone()) {
+            Ok(value) => Ok(value),
+            Err(left) => B::refine(value).map_err(|right| OrError { left, right }),
+        }
+    }
+}
+
+/// The error returned when an [`Or`] check fails, containing the errors from both sides.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct OrError<A, B> {
+    /// The error from the left-hand check.
+    pub left: A,
+
+    /// The error from the right-hand check.
+    pub right: B,
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for OrError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} and {}", self.left, self.right)
+    }
+}
+
+impl<A, B> core::error::Error for OrError<A, B>
+where
+    A: core::error::Error,
+    B: core::error::Error,
+{
+}
+
+/// Since both sides failed, `OrError` has the code `none_matched`, like [`AnyError`]. The codes of
+/// the [`left`](OrError::left) and [`right`](OrError::right) errors are still available.
+impl<A, B> ErrorCode for OrError<A, B> {
+    fn code(&self) -> &'static str {
+        "none_matched"
+    }
+}
+
+/// A negated check.
+///
+/// This is created by [`CheckExt::not`]. The error is the one given to `not`, so its
+/// [`ErrorCode`] is used as-is.
+#[derive(Clone, Copy, Debug)]
+pub struct Not<C, E>(C, E);
+
+impl<C: Check, E> Check for Not<C, E> {
+    type Ok = C::Err;
+    type Err = E;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        match self.0.check() {
+            Ok(_) => Err(self.1),
+            Err(error) => Ok(error),
+        }
+    }
+}
+
+/// A sequence of two checks.
+///
+/// This is created by [`CheckExt::then`].
+#[derive(Clone, Copy, Debug)]
+pub struct Then<A, F>(A, F);
+
+impl<A, F, B> Check for Then<A, F>
+where
+    A: Check,
+    F: FnOnce(A::Ok) -> B,
+    B: Check,
+{
+    type Ok = B::Ok;
+    type Err = ThenError<A::Err, B::Err>;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        let value = self.0.check().map_err(ThenError::First)?;
+        (self.1)(value).check().map_err(ThenError::Second)
+    }
+}
+
+/// The error returned when a [`Then`] check fails, identifying which check failed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ThenError<A, B> {
+    /// The first check failed.
+    First(A),
+
+    /// The second check failed.
+    Second(B),
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for ThenError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::First(error) => error.fmt(f),
+            Self::Second(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<A, B> core::error::Error for ThenError<A, B>
+where
+    A: core::error::Error + 'static,
+    B: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::First(error) => Some(error),
+            Self::Second(error) => Some(error),
+        }
+    }
+}
+
+impl<A: ErrorCode, B: ErrorCode> ErrorCode for ThenError<A, B> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::First(error) => error.code(),
+            Self::Second(error) => error.code(),
+        }
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        match self {
+            Self::First(error) => error.numeric_code(),
+            Self::Second(error) => error.numeric_code(),
+        }
+    }
+}
+
+/// When used as a [`Predicate`], the error is constructed with `E::default()`, and the value must be
+/// `Clone` so that it can be returned if the inner predicate fails.
+impl<T, C, E> Predicate<T> for Not<C, E>
+where
+    T: Clone,
+    C: Predicate<T>,
+    E: Default,
+{
+    type Err = E;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        match C::refine(value.clone()) {
+            Ok(_) => Err(E::default()),
+            Err(_) => Ok(value),
+        }
+    }
+}
+
+/// A predicate that holds if every element of a collection satisfies `C`.
+///
+/// This is a predicate over any collection that can be iterated by value and collected back again
+/// (e.g. `Vec<T>` or `BTreeSet<T>`). The error identifies the first element that failed.
+///
+/// ```
+/// use check_mate::{combinators::All, Checked, Predicate};
+///
+/// struct NonZero;
+///
+/// impl Predicate<u32> for NonZero {
+///     type Err = &'static str;
+///
+///     fn refine(value: u32) -> Result<u32, Self::Err> {
+///         if value == 0 { Err("zero") } else { Ok(value) }
+///     }
+/// }
+///
+/// assert!(Checked::<Vec<u32>, All<NonZero>>::refine(vec![1, 2, 3]).is_ok());
+///
+/// let error = Checked::<Vec<u32>, All<NonZero>>::refine(vec![1, 0, 3]).unwrap_err();
+/// assert_eq!((error.index, error.error), (1, "zero"));
+/// ```
+pub struct All<C>(PhantomData<fn() -> C>);
+
+impl<T, C> Predicate<T> for All<C>
+where
+    T: IntoIterator + FromIterator<T::Item>,
+    C: Predicate<T::Item>,
+{
+    type Err = AllError<C::Err>;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        value
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| C::refine(item).map_err(|error| AllError { index, error }))
+            .collect()
+    }
+}
+
+/// The error returned when an [`All`] predicate fails.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AllError<E> {
+    /// The index of the first element that failed.
+    pub index: usize,
+
+    /// The error for the element that failed.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for AllError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "element {}: {}", self.index, self.error)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for AllError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<E: ErrorCode> ErrorCode for AllError<E> {
+    fn code(&self) -> &'static str {
+        self.error.code()
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        self.error.numeric_code()
+    }
+}
+
+/// A predicate that holds if at least one element of a collection satisfies `C`.
+///
+/// Like [`All`], this is a predicate over any collection that can be iterated by value and
+/// collected back again. Elements must be `Clone`, so that they can be retained when they don't
+/// satisfy `C`.
+pub struct Any<C>(PhantomData<fn() -> C>);
+
+impl<T, C> Predicate<T> for Any<C>
+where
+    T: IntoIterator + FromIterator<T::Item>,
+    T::Item: Clone,
+    C: Predicate<T::Item>,
+{
+    type Err = AnyError;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        let mut matched = false;
+        let value = value
+            .into_iter()
+            .inspect(|item| matched = matched || C::refine(item.clone()).is_ok())
+            .collect();
+        if matched {
+            Ok(value)
+        } else {
+            Err(AnyError)
+        }
+    }
+}
+
+/// The error returned when an [`Any`] predicate fails.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AnyError;
+
+impl fmt::Display for AnyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no element matched")
+    }
+}
+
+impl core::error::Error for AnyError {}
+
+impl ErrorCode for AnyError {
+    fn code(&self) -> &'static str {
+        "none_matched"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        All, AllError, And, AndError, Any, AnyError, CheckExt, Not, Or, OrError, ThenError,
+    };
+    use crate::{Check, Checked, ErrorCode, Predicate};
+
+    struct LessThan10(usize);
+
+    impl Check for LessThan10 {
+        type Ok = usize;
+        type Err = &'static str;
+
+        fn check(self) -> Result<Self::Ok, Self::Err> {
+            if self.0 < 10 {
+                Ok(self.0)
+            } else {
+                Err("too big")
+            }
+        }
+    }
+
+    struct Even;
+
+    impl Predicate<usize> for Even {
+        type Err = &'static str;
+
+        fn refine(value: usize) -> Result<usize, Self::Err> {
+            if value.is_multiple_of(2) {
+                Ok(value)
+            } else {
+                Err("odd")
+            }
+        }
+    }
+
+    struct Small;
+
+    impl Predicate<usize> for Small {
+        type Err = &'static str;
+
+        fn refine(value: usize) -> Result<usize, Self::Err> {
+            LessThan10(value).check()
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    struct TooSmall;
+
+    impl ErrorCode for TooSmall {
+        fn code(&self) -> &'static str {
+            "too_small"
+        }
+
+        fn numeric_code(&self) -> Option<u32> {
+            Some(400)
+        }
+    }
+
+    #[test]
+    fn map_err() {
+        assert_eq!(
+            Checked::try_from(LessThan10(3).map_err(str::len)).as_deref(),
+            Ok(&3)
+        );
+        assert_eq!(
+            Checked::try_from(LessThan10(11).map_err(str::len)).as_deref(),
+            Err(&7)
+        );
+    }
+
+    #[test]
+    fn and() {
+        assert_eq!(
+            Checked::try_from(LessThan10(3).and(LessThan10(4))).as_deref(),
+            Ok(&(3, 4))
+        );
+        assert_eq!(
+            Checked::try_from(LessThan10(11).and(LessThan10(4))).as_deref(),
+            Err(&AndError::Left("too big"))
+        );
+        assert_eq!(
+            Checked::try_from(LessThan10(3).and(LessThan10(12))).as_deref(),
+            Err(&AndError::Right("too big"))
+        );
+    }
+
+    #[test]
+    fn or() {
+        assert_eq!(
+            Checked::try_from(LessThan10(3).or(LessThan10(11))).as_deref(),
+            Ok(&3)
+        );
+        assert_eq!(
+            Checked::try_from(LessThan10(11).or(LessThan10(4))).as_deref(),
+            Ok(&4)
+        );
+        assert_eq!(
+            Checked::try_from(LessThan10(11).or(LessThan10(12))).as_deref(),
+            Err(&OrError {
+                left: "too big",
+                right: "too big"
+            })
+        );
+    }
+
+    #[test]
+    fn not() {
+        assert_eq!(
+            Checked::try_from(LessThan10(11).not("too small")).as_deref(),
+            Ok(&"too big")
+        );
+        assert_eq!(
+            Checked::try_from(LessThan10(3).not("too small")).as_deref(),
+            Err(&"too small")
+        );
+    }
+
+    #[test]
+    fn error_codes() {
+        type Big = Not<Small, TooSmall>;
+
+        let error = Checked::<_, Big>::refine(3).unwrap_err();
+        assert_eq!(error.code(), "too_small");
+
+        let error = Checked::<_, Or<Big, Big>>::refine(3).unwrap_err();
+        assert_eq!(error.code(), "none_matched");
+        assert_eq!(error.numeric_code(), None);
+        assert_eq!(error.right.code(), "too_small");
+
+        let error = Checked::<_, And<Big, Big>>::refine(4).unwrap_err();
+        assert_eq!(error.code(), "too_small");
+        assert_eq!(error.numeric_code(), Some(400));
+    }
+
+    #[test]
+    fn then() {
+        assert_eq!(
+            Checked::try_from(LessThan10(3).then(|n| LessThan10(n * 3))).as_deref(),
+            Ok(&9)
+        );
+        assert_eq!(
+            Checked::try_from(LessThan10(11).then(|n| LessThan10(n * 3))).as_deref(),
+            Err(&ThenError::First("too big"))
+        );
+        assert_eq!(
+            Checked::try_from(LessThan10(4).then(|n| LessThan10(n * 3))).as_deref(),
+            Err(&ThenError::Second("too big"))
+        );
+    }
+
+    #[test]
+    fn predicates() {
+        type SmallEven = And<Small, Even>;
+        type SmallOrEven = Or<Small, Even>;
+        type Big = Not<Small, TooSmall>;
+
+        assert_eq!(Checked::<_, SmallEven>::refine(4).as_deref(), Ok(&4));
+        assert_eq!(
+            Checked::<_, SmallEven>::refine(12).as_deref(),
+            Err(&AndError::Left("too big"))
+        );
+        assert_eq!(
+            Checked::<_, SmallEven>::refine(5).as_deref(),
+            Err(&AndError::Right("odd"))
+        );
+
+        assert_eq!(Checked::<_, SmallOrEven>::refine(5).as_deref(), Ok(&5));
+        assert_eq!(Checked::<_, SmallOrEven>::refine(12).as_deref(), Ok(&12));
+        assert_eq!(
+            Checked::<_, SmallOrEven>::refine(13).as_deref(),
+            Err(&OrError {
+                left: "too big",
+                right: "odd"
+            })
+        );
+
+        assert_eq!(Checked::<_, Big>::refine(12).as_deref(), Ok(&12));
+        assert_eq!(Checked::<_, Big>::refine(3).as_deref(), Err(&TooSmall));
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(
+            Checked::<_, All<Even>>::refine(vec![2, 4, 6]).as_deref(),
+            Ok(&vec![2, 4, 6])
+        );
+        assert_eq!(
+            Checked::<_, All<Even>>::refine(vec![2, 3, 5]).as_deref(),
+            Err(&AllError {
+                index: 1,
+                error: "odd"
+            })
+        );
+        assert!(Checked::<Vec<usize>, All<Even>>::refine(vec![]).is_ok());
+    }
+
+    #[test]
+    fn any() {
+        assert_eq!(
+            Checked::<_, Any<Even>>::refine(vec![1, 4, 5]).as_deref(),
+            Ok(&vec![1, 4, 5])
+        );
+        assert_eq!(
+            Checked::<_, Any<Even>>::refine(vec![1, 3, 5]).as_deref(),
+            Err(&AnyError)
+        );
+        assert!(Checked::<Vec<usize>, Any<Even>>::refine(vec![]).is_err());
+    }
+
+    #[test]
+    fn split() {
+        let checked = Checked::<_, And<Small, Even>>::refine(4).unwrap();
+
+        let (small, even): (Checked<&usize, Small>, Checked<&usize, Even>) = checked.split_ref();
+        assert_eq!((**small, **even), (4, 4));
+
+        let small: Checked<usize, Small> = checked.clone().into_left();
+        let even: Checked<usize, Even> = checked.into_right();
+        assert_eq!((*small, *even), (4, 4));
+    }
+}
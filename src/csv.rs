@@ -0,0 +1,216 @@
+//! Utilities for checking records read from CSV files.
+//!
+//! This module is enabled by the `csv` feature. [`deserialize`] reads each record from a
+//! [`csv::Reader`] into a `T`, and checks it. Records that fail to parse or fail their checks are
+//! reported with their line numbers, so bad records can be skipped or reported without aborting
+//! the import.
+//!
+//! ```
+//! use check_mate::Check;
+//!
+//! #[derive(Debug, serde::Deserialize)]
+//! struct Product {
+//!     name: String,
+//!     price: u32,
+//! }
+//!
+//! impl Check for Product {
+//!     type Ok = Self;
+//!     type Err = &'static str;
+//!
+//!     fn check(self) -> Result<Self, Self::Err> {
+//!         if self.price > 0 { Ok(self) } else { Err("price must be positive") }
+//!     }
+//! }
+//!
+//! let data = "name,price\nwidget,5\ngadget,0\n";
+//! let mut reader = csv::Reader::from_reader(data.as_bytes());
+//! let records: Vec<_> = check_mate::csv::deserialize::<_, Product>(&mut reader).collect();
+//!
+//! assert_eq!(records[0].as_ref().unwrap().name, "widget");
+//!
+//! let error = records[1].as_ref().unwrap_err();
+//! assert_eq!(error.line(), Some(3));
+//! assert_eq!(error.to_string(), "line 3: price must be positive");
+//! ```
+
+use std::{fmt, io, marker::PhantomData};
+
+use csv::{Reader, StringRecord};
+use serde::de::DeserializeOwned;
+
+use crate::{Check, Checked, ErrorCode};
+
+/// Deserialize and check the records of a CSV file.
+///
+/// Like [`Reader::deserialize`], headers are used to match fields by name if the reader has them.
+pub fn deserialize<R: io::Read, T>(reader: &mut Reader<R>) -> CheckedRecords<'_, R, T> {
+    CheckedRecords {
+        reader,
+        headers: None,
+        record: StringRecord::new(),
+        value: PhantomData,
+    }
+}
+
+/// An iterator over checked records, created by [`deserialize`].
+pub struct CheckedRecords<'r, R, T> {
+    reader: &'r mut Reader<R>,
+    headers: Option<StringRecord>,
+    record: StringRecord,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<R, T> fmt::Debug for CheckedRecords<'_, R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CheckedRecords")
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R, T> CheckedRecords<'_, R, T>
+where
+    R: io::Read,
+    T: DeserializeOwned + Check<Ok = T>,
+{
+    fn read(&mut self) -> Result<Option<Checked<T>>, RecordError<T::Err>> {
+        if self.headers.is_none() && self.reader.has_headers() {
+            self.headers = Some(self.reader.headers()?.clone());
+        }
+        if !self.reader.read_record(&mut self.record)? {
+            return Ok(None);
+        }
+        let value: T = self.record.deserialize(self.headers.as_ref())?;
+        let line = self.record.position().map_or(0, csv::Position::line);
+        Checked::try_from(value)
+            .map(Some)
+            .map_err(|error| RecordError::Invalid { line, error })
+    }
+}
+
+impl<R, T> Iterator for CheckedRecords<'_, R, T>
+where
+    R: io::Read,
+    T: DeserializeOwned + Check<Ok = T>,
+{
+    type Item = Result<Checked<T>, RecordError<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+/// An error from reading or checking a CSV record.
+#[derive(Debug)]
+pub enum RecordError<E> {
+    /// The record couldn't be read or deserialized.
+    Csv(csv::Error),
+
+    /// The record failed its check.
+    Invalid {
+        /// The line the record started on.
+        line: u64,
+
+        /// The error from the check.
+        error: E,
+    },
+}
+
+impl<E> RecordError<E> {
+    /// The line the record started on, if it's known.
+    #[must_use]
+    pub fn line(&self) -> Option<u64> {
+        match self {
+            Self::Csv(error) => error.position().map(csv::Position::line),
+            Self::Invalid { line, .. } => Some(*line),
+        }
+    }
+}
+
+impl<E> From<csv::Error> for RecordError<E> {
+    fn from(error: csv::Error) -> Self {
+        Self::Csv(error)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for RecordError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Csv(error) => error.fmt(f),
+            Self::Invalid { line, error } => write!(f, "line {line}: {error}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for RecordError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Csv(error) => Some(error),
+            Self::Invalid { error, .. } => Some(error),
+        }
+    }
+}
+
+impl<E: ErrorCode> ErrorCode for RecordError<E> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Csv(_) => "csv",
+            Self::Invalid { error, .. } => error.code(),
+        }
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        match self {
+            Self::Csv(_) => None,
+            Self::Invalid { error, .. } => error.numeric_code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deserialize, RecordError};
+    use crate::checks::{Bounded, NonZero};
+    use crate::Checked;
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Row {
+        id: Checked<u32, NonZero>,
+        score: u8,
+    }
+
+    impl crate::Check for Row {
+        type Ok = Self;
+        type Err = crate::checks::OutOfRange<i128>;
+
+        fn check(self) -> Result<Self, Self::Err> {
+            Checked::<_, Bounded<0, 100>>::refine(self.score)?;
+            Ok(self)
+        }
+    }
+
+    #[test]
+    fn records() {
+        let data = "id,score\n1,50\n0,50\n2,101\nx\n3,100\n";
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        let records: Vec<_> = deserialize::<_, Row>(&mut reader).collect();
+        assert_eq!(records.len(), 5);
+
+        assert_eq!(*records[0].as_ref().unwrap().id, 1);
+
+        // Checks in the record's fields are reported as deserialization errors.
+        let error = records[1].as_ref().unwrap_err();
+        assert!(matches!(error, RecordError::Csv(_)));
+        assert_eq!(error.line(), Some(3));
+
+        let error = records[2].as_ref().unwrap_err();
+        assert!(matches!(error, RecordError::Invalid { line: 4, .. }));
+
+        let error = records[3].as_ref().unwrap_err();
+        assert!(matches!(error, RecordError::Csv(_)));
+        assert_eq!(error.line(), Some(5));
+
+        assert_eq!(records[4].as_ref().unwrap().score, 100);
+    }
+}
@@ -0,0 +1,135 @@
+//! Support code for `#[derive(Check)]`.
+//!
+//! This is not part of the public API, and may change without notice.
+
+use alloc::{format, string::String};
+use core::{fmt, ops::RangeBounds};
+
+#[cfg(feature = "email")]
+use crate::{checks::InvalidEmail, Predicate};
+use crate::{
+    checks::{Empty, InvalidLength, Length},
+    Check, Checked,
+};
+
+/// Check that a field is within a range.
+///
+/// # Errors
+///
+/// This will return a message naming the range if the value is outside of it.
+pub fn range<T, R>(value: &T, range: R) -> Result<(), String>
+where
+    T: PartialOrd + ?Sized,
+    R: RangeBounds<T> + fmt::Debug,
+{
+    if range.contains(value) {
+        Ok(())
+    } else {
+        Err(format!("must be in the range {range:?}"))
+    }
+}
+
+/// Check that a field is not empty.
+///
+/// # Errors
+///
+/// This will return [`Empty`] if the value is empty.
+pub fn non_empty<T: Length + ?Sized>(value: &T) -> Result<(), Empty> {
+    if value.is_empty() {
+        Err(Empty)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a field's length is within bounds.
+///
+/// # Errors
+///
+/// This will return [`InvalidLength`] if the length is out of bounds.
+pub fn length<T: Length + ?Sized>(
+    value: &T,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), InvalidLength> {
+    InvalidLength::check(value, min, max).map(drop)
+}
+
+/// Check that a field contains a string.
+///
+/// # Errors
+///
+/// This will return a message naming the pattern if the value doesn't contain it.
+pub fn contains<T: AsRef<str> + ?Sized>(value: &T, pattern: &str) -> Result<(), String> {
+    if value.as_ref().contains(pattern) {
+        Ok(())
+    } else {
+        Err(format!("must contain {pattern:?}"))
+    }
+}
+
+/// Check that a field is equal to another field.
+///
+/// # Errors
+///
+/// This will return a message naming the other field if the values aren't equal.
+pub fn must_match<T, U>(value: &T, other: &U, other_name: &str) -> Result<(), String>
+where
+    T: PartialEq<U> + ?Sized,
+    U: ?Sized,
+{
+    if value == other {
+        Ok(())
+    } else {
+        Err(format!("must match `{other_name}`"))
+    }
+}
+
+/// Check that a field is an email address.
+///
+/// # Errors
+///
+/// This will return [`InvalidEmail`] if the value is not a valid email address.
+#[cfg(feature = "email")]
+pub fn email<T: AsRef<str> + ?Sized>(value: &T) -> Result<(), InvalidEmail> {
+    crate::checks::Email::refine(value.as_ref()).map(drop)
+}
+
+/// Check that a field is a URL.
+///
+/// # Errors
+///
+/// This will return the parse error if the value is not a valid URL.
+#[cfg(feature = "url")]
+pub fn url<T: AsRef<str> + ?Sized>(value: &T) -> Result<(), url::ParseError> {
+    crate::checks::Url(value).check().map(drop)
+}
+
+/// Check a field with its own [`Check`] implementation.
+///
+/// # Errors
+///
+/// This will return the error from [`Check::check`] if the check fails.
+pub fn nested<T: Check<Ok = T>>(value: T) -> Result<T, T::Err> {
+    value.check()
+}
+
+/// The state of a typestate builder's field that hasn't been set.
+#[derive(Clone, Copy, Debug)]
+pub struct Unset;
+
+/// The state of a typestate builder's field that has been set (and checked).
+#[derive(Clone, Copy, Debug)]
+pub struct Set<T>(pub T);
+
+/// Wrap a value without checking it, for `#[checked]` newtypes' `new_unchecked` and typestate
+/// builders.
+///
+/// # Safety
+///
+/// `value` must be known to pass its [`Check`]. This doesn't affect memory safety within this
+/// crate, but `unsafe` code is allowed to rely on checked values being valid, and this can't be
+/// sealed any other way while still being callable from generated code.
+pub unsafe fn assume_checked<T>(value: T) -> Checked<T> {
+    Checked::new_unchecked(value)
+}
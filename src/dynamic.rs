@@ -0,0 +1,455 @@
+//! Checks that are chosen at runtime.
+//!
+//! [`Check`](crate::Check) consumes the value being checked, which means it can't be used as a
+//! trait object. [`DynCheck`] instead checks values by reference, so implementations can be boxed,
+//! stored, and selected at runtime (e.g. when checks are registered by plugins).
+//!
+//! With the `alloc` feature enabled, [`CheckRegistry`] can be used to register checks by name and
+//! resolve them later, e.g. from configuration, and [`DynPredicate`] can be used to produce
+//! [`Checked`] proofs from predicates whose parameters are only known at runtime.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::BTreeMap, string::String};
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use crate::Checked;
+
+/// Object-safe checks.
+///
+/// Values can be checked with a `DynCheck` using [`Checked::try_from_dyn`](crate::Checked::try_from_dyn).
+pub trait DynCheck<T: ?Sized> {
+    /// The error returned when the check fails.
+    type Err;
+
+    /// Check `value`.
+    ///
+    /// # Errors
+    ///
+    /// If `value` is valid this should return `Ok(())`, and otherwise `Err(Self::Err)`.
+    fn check_dyn(&self, value: &T) -> Result<(), Self::Err>;
+}
+
+impl<T: ?Sized, C: DynCheck<T> + ?Sized> DynCheck<T> for &C {
+    type Err = C::Err;
+
+    fn check_dyn(&self, value: &T) -> Result<(), Self::Err> {
+        (**self).check_dyn(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, C: DynCheck<T> + ?Sized> DynCheck<T> for Box<C> {
+    type Err = C::Err;
+
+    fn check_dyn(&self, value: &T) -> Result<(), Self::Err> {
+        (**self).check_dyn(value)
+    }
+}
+
+/// Create a [`DynCheck`] from a closure.
+///
+/// ```
+/// use check_mate::{dynamic, Checked};
+///
+/// let even = dynamic::from_fn(|n: &u32| if n % 2 == 0 { Ok(()) } else { Err("odd") });
+///
+/// assert!(Checked::try_from_dyn(2, &even).is_ok());
+/// assert_eq!(Checked::try_from_dyn(3, &even).err(), Some("odd"));
+/// ```
+pub fn from_fn<T, E, F>(f: F) -> FromFn<F>
+where
+    T: ?Sized,
+    F: Fn(&T) -> Result<(), E>,
+{
+    FromFn(f)
+}
+
+/// A [`DynCheck`] that calls a closure.
+///
+/// This is created by [`from_fn`].
+#[derive(Clone, Copy, Debug)]
+pub struct FromFn<F>(F);
+
+impl<T, E, F> DynCheck<T> for FromFn<F>
+where
+    T: ?Sized,
+    F: Fn(&T) -> Result<(), E>,
+{
+    type Err = E;
+
+    fn check_dyn(&self, value: &T) -> Result<(), Self::Err> {
+        (self.0)(value)
+    }
+}
+
+/// A registry of named checks.
+///
+/// Checks are registered with [`register`](Self::register), and can later be resolved by name with
+/// [`get`](Self::get), or run directly with [`check`](Self::check).
+///
+/// ```
+/// use check_mate::{dynamic::{self, CheckRegistry}, Checked};
+///
+/// let mut registry = CheckRegistry::new();
+/// registry.register("us-zip", dynamic::from_fn(|zip: &str| {
+///     if zip.len() == 5 && zip.bytes().all(|b| b.is_ascii_digit()) {
+///         Ok(())
+///     } else {
+///         Err("invalid zip code")
+///     }
+/// }));
+///
+/// assert!(registry.check("us-zip", "90210").is_ok());
+/// assert!(registry.check("us-zip", "9021").is_err());
+/// assert!(registry.check("email", "90210").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub struct CheckRegistry<T: ?Sized, E> {
+    checks: BTreeMap<String, Box<dyn DynCheck<T, Err = E> + Send + Sync>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, E> CheckRegistry<T, E> {
+    /// Construct an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            checks: BTreeMap::new(),
+        }
+    }
+
+    /// Register a check with the given name.
+    ///
+    /// If a check was already registered with the same name it will be replaced.
+    pub fn register<C>(&mut self, name: impl Into<String>, check: C) -> &mut Self
+    where
+        C: DynCheck<T, Err = E> + Send + Sync + 'static,
+    {
+        self.checks.insert(name.into(), Box::new(check));
+        self
+    }
+
+    /// Resolve the check with the given name, if there is one.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&(dyn DynCheck<T, Err = E> + Send + Sync)> {
+        self.checks.get(name).map(|check| &**check)
+    }
+
+    /// Check `value` with the check registered with the given name.
+    ///
+    /// # Errors
+    ///
+    /// This will return [`RegistryError::Unknown`] if there's no check registered with the given
+    /// name, or [`RegistryError::Failed`] with the check's error if the check fails.
+    pub fn check(&self, name: &str, value: &T) -> Result<(), RegistryError<E>> {
+        let check = self
+            .get(name)
+            .ok_or_else(|| RegistryError::Unknown(name.into()))?;
+        check.check_dyn(value).map_err(RegistryError::Failed)
+    }
+
+    /// Iterate over the names of the registered checks, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.checks.keys().map(String::as_str)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, E> Default for CheckRegistry<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, E> core::fmt::Debug for CheckRegistry<T, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+/// The error returned by [`CheckRegistry::check`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RegistryError<E> {
+    /// There was no check registered with the given name.
+    Unknown(String),
+
+    /// The check failed.
+    Failed(E),
+}
+
+#[cfg(feature = "alloc")]
+impl<E: core::fmt::Display> core::fmt::Display for RegistryError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unknown(name) => write!(f, "no check registered with name `{name}`"),
+            Self::Failed(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: core::error::Error + 'static> core::error::Error for RegistryError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Unknown(_) => None,
+            Self::Failed(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: crate::ErrorCode> crate::ErrorCode for RegistryError<E> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Unknown(_) => "unknown_check",
+            Self::Failed(error) => error.code(),
+        }
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        match self {
+            Self::Unknown(_) => None,
+            Self::Failed(error) => error.numeric_code(),
+        }
+    }
+}
+
+/// A predicate built from a closure, for when its parameters are only known at runtime.
+///
+/// Static [`Predicate`](crate::Predicate)s can't capture runtime configuration (limits,
+/// allow-lists, etc.). `DynPredicate` wraps a closure along with a human-readable description,
+/// and values that satisfy it are wrapped in a `Checked<T, DynTag<M>>`.
+///
+/// By default `M` is `()`, so values checked by different predicates have the same type. To keep
+/// them apart, choose a marker type for each predicate with [`with_tag`](Self::with_tag):
+///
+/// ```
+/// use check_mate::{dynamic::{DynPredicate, DynTag}, Checked};
+///
+/// struct UnderLimit;
+///
+/// let limit = 10; // e.g. from configuration
+/// let under_limit = DynPredicate::new(format!("at most {limit}"), move |n: &u32| *n <= limit)
+///     .with_tag::<UnderLimit>();
+///
+/// let checked: Checked<u32, DynTag<UnderLimit>> = under_limit.refine(7).unwrap();
+/// assert_eq!(
+///     under_limit.refine(11).unwrap_err().to_string(),
+///     "value does not satisfy predicate: at most 10",
+/// );
+/// ```
+///
+/// Nothing stops two predicates from using the same marker, so a marker should only be used for
+/// one predicate (or for predicates that check the same thing).
+#[cfg(feature = "alloc")]
+pub struct DynPredicate<T: ?Sized, M = ()> {
+    description: String,
+    test: Box<dyn Fn(&T) -> bool + Send + Sync>,
+    tag: PhantomData<fn() -> M>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> DynPredicate<T> {
+    /// Construct a predicate from a description and a closure.
+    ///
+    /// The closure should return `true` if the value satisfies the predicate.
+    pub fn new<F>(description: impl Into<String>, test: F) -> Self
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            description: description.into(),
+            test: Box::new(test),
+            tag: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, M> DynPredicate<T, M> {
+    /// Tag values that satisfy the predicate with the marker type `N`.
+    #[must_use]
+    pub fn with_tag<N>(self) -> DynPredicate<T, N> {
+        DynPredicate {
+            description: self.description,
+            test: self.test,
+            tag: PhantomData,
+        }
+    }
+
+    /// The description of the predicate.
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Check that `value` satisfies the predicate.
+    ///
+    /// # Errors
+    ///
+    /// This will return a [`DynPredicateError`] with the predicate's description if `value` doesn't
+    /// satisfy the predicate.
+    pub fn refine(&self, value: T) -> Result<Checked<T, DynTag<M>>, DynPredicateError>
+    where
+        T: Sized,
+    {
+        self.check_dyn(&value)?;
+        Ok(Checked::new_unchecked(value))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, M> DynCheck<T> for DynPredicate<T, M> {
+    type Err = DynPredicateError;
+
+    fn check_dyn(&self, value: &T) -> Result<(), Self::Err> {
+        if (self.test)(value) {
+            Ok(())
+        } else {
+            Err(DynPredicateError {
+                description: self.description.clone(),
+            })
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized, M> core::fmt::Debug for DynPredicate<T, M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynPredicate")
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The predicate tag for values checked at runtime, by a [`DynPredicate`] or with
+/// [`Checked::try_from_dyn`](crate::Checked::try_from_dyn).
+///
+/// `DynTag` doesn't implement [`Predicate`](crate::Predicate), so a `Checked<T, DynTag<M>>` can
+/// only be obtained from a runtime check. With the default marker `()`, it only proves that *some*
+/// runtime check passed, not which one. [`DynPredicate::with_tag`] can be used to choose a marker
+/// type that identifies the predicate.
+#[derive(Clone, Copy, Debug)]
+pub struct DynTag<M = ()>(PhantomData<fn() -> M>);
+
+/// The error returned when a value doesn't satisfy a [`DynPredicate`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DynPredicateError {
+    description: String,
+}
+
+#[cfg(feature = "alloc")]
+impl DynPredicateError {
+    /// The description of the predicate that wasn't satisfied.
+    #[must_use]
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for DynPredicateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "value does not satisfy predicate: {}", self.description)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for DynPredicateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::DynCheck;
+    use crate::Checked;
+
+    struct LessThan(usize);
+
+    impl DynCheck<usize> for LessThan {
+        type Err = &'static str;
+
+        fn check_dyn(&self, value: &usize) -> Result<(), Self::Err> {
+            if *value < self.0 {
+                Ok(())
+            } else {
+                Err("too big")
+            }
+        }
+    }
+
+    #[test]
+    fn try_from_dyn() {
+        let checks: [&dyn DynCheck<usize, Err = &'static str>; 2] = [&LessThan(10), &LessThan(5)];
+
+        assert_eq!(Checked::try_from_dyn(7, checks[0]).as_deref(), Ok(&7));
+        assert_eq!(
+            Checked::try_from_dyn(7, checks[1]).as_deref(),
+            Err(&"too big")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed() {
+        let check: Box<dyn DynCheck<usize, Err = &'static str>> = Box::new(LessThan(10));
+
+        assert_eq!(Checked::try_from_dyn(7, &check).as_deref(), Ok(&7));
+        assert_eq!(
+            Checked::try_from_dyn(11, &check).as_deref(),
+            Err(&"too big")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn registry() {
+        use super::{CheckRegistry, RegistryError};
+
+        let mut registry = CheckRegistry::new();
+        registry
+            .register("lt10", LessThan(10))
+            .register("lt5", LessThan(5));
+
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["lt10", "lt5"]);
+        assert_eq!(registry.check("lt10", &7), Ok(()));
+        assert_eq!(
+            registry.check("lt5", &7),
+            Err(RegistryError::Failed("too big"))
+        );
+        assert_eq!(
+            registry.check("lt1", &7),
+            Err(RegistryError::Unknown("lt1".into()))
+        );
+        assert_eq!(
+            Checked::try_from_dyn(3, registry.get("lt5").unwrap()).as_deref(),
+            Ok(&3)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn dyn_predicate() {
+        use super::{DynPredicate, DynTag};
+
+        struct Colour;
+
+        let allowed = ["red", "green"];
+        let colour = DynPredicate::new("an allowed colour", move |colour: &&str| {
+            allowed.contains(colour)
+        });
+
+        let checked: Checked<&str, DynTag> = colour.refine("red").unwrap();
+        assert_eq!(*checked, "red");
+
+        let colour = colour.with_tag::<Colour>();
+        let checked: Checked<&str, DynTag<Colour>> = colour.refine("green").unwrap();
+        assert_eq!(*checked, "green");
+
+        let error = colour.refine("blue").unwrap_err();
+        assert_eq!(error.description(), "an allowed colour");
+        assert_eq!(colour.check_dyn(&"green"), Ok(()));
+    }
+}
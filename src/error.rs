@@ -0,0 +1,540 @@
+//! Utilities for working with check errors.
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, string::String};
+use core::{fmt, panic::Location};
+
+/// Machine-readable codes for check errors.
+///
+/// Check errors can implement this to expose a stable code that doesn't depend on the error's
+/// `Display` output, e.g. for mapping validation failures to client-facing API errors. The error
+/// types in this crate implement `ErrorCode`, delegating to the wrapped error where there is one.
+///
+/// ```
+/// use check_mate::ErrorCode;
+///
+/// enum UsernameError {
+///     TooLong,
+///     Reserved,
+/// }
+///
+/// impl ErrorCode for UsernameError {
+///     fn code(&self) -> &'static str {
+///         match self {
+///             Self::TooLong => "username.too_long",
+///             Self::Reserved => "username.reserved",
+///         }
+///     }
+/// }
+/// ```
+pub trait ErrorCode {
+    /// A stable, machine-readable code for the error.
+    fn code(&self) -> &'static str;
+
+    /// A stable numeric code for the error, if there is one.
+    ///
+    /// By default this returns `None`.
+    fn numeric_code(&self) -> Option<u32> {
+        None
+    }
+}
+
+impl ErrorCode for core::convert::Infallible {
+    fn code(&self) -> &'static str {
+        match *self {}
+    }
+}
+
+impl<E: ErrorCode + ?Sized> ErrorCode for &E {
+    fn code(&self) -> &'static str {
+        (**self).code()
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        (**self).numeric_code()
+    }
+}
+
+/// An error that includes its [`code`](ErrorCode::code) when displayed.
+///
+/// Since the `serde` integration reports check failures using the error's `Display`
+/// implementation, using `Coded<E>` as a [`Check::Err`](crate::Check::Err) makes the error code
+/// available in deserialization errors.
+///
+/// ```
+/// use check_mate::{error::Coded, ErrorCode};
+///
+/// struct TooBig;
+///
+/// impl core::fmt::Display for TooBig {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         f.write_str("too big")
+///     }
+/// }
+///
+/// impl ErrorCode for TooBig {
+///     fn code(&self) -> &'static str {
+///         "too_big"
+///     }
+/// }
+///
+/// assert_eq!(Coded(TooBig).to_string(), "too_big: too big");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Coded<E>(pub E);
+
+impl<E: ErrorCode + fmt::Display> fmt::Display for Coded<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.0.code(), self.0)
+    }
+}
+
+impl<E: ErrorCode + fmt::Debug + fmt::Display> core::error::Error for Coded<E> {}
+
+impl<E: ErrorCode> ErrorCode for Coded<E> {
+    fn code(&self) -> &'static str {
+        self.0.code()
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        self.0.numeric_code()
+    }
+}
+
+/// A check error, with context about what was being checked.
+///
+/// This wraps a check's error together with the name of the type that was checked and, optionally,
+/// the location that performed the check. This makes it possible to tell which value failed
+/// validation when many different types are checked together, without needing bespoke error types.
+///
+/// `CheckError`s are usually obtained from [`Checked::try_from_context`](crate::Checked::try_from_context).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CheckError<E> {
+    error: E,
+    type_name: &'static str,
+    location: Option<&'static Location<'static>>,
+}
+
+impl<E> CheckError<E> {
+    /// Wrap an error from checking a `T`.
+    pub fn new<T: ?Sized>(error: E) -> Self {
+        Self {
+            error,
+            type_name: core::any::type_name::<T>(),
+            location: None,
+        }
+    }
+
+    /// Wrap an error from checking a `T`, recording the caller's location.
+    #[track_caller]
+    pub fn new_located<T: ?Sized>(error: E) -> Self {
+        Self {
+            location: Some(Location::caller()),
+            ..Self::new::<T>(error)
+        }
+    }
+
+    /// The error returned by the check.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Retrieve the error returned by the check, dropping the context.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// The name of the type that was checked.
+    ///
+    /// This is obtained from [`core::any::type_name`], so the same caveats apply.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The location that performed the check, if it was recorded.
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for CheckError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {}", self.type_name, self.error)?;
+        if let Some(location) = self.location {
+            write!(f, " (at {location})")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for CheckError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<E: ErrorCode> ErrorCode for CheckError<E> {
+    fn code(&self) -> &'static str {
+        self.error.code()
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        self.error.numeric_code()
+    }
+}
+
+/// An error from building a value with a derived builder (`#[check(builder)]`).
+///
+/// ```
+/// use check_mate::error::BuildError;
+///
+/// let error: BuildError<&str> = BuildError::Missing("age");
+/// assert_eq!(error.to_string(), "missing field `age`");
+///
+/// let error = BuildError::Invalid("age: must be at least 18");
+/// assert_eq!(error.to_string(), "age: must be at least 18");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BuildError<E> {
+    /// A field wasn't set.
+    Missing(&'static str),
+
+    /// The built value failed its check.
+    Invalid(E),
+}
+
+impl<E: fmt::Display> fmt::Display for BuildError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(field) => write!(f, "missing field `{field}`"),
+            Self::Invalid(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for BuildError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Missing(_) => None,
+            Self::Invalid(error) => Some(error),
+        }
+    }
+}
+
+/// Missing fields have the code `missing_field`, and check failures use the check's code.
+impl<E: ErrorCode> ErrorCode for BuildError<E> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Missing(_) => "missing_field",
+            Self::Invalid(error) => error.code(),
+        }
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        match self {
+            Self::Missing(_) => None,
+            Self::Invalid(error) => error.numeric_code(),
+        }
+    }
+}
+
+/// Error messages for decoded values that fail their checks, shared by the serialization
+/// integrations.
+#[cfg(any(
+    feature = "bincode",
+    feature = "borsh",
+    feature = "rkyv",
+    feature = "serde"
+))]
+pub(crate) mod decode {
+    use core::fmt;
+
+    /// Displays the error for a decoded value of the given type that failed its check, e.g.
+    /// `invalid value for Checked<Email>: <error>`.
+    pub(crate) struct Invalid<T, E>(pub(crate) T, pub(crate) E);
+
+    impl<T: fmt::Display, E: fmt::Display> fmt::Display for Invalid<T, E> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "invalid value for {}: {}", self.0, self.1)
+        }
+    }
+
+    /// Displays the name of `Checked<T, P>`, omitting `P` if it's [`Inherent`](crate::Inherent).
+    pub(crate) struct CheckedTypeName<T, P>(core::marker::PhantomData<fn() -> (T, P)>);
+
+    impl<T, P> CheckedTypeName<T, P> {
+        pub(crate) fn new() -> Self {
+            Self(core::marker::PhantomData)
+        }
+    }
+
+    impl<T, P> fmt::Display for CheckedTypeName<T, P> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            use core::any::type_name;
+
+            write!(f, "Checked<{}", ShortTypeName(type_name::<T>()))?;
+            if type_name::<P>() != type_name::<crate::Inherent>() {
+                write!(f, ", {}", ShortTypeName(type_name::<P>()))?;
+            }
+            f.write_str(">")
+        }
+    }
+
+    /// Displays a type name without module paths, e.g. `Vec<String>` for
+    /// `alloc::vec::Vec<alloc::string::String>`.
+    pub(crate) struct ShortTypeName(pub(crate) &'static str);
+
+    impl fmt::Display for ShortTypeName {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let is_path = |c: char| c.is_alphanumeric() || c == '_' || c == ':';
+            let mut rest = self.0;
+            while let Some(start) = rest.find(is_path) {
+                f.write_str(&rest[..start])?;
+                rest = &rest[start..];
+                let end = rest.find(|c| !is_path(c)).unwrap_or(rest.len());
+                let path = &rest[..end];
+                f.write_str(path.rsplit("::").next().unwrap_or(path))?;
+                rest = &rest[end..];
+            }
+            f.write_str(rest)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{CheckedTypeName, ShortTypeName};
+        use crate::checks::NonZero;
+
+        #[test]
+        fn type_names() {
+            assert_eq!(
+                ShortTypeName(core::any::type_name::<Vec<(u8, &str)>>()).to_string(),
+                "Vec<(u8, &str)>"
+            );
+            assert_eq!(
+                CheckedTypeName::<String, NonZero>::new().to_string(),
+                "Checked<String, NonZero>"
+            );
+            assert_eq!(
+                CheckedTypeName::<String, crate::Inherent>::new().to_string(),
+                "Checked<String>"
+            );
+        }
+    }
+}
+
+/// A boxed error, as reported by derived [`Check`](crate::Check) implementations.
+#[cfg(feature = "alloc")]
+pub type BoxError = Box<dyn core::error::Error + Send + Sync>;
+
+/// An error from a derived [`Check`](crate::Check) implementation.
+///
+/// This records the name of the field that failed its check, along with the underlying error. For
+/// tuple structs and variants, the field name is its index. For enums, the name of the variant is
+/// also recorded. Errors from checks of the whole value (e.g. `#[check(with = "...")]`) have no
+/// field.
+///
+/// When the underlying error is itself a `FieldError` (e.g. from a `#[check(nested)]` field), the
+/// errors are combined, and the field is recorded as a dotted path.
+///
+/// ```
+/// use check_mate::error::FieldError;
+///
+/// let error = FieldError::new("age", "must be at least 18");
+/// assert_eq!(error.field(), Some("age"));
+/// assert_eq!(error.to_string(), "age: must be at least 18");
+///
+/// let error = FieldError::in_variant("Circle", "radius", "must be positive");
+/// assert_eq!(error.variant(), Some("Circle"));
+/// assert_eq!(error.to_string(), "Circle.radius: must be positive");
+///
+/// let error = FieldError::whole("start must not be after end");
+/// assert_eq!(error.field(), None);
+/// assert_eq!(error.to_string(), "start must not be after end");
+///
+/// let error = FieldError::new("user", FieldError::new("age", "must be at least 18"));
+/// assert_eq!(error.field(), Some("user.age"));
+/// assert_eq!(error.to_string(), "user.age: must be at least 18");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct FieldError {
+    variant: Option<&'static str>,
+    field: Option<Cow<'static, str>>,
+    error: BoxError,
+}
+
+#[cfg(feature = "alloc")]
+impl FieldError {
+    /// Wrap an error from checking the given field.
+    pub fn new(field: &'static str, error: impl Into<BoxError>) -> Self {
+        match error.into().downcast::<Self>() {
+            Ok(inner) => {
+                let mut path = String::from(field);
+                if let Some(variant) = inner.variant {
+                    path.push('.');
+                    path.push_str(variant);
+                }
+                if let Some(field) = &inner.field {
+                    path.push('.');
+                    path.push_str(field);
+                }
+                Self {
+                    variant: None,
+                    field: Some(Cow::Owned(path)),
+                    error: inner.error,
+                }
+            }
+            Err(error) => Self {
+                variant: None,
+                field: Some(Cow::Borrowed(field)),
+                error,
+            },
+        }
+    }
+
+    /// Wrap an error from checking the given field of an enum variant.
+    pub fn in_variant(
+        variant: &'static str,
+        field: &'static str,
+        error: impl Into<BoxError>,
+    ) -> Self {
+        Self {
+            variant: Some(variant),
+            ..Self::new(field, error)
+        }
+    }
+
+    /// Wrap an error from checking the whole value, rather than a particular field.
+    pub fn whole(error: impl Into<BoxError>) -> Self {
+        match error.into().downcast::<Self>() {
+            Ok(inner) => *inner,
+            Err(error) => Self {
+                variant: None,
+                field: None,
+                error,
+            },
+        }
+    }
+
+    /// The name of the enum variant that failed its check, if the checked value was an enum.
+    #[must_use]
+    pub fn variant(&self) -> Option<&'static str> {
+        self.variant
+    }
+
+    /// The name of the field that failed its check, if the error is attributed to a field.
+    ///
+    /// For nested fields, this is a dotted path (e.g. `address.city`).
+    #[must_use]
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// The error returned by the field's check.
+    #[must_use]
+    pub fn error(&self) -> &(dyn core::error::Error + Send + Sync + 'static) {
+        &*self.error
+    }
+
+    /// Retrieve the error returned by the field's check, dropping the field name.
+    #[must_use]
+    pub fn into_error(self) -> BoxError {
+        self.error
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(variant) = self.variant {
+            write!(f, "{variant}.")?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, "{field}: ")?;
+        }
+        self.error.fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for FieldError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&*self.error)
+    }
+}
+
+/// The field's error is type-erased, so `FieldError` always has the code `invalid_field`. The
+/// field's own code can be recovered by downcasting [`error`](FieldError::error).
+#[cfg(feature = "alloc")]
+impl ErrorCode for FieldError {
+    fn code(&self) -> &'static str {
+        "invalid_field"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BuildError, CheckError, Coded, ErrorCode};
+
+    #[derive(Debug)]
+    struct TooBig;
+
+    impl core::fmt::Display for TooBig {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("too big")
+        }
+    }
+
+    impl ErrorCode for TooBig {
+        fn code(&self) -> &'static str {
+            "too_big"
+        }
+
+        fn numeric_code(&self) -> Option<u32> {
+            Some(413)
+        }
+    }
+
+    #[test]
+    fn coded() {
+        assert_eq!(Coded(TooBig).to_string(), "too_big: too big");
+        assert_eq!(Coded(TooBig).code(), "too_big");
+        assert_eq!(Coded(&TooBig).numeric_code(), Some(413));
+    }
+
+    #[test]
+    fn check_error() {
+        let error = CheckError::new::<u8>(TooBig);
+        assert_eq!(error.to_string(), "invalid u8: too big");
+        assert_eq!(error.code(), "too_big");
+
+        let error = CheckError::new_located::<u8>(TooBig);
+        let line = line!() - 1;
+        assert_eq!(
+            error.to_string(),
+            format!("invalid u8: too big (at {}:{}:21)", file!(), line)
+        );
+    }
+
+    #[test]
+    fn build_error() {
+        assert_eq!(BuildError::<TooBig>::Missing("age").code(), "missing_field");
+        assert_eq!(BuildError::<TooBig>::Missing("age").numeric_code(), None);
+        assert_eq!(BuildError::Invalid(TooBig).code(), "too_big");
+        assert_eq!(BuildError::Invalid(TooBig).numeric_code(), Some(413));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn field_error() {
+        use super::FieldError;
+
+        let error = FieldError::new("size", Coded(TooBig));
+        assert_eq!(error.code(), "invalid_field");
+        assert_eq!(error.numeric_code(), None);
+
+        let inner = error.error().downcast_ref::<Coded<TooBig>>().unwrap();
+        assert_eq!(inner.code(), "too_big");
+    }
+}
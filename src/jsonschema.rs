@@ -0,0 +1,184 @@
+//! Checking dynamic JSON against JSON Schemas.
+//!
+//! This module is enabled by the `jsonschema` feature. A [`Schema`] is compiled once (e.g. when a
+//! service starts up), and can then be used to check `serde_json::Value`s. Values that conform to
+//! the schema are wrapped in a `Checked<Value, SchemaTag>`, so code handling dynamic JSON can still
+//! require proof that it was validated.
+//!
+//! ```
+//! use check_mate::{jsonschema::{Schema, SchemaTag}, Checked};
+//! use serde_json::{json, Value};
+//!
+//! let schema = Schema::new(&json!({
+//!     "type": "object",
+//!     "properties": { "name": { "type": "string" } },
+//!     "required": ["name"],
+//! }))
+//! .unwrap();
+//!
+//! fn greet(user: &Checked<Value, SchemaTag>) -> String {
+//!     format!("hello, {}", user["name"].as_str().unwrap())
+//! }
+//!
+//! let user = schema.refine(json!({ "name": "ferris" })).unwrap();
+//! assert_eq!(greet(&user), "hello, ferris");
+//!
+//! let error = schema.refine(json!({ "name": 42 })).unwrap_err();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "value does not match schema at `/name`: 42 is not of type \"string\"",
+//! );
+//! ```
+//!
+//! `Schema` also implements [`DynCheck`], so it can be used with
+//! [`Checked::try_from_dyn`](crate::Checked::try_from_dyn) or registered in a
+//! [`CheckRegistry`](crate::dynamic::CheckRegistry).
+
+use std::{fmt, vec::Vec};
+
+use jsonschema::{ValidationError, Validator};
+use serde_json::Value;
+
+use crate::{Checked, DynCheck, ErrorCode};
+
+/// A compiled JSON Schema.
+pub struct Schema {
+    validator: Validator,
+}
+
+impl Schema {
+    /// Compile a JSON Schema.
+    ///
+    /// The draft is detected from the schema's `$schema` keyword, if it has one. Use
+    /// [`jsonschema::options`] to configure the validator, and convert it with [`From`].
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if `schema` isn't a valid JSON Schema.
+    pub fn new(schema: &Value) -> Result<Self, ValidationError<'static>> {
+        jsonschema::validator_for(schema).map(Self::from)
+    }
+
+    /// Check that `value` conforms to the schema.
+    ///
+    /// # Errors
+    ///
+    /// This will return a [`SchemaError`] with every validation error if `value` doesn't conform
+    /// to the schema.
+    pub fn refine(&self, value: Value) -> Result<Checked<Value, SchemaTag>, SchemaError> {
+        self.check_dyn(&value)?;
+        Ok(Checked::new_unchecked(value))
+    }
+}
+
+impl From<Validator> for Schema {
+    fn from(validator: Validator) -> Self {
+        Self { validator }
+    }
+}
+
+impl DynCheck<Value> for Schema {
+    type Err = SchemaError;
+
+    fn check_dyn(&self, value: &Value) -> Result<(), Self::Err> {
+        let errors: Vec<_> = self
+            .validator
+            .iter_errors(value)
+            .map(ValidationError::to_owned)
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError { errors })
+        }
+    }
+}
+
+impl fmt::Debug for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Schema")
+            .field("draft", &self.validator.draft())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The predicate tag for values checked by a [`Schema`].
+///
+/// Like [`DynTag`](crate::dynamic::DynTag), `SchemaTag` doesn't implement
+/// [`Predicate`](crate::Predicate), so a `Checked<Value, SchemaTag>` can only be obtained from
+/// [`Schema::refine`].
+#[derive(Clone, Copy, Debug)]
+pub struct SchemaTag;
+
+/// The error returned when a value doesn't conform to a [`Schema`].
+#[derive(Debug)]
+pub struct SchemaError {
+    errors: Vec<ValidationError<'static>>,
+}
+
+impl SchemaError {
+    /// The validation errors, in the order they were found.
+    ///
+    /// There's always at least one.
+    #[must_use]
+    pub fn errors(&self) -> &[ValidationError<'static>] {
+        &self.errors
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let error = &self.errors[0];
+        write!(
+            f,
+            "value does not match schema at `{}`: {error}",
+            error.instance_path()
+        )?;
+        if self.errors.len() > 1 {
+            write!(f, " (and {} more)", self.errors.len() - 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+impl ErrorCode for SchemaError {
+    fn code(&self) -> &'static str {
+        "schema"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Schema;
+    use crate::{Checked, ErrorCode};
+
+    #[test]
+    fn errors() {
+        let schema = Schema::new(&json!({
+            "type": "array",
+            "items": { "type": "integer", "minimum": 0 },
+        }))
+        .unwrap();
+
+        assert_eq!(*schema.refine(json!([0, 1])).unwrap(), json!([0, 1]));
+        assert!(Checked::try_from_dyn(json!([]), &schema).is_ok());
+
+        let error = schema.refine(json!([-1, "a", 2])).unwrap_err();
+        assert_eq!(error.errors().len(), 2);
+        assert_eq!(error.errors()[1].instance_path().as_str(), "/1");
+        assert_eq!(error.code(), "schema");
+        assert_eq!(
+            error.to_string(),
+            "value does not match schema at `/0`: -1 is less than the minimum of 0 (and 1 more)"
+        );
+    }
+
+    #[test]
+    fn invalid_schema() {
+        assert!(Schema::new(&json!({ "type": 42 })).is_err());
+    }
+}
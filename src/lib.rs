@@ -209,7 +209,472 @@
 //! With the `serde` feature enabled, `Checked<T>` will also implement `Serialize` if
 //! `T: Serialize`, and `Deserialize` if `T: Deserialize` **and** there's a `Check<Ok = T>` impl to
 //! use for the check (unconstrained type parameter limitations prevent a blanket `Deserialize` impl
-//! for any `U: Check<Ok = T>` – it must be `T` itself).
+//! for any `U: Check<Ok = T>` – it must be `T` itself). With the `serde_with` feature enabled,
+//! [`serde::CheckedAs<U>`](serde::CheckedAs) can be used with `#[serde_as]` to name a separate
+//! checker type instead.
+//!
+//! When a deserialized value fails its check, the error names the checked type along with the
+//! check's error, e.g. `invalid value for Checked<Email>: must contain an '@'`. Errors from derived
+//! checks also name the field that failed (see [`error::FieldError`]). The error doesn't include
+//! the value's path within the document, since `Deserialize` impls can't see it. Deserializing
+//! through `serde_path_to_error` adds it, e.g.
+//! `users[3].email: invalid value for Checked<Email>: must contain an '@'`.
+//!
+//! Fields that aren't wrapped in `Checked` can still be checked when they're deserialized, using
+//! `#[serde(with = "check_mate::serde")]` (see the [`serde`] module).
+//!
+//! Similarly, with the `borsh` feature enabled, `Checked<T, P>` implements `BorshSerialize` and
+//! `BorshDeserialize`. Values are encoded exactly like `T`, and decoded values are checked, with
+//! failures reported as `InvalidData` I/O errors. The `bincode` feature does the same for
+//! `bincode`'s `Encode`, `Decode`, and `BorrowDecode`. With the `rkyv` feature enabled,
+//! `Checked<T, P>` can be archived with `rkyv`, and is checked when it's deserialized (see the
+//! [`rkyv`] module). With the `prost` feature enabled, protobuf messages can be checked as they're
+//! decoded (see the [`prost`] module).
+//!
+//! With the `csv` feature enabled, records read from CSV files can be checked as they're
+//! deserialized, with failures reported along with their line numbers (see the [`csv`] module).
+//!
+//! With the `jsonschema` feature enabled, dynamic `serde_json::Value`s can be checked against
+//! compiled JSON Schemas (see the [`jsonschema`] module).
+//!
+//! With the `arbitrary` feature enabled, `Checked<T, P>` implements `Arbitrary` by generating `T`s
+//! until one passes the check, so fuzz targets can take checked values as inputs directly.
+//! Similarly, the `proptest` feature adds strategies for generating checked values in property
+//! tests (see the [`proptest`] module), and with the `quickcheck` feature enabled `Checked<T, P>`
+//! implements `quickcheck::Arbitrary`, shrinking through `T` and discarding shrunk values that
+//! fail the check.
+//!
+//! ## Refinements
+//!
+//! Since `Check` can only be implemented once per type, `Checked<T>` can only express a single
+//! invariant for a given `T`. `Checked` also takes an optional second type parameter, which can be
+//! any [`Predicate`] over `T`. This allows lightweight, named refinements of types you don't own:
+//!
+//! ```
+//! use check_mate::{Checked, Predicate};
+//!
+//! struct NonZero;
+//!
+//! impl Predicate<u32> for NonZero {
+//!     type Err = &'static str;
+//!
+//!     fn refine(value: u32) -> Result<u32, Self::Err> {
+//!         if value == 0 { Err("must not be zero") } else { Ok(value) }
+//!     }
+//! }
+//!
+//! fn divide(n: u32, d: Checked<u32, NonZero>) -> u32 {
+//!     n / *d
+//! }
+//!
+//! assert_eq!(divide(10, Checked::refine(2).unwrap()), 5);
+//! ```
+//!
+//! With the `serde` feature enabled, `Checked<T, P>` implements `Deserialize` if
+//! `T: Deserialize` and `P: Predicate<T>`.
+//!
+//! With the `schemars` feature enabled, `Checked<T, P>` implements `JsonSchema` if `T: JsonSchema`,
+//! using the schema for `T`. The built-in length, range, and pattern predicates (e.g.
+//! [`MaxLen`](checks::MaxLen) and [`Bounded`](checks::Bounded)) add the corresponding JSON Schema
+//! keywords, so generated documentation reflects the checks. Other predicates can do the same by
+//! implementing `Predicate::constrain_schema`.
+//!
+//! Predicates are also useful when a single type has several independent invariants. For example,
+//! a `Signed` message might be checked for a valid signature in one place, and for a valid payload
+//! in another. Each invariant can be given its own predicate, so that the proofs have distinct
+//! types:
+//!
+//! ```
+//! # struct PublicKey;
+//! # impl PublicKey {
+//! #     fn verify(&self, payload: &[u8], signature: &Signature) -> Result<(), Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! # struct Signature;
+//! # type Error = ();
+//! use check_mate::{Checked, Predicate};
+//!
+//! struct Signed {
+//!     payload: Vec<u8>,
+//!     public_key: PublicKey,
+//!     signature: Signature,
+//! }
+//!
+//! /// The signature is valid for the payload.
+//! struct SignatureValid;
+//!
+//! impl Predicate<Signed> for SignatureValid {
+//!     type Err = Error;
+//!
+//!     fn refine(signed: Signed) -> Result<Signed, Self::Err> {
+//!         signed.public_key.verify(&signed.payload, &signed.signature)?;
+//!         Ok(signed)
+//!     }
+//! }
+//!
+//! /// The payload is a valid message.
+//! struct PayloadValid;
+//!
+//! impl Predicate<Signed> for PayloadValid {
+//!     type Err = Error;
+//!
+//!     fn refine(signed: Signed) -> Result<Signed, Self::Err> {
+//!         if signed.payload.is_empty() { Err(()) } else { Ok(signed) }
+//!     }
+//! }
+//!
+//! fn relay(message: Checked<Signed, SignatureValid>) { /* ... */ }
+//!
+//! fn process(message: Checked<Signed, PayloadValid>) { /* ... */ }
+//!
+//! # let signed = || Signed { payload: vec![1], public_key: PublicKey, signature: Signature };
+//! relay(Checked::refine(signed()).unwrap());
+//! process(Checked::refine(signed()).unwrap());
+//! ```
+//!
+//! A value satisfying both invariants can be expressed as
+//! `Checked<Signed, And<SignatureValid, PayloadValid>>` (see [`combinators::And`]).
+//!
+//! ## Deriving `Check`
+//!
+//! With the `derive` feature enabled, `Check` can be derived for structs and enums whose
+//! invariants are simple checks on their fields. The checks are declared with `#[check(...)]`
+//! attributes:
+//!
+//! - `#[check(range(<range>))]` – the field must be within the given range, e.g. `1..=10`.
+//! - `#[check(non_empty)]` – the field must not be empty (see [`checks::Length`]).
+//! - `#[check(custom = "<path>")]` – the field must pass the given function, which should have a
+//!   signature like `fn(&T) -> Result<(), E>`, where `E` can be converted into a
+//!   `Box<dyn Error + Send + Sync>` (e.g. `&str`, `String`, or any error type). Deref coercions
+//!   apply, so a `String` field can be checked by a `fn(&str)`.
+//! - `#[check(nested)]` – the field must pass its own `Check` implementation, which must have
+//!   `Ok = Self` (e.g. a derived one). Fields that are already `Checked<...>` are valid by
+//!   construction, so aren't checked again. Nested [`error::FieldError`]s are combined, so the
+//!   error names the full path to the field (e.g. `address.city`).
+//!
+//! To ease migration from the `validator` crate, its familiar attribute names are supported too:
+//!
+//! - `#[check(length(min = <n>, max = <n>))]`, or `length(equal = <n>)` – the field's
+//!   [`checks::Length`] must be within the given bounds.
+//! - `#[check(range(min = <value>, max = <value>))]` – the same as `range(<min>..=<max>)`.
+//! - `#[check(contains = "<str>")]`, or `contains(pattern = "<str>")` – the field must contain the
+//!   given string.
+//! - `#[check(must_match = "<field>")]`, or `must_match(other = "<field>")` – the field must be
+//!   equal to the given field (its index, for tuple structs).
+//! - `#[check(email)]` – the field must be an email address (see `checks::Email`). This
+//!   requires the `email` feature.
+//! - `#[check(url)]` – the field must be an absolute URL (see `checks::Url`). This requires the
+//!   `url` feature.
+//!
+//! Several checks can be given in one attribute, separated by commas, and they're run in order.
+//! The derived implementation has `Ok = Self`, and reports the first failure as an
+//! [`error::FieldError`] naming the field:
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::{Check, Checked};
+//!
+//! #[derive(Check)]
+//! struct Order {
+//!     #[check(non_empty, custom = "no_spaces")]
+//!     sku: String,
+//!     #[check(range(1..=10))]
+//!     quantity: u8,
+//! }
+//!
+//! fn no_spaces(sku: &str) -> Result<(), &'static str> {
+//!     if sku.contains(' ') { Err("must not contain spaces") } else { Ok(()) }
+//! }
+//!
+//! let order = Order { sku: "ABC-123".into(), quantity: 3 };
+//! assert!(Checked::try_from(order).is_ok());
+//!
+//! let order = Order { sku: "ABC 123".into(), quantity: 3 };
+//! assert_eq!(
+//!     Checked::try_from(order).err().map(|error| error.to_string()),
+//!     Some("sku: must not contain spaces".into()),
+//! );
+//!
+//! let order = Order { sku: "ABC-123".into(), quantity: 11 };
+//! assert_eq!(
+//!     Checked::try_from(order).err().map(|error| error.to_string()),
+//!     Some("quantity: must be in the range 1..=10".into()),
+//! );
+//! # }
+//! ```
+//!
+//! Unless any fields are `nested`, all of the checks work by reference, so [`CheckRef`] is derived
+//! too. This allows values to be checked where they're only borrowed, such as in slices.
+//!
+//! For enums, only the fields of the value's variant are checked, and the error also names the
+//! variant:
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::Check;
+//!
+//! #[derive(Check)]
+//! enum Shape {
+//!     Circle {
+//!         #[check(range(0.0..))]
+//!         radius: f64,
+//!     },
+//!     Polygon(#[check(range(3..))] usize),
+//! }
+//!
+//! let error = Shape::Polygon(2).check().err().unwrap();
+//! assert_eq!(error.variant(), Some("Polygon"));
+//! assert_eq!(error.to_string(), "Polygon.0: must be in the range 3..");
+//! # }
+//! ```
+//!
+//! Invariants relating several fields can be checked with `#[check(with = "<path>")]` on the type
+//! itself. The function is given a reference to the whole value, and is only called once all the
+//! field checks have passed. Its errors are reported without a field:
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::Check;
+//!
+//! #[derive(Check)]
+//! #[check(with = "start_before_end")]
+//! struct Span {
+//!     start: u32,
+//!     end: u32,
+//! }
+//!
+//! fn start_before_end(span: &Span) -> Result<(), &'static str> {
+//!     if span.start <= span.end { Ok(()) } else { Err("start must not be after end") }
+//! }
+//!
+//! let error = Span { start: 2, end: 1 }.check().err().unwrap();
+//! assert_eq!(error.field(), None);
+//! assert_eq!(error.to_string(), "start must not be after end");
+//! # }
+//! ```
+//!
+//! Generic types are supported, and the derived implementation has the same bounds and `where`
+//! clauses as the type. Nested fields whose types mention a type parameter also get bounds
+//! requiring their `Check` implementations. When those bounds aren't right (e.g. a custom check
+//! needs a bound of its own), they can be replaced with `#[check(bound = "<predicates>")]`:
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::Check;
+//!
+//! #[derive(Check)]
+//! #[check(with = "ordered")]
+//! struct Window<T: Ord> {
+//!     lo: T,
+//!     hi: T,
+//! }
+//!
+//! fn ordered<T: Ord>(window: &Window<T>) -> Result<(), &'static str> {
+//!     if window.lo <= window.hi { Ok(()) } else { Err("lo must not be greater than hi") }
+//! }
+//!
+//! #[derive(Check)]
+//! #[check(bound = "T: Default + PartialEq")]
+//! struct NonDefault<T>(#[check(custom = "non_default")] T);
+//!
+//! fn non_default<T: Default + PartialEq>(value: &T) -> Result<(), &'static str> {
+//!     if *value == T::default() { Err("must not be the default") } else { Ok(()) }
+//! }
+//!
+//! assert!(Window { lo: 1, hi: 2 }.check().is_ok());
+//! assert!(Window { lo: "b", hi: "a" }.check().is_err());
+//! assert!(NonDefault(1).check().is_ok());
+//! assert!(NonDefault("").check().is_err());
+//! # }
+//! ```
+//!
+//! To handle failures with `match` rather than by inspecting field names, add
+//! `#[check(error_enum = "<name>")]` to the type. This generates an error enum with the given name
+//! and the same visibility as the type, with a variant for each checked field carrying the inner
+//! error as an [`error::BoxError`]. Variants are named after their fields in `UpperCamelCase`
+//! (`Field0`, `Field1`, etc. for tuple structs), and prefixed with the variant name for enums.
+//! Failures of `with` checks are reported as an `Invalid` variant.
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::Check;
+//!
+//! #[derive(Check)]
+//! #[check(error_enum = "SignupError")]
+//! struct Signup {
+//!     #[check(non_empty)]
+//!     user_name: String,
+//!     #[check(range(13..))]
+//!     age: u8,
+//! }
+//!
+//! match (Signup { user_name: "ferris".into(), age: 12 }).check() {
+//!     Ok(_) => unreachable!(),
+//!     Err(SignupError::UserName(_)) => unreachable!(),
+//!     Err(error @ SignupError::Age(_)) => {
+//!         assert_eq!(error.to_string(), "age: must be in the range 13..");
+//!     }
+//! }
+//! # }
+//! ```
+//!
+//! To fit into an existing error type instead, add `#[check(error = "<type>")]`. The derived
+//! implementation reports errors as that type, converting them with `From` from
+//! [`error::FieldError`] (or from the `error_enum`, if one is generated). Fields that shouldn't be
+//! checked yet can be marked `#[check(skip)]`, which disables any other checks on the field, so
+//! the derive can be adopted incrementally.
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::{error::FieldError, Check};
+//!
+//! #[derive(Debug)]
+//! enum AppError {
+//!     Validation(FieldError),
+//! }
+//!
+//! impl From<FieldError> for AppError {
+//!     fn from(error: FieldError) -> Self {
+//!         Self::Validation(error)
+//!     }
+//! }
+//!
+//! #[derive(Check)]
+//! #[check(error = "AppError")]
+//! struct Signup {
+//!     #[check(non_empty)]
+//!     user_name: String,
+//!     #[check(skip, range(13..))]
+//!     age: u8,
+//! }
+//!
+//! assert!(Signup { user_name: "ferris".into(), age: 12 }.check().is_ok());
+//! assert!(matches!(
+//!     Signup { user_name: "".into(), age: 13 }.check(),
+//!     Err(AppError::Validation(_)),
+//! ));
+//! # }
+//! ```
+//!
+//! Large structs can be built up incrementally by adding `#[check(builder)]`, which generates a
+//! `{Name}Builder` with a setter for each field, and a `Name::builder()` constructor. The builder's
+//! `build` method checks the value and returns a `Checked<Name>`, so the unchecked value never
+//! escapes. Unset fields are reported as [`error::BuildError::Missing`], and check failures as
+//! [`error::BuildError::Invalid`].
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::{error::BuildError, Check};
+//!
+//! #[derive(Check, Debug)]
+//! #[check(builder)]
+//! struct Signup {
+//!     #[check(non_empty)]
+//!     user_name: String,
+//!     #[check(range(13..))]
+//!     age: u8,
+//! }
+//!
+//! let signup = Signup::builder().user_name("ferris".into()).age(13).build().unwrap();
+//! assert_eq!(signup.user_name, "ferris");
+//!
+//! let error = Signup::builder().age(13).build().unwrap_err();
+//! assert!(matches!(error, BuildError::Missing("user_name")));
+//!
+//! let error = Signup::builder().user_name("ferris".into()).age(12).build().unwrap_err();
+//! assert_eq!(error.to_string(), "age: must be in the range 13..");
+//! # }
+//! ```
+//!
+//! With `#[check(builder(typestate))]`, the builder tracks which fields have been set in its type
+//! instead. Setters for checked fields run their checks immediately and return a `Result`, and
+//! `build` (which only runs `with` checks) doesn't exist until every field has been set, so a
+//! forgotten field is a compile error rather than a runtime one. `must_match` checks aren't
+//! supported by typestate builders.
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::Check;
+//!
+//! #[derive(Check, Debug)]
+//! #[check(builder(typestate))]
+//! struct Signup {
+//!     #[check(non_empty)]
+//!     user_name: String,
+//!     #[check(range(13..))]
+//!     age: u8,
+//!     referrer: Option<String>,
+//! }
+//!
+//! let signup = Signup::builder()
+//!     .user_name("ferris".into())
+//!     .unwrap()
+//!     .referrer(None)
+//!     .age(13)
+//!     .unwrap()
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(signup.user_name, "ferris");
+//!
+//! let error = Signup::builder().age(12).unwrap_err();
+//! assert_eq!(error.to_string(), "age: must be in the range 13..");
+//! # }
+//! ```
+//!
+//! ```compile_fail
+//! # use check_mate::Check;
+//! #[derive(Check)]
+//! #[check(builder(typestate))]
+//! struct Signup {
+//!     #[check(non_empty)]
+//!     user_name: String,
+//!     age: u8,
+//! }
+//!
+//! // `age` hasn't been set, so there's no `build` method.
+//! let signup = Signup::builder().user_name("ferris".into()).unwrap().build();
+//! ```
+//!
+//! ## Checked newtypes
+//!
+//! `Checked<T>` is convenient, but a dedicated type can make signatures clearer and leaves room
+//! for inherent methods. With the `derive` feature enabled, the [`checked`] attribute generates a
+//! `Checked{Name}` newtype around `Checked<Name>`, for a type implementing [`Check`] with
+//! `Ok = Self`. The newtype has the same visibility as the type, and has:
+//!
+//! - `new`, which checks a value, and `new_unchecked`, an `unsafe` function which wraps a value
+//!   without checking it.
+//! - `into_inner`, and a [`Deref`](core::ops::Deref) implementation targeting the type.
+//! - A conversion from `Checked<Name>`, and a `TryFrom<Name>` implementation.
+//!
+//! The name can be customised with `#[checked(name = "<name>")]`, and derives can be added to the
+//! newtype with `#[checked(derive(<traits>))]`:
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use check_mate::{checked, Check};
+//!
+//! #[checked(derive(Debug))]
+//! #[derive(Check, Debug)]
+//! pub struct Username {
+//!     #[check(non_empty)]
+//!     name: String,
+//! }
+//!
+//! fn greet(user: &CheckedUsername) -> String {
+//!     format!("Hello, {}!", user.name)
+//! }
+//!
+//! let user = CheckedUsername::new(Username { name: "ferris".into() }).unwrap();
+//! assert_eq!(greet(&user), "Hello, ferris!");
+//! assert!(CheckedUsername::new(Username { name: "".into() }).is_err());
+//! # }
+//! ```
+//!
+//! For simple newtypes over a single value, the [`checked_type!`] macro declares the newtype and
+//! its `Check` implementation in one go, without needing the `derive` feature. Similarly,
+//! [`impl_check!`] implements `Check` for an existing type from a closure.
 //!
 //! # When (not) to use this
 //!
@@ -235,19 +700,79 @@
 //! I want to try and use this to get a sense of whether or not it's actually useful, and what the
 //! pain points are. Some things I could imagine adding:
 //!
-//! - Implement additional common traits (`AsRef<T>`, `Borrow<T>`).
 //! - Implement additional common indirection methods (`as_deref`, `cloned`).
 
 #![warn(clippy::pedantic)]
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[macro_use]
+mod macros;
+
+pub mod checks;
+pub mod combinators;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod derive;
+pub mod dynamic;
+pub mod error;
+#[cfg(feature = "jsonschema")]
+pub mod jsonschema;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "prost")]
+pub mod prost;
+pub mod refine;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "rules")]
+pub mod rules;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+pub use combinators::CheckExt;
+pub use dynamic::DynCheck;
+pub use error::ErrorCode;
+
+#[cfg(feature = "derive")]
+pub use check_mate_derive::{checked, Check};
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use ::serde;
+}
+
 /// A checked value.
 ///
-/// The wrapped value is guaranteed to be valid with respect to its implementation of [`Check`].
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
-#[cfg_attr(feature = "serde", serde(transparent))]
-pub struct Checked<T>(T);
+/// The wrapped value is guaranteed to be valid with respect to the predicate `P`. By default this
+/// is [`Inherent`], meaning the value is valid with respect to its implementation of [`Check`].
+/// Other [`Predicate`]s can be used to carry different, named refinements of the same type (e.g.
+/// `Checked<u32, NonZero>` and `Checked<u32, LessThan100>`).
+pub struct Checked<T, P = Inherent>(T, PhantomData<fn() -> P>);
+
+impl<T, P> Checked<T, P> {
+    /// Wrap a value without checking it.
+    ///
+    /// This must only be used when the value is already known to satisfy `P`.
+    pub(crate) const fn new_unchecked(value: T) -> Self {
+        Checked(value, PhantomData)
+    }
+}
 
 impl<T> Checked<T> {
     /// Check a value.
@@ -256,7 +781,24 @@ impl<T> Checked<T> {
     ///
     /// This will return the error from [`Check::check`] verbatim if the check fails.
     pub fn try_from<U: Check<Ok = T>>(value: U) -> Result<Self, U::Err> {
-        value.check().map(Checked)
+        value.check().map(Checked::new_unchecked)
+    }
+
+    /// Check a value, adding context to the error if the check fails.
+    ///
+    /// This is like [`try_from`](Self::try_from), but the error is wrapped in a
+    /// [`CheckError`](error::CheckError) that records the name of the type that was checked and
+    /// the location of the call.
+    ///
+    /// # Errors
+    ///
+    /// This will return the error from [`Check::check`], with context, if the check fails.
+    #[track_caller]
+    pub fn try_from_context<U: Check<Ok = T>>(value: U) -> Result<Self, error::CheckError<U::Err>> {
+        match value.check() {
+            Ok(value) => Ok(Checked::new_unchecked(value)),
+            Err(error) => Err(error::CheckError::new_located::<U>(error)),
+        }
     }
 }
 
@@ -267,18 +809,142 @@ impl<T: Check<Err = core::convert::Infallible>> Checked<T> {
     /// to immediately construct a valid value, so long as the [`Check`] implementation doesn't
     /// fail.
     pub fn from(value: T) -> Checked<T::Ok> {
-        value.check().map(Checked).expect("infallible")
+        match value.check() {
+            Ok(value) => Checked::new_unchecked(value),
+            Err(never) => match never {},
+        }
     }
 }
 
-impl<T> Checked<T> {
+impl<T> Checked<T, dynamic::DynTag> {
+    /// Check a value using a check chosen at runtime.
+    ///
+    /// This is useful when the check to perform isn't known statically, e.g. when checks are
+    /// provided by plugins. `T`'s own [`Check`] (if any) isn't run, so the value is tagged with
+    /// [`DynTag`](dynamic::DynTag) rather than [`Inherent`], which only proves that some runtime
+    /// check passed.
+    ///
+    /// # Errors
+    ///
+    /// This will return the error from [`DynCheck::check_dyn`] verbatim if the check fails.
+    pub fn try_from_dyn<C>(value: T, check: &C) -> Result<Self, C::Err>
+    where
+        C: DynCheck<T> + ?Sized,
+    {
+        check.check_dyn(&value)?;
+        Ok(Checked::new_unchecked(value))
+    }
+}
+
+impl<T, P: Predicate<T>> Checked<T, P> {
+    /// Check a value against the predicate `P`.
+    ///
+    /// ```
+    /// use check_mate::{Checked, Predicate};
+    ///
+    /// struct NonZero;
+    ///
+    /// impl Predicate<u32> for NonZero {
+    ///     type Err = &'static str;
+    ///
+    ///     fn refine(value: u32) -> Result<u32, Self::Err> {
+    ///         if value == 0 { Err("zero") } else { Ok(value) }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Checked::<_, NonZero>::refine(3).as_deref(), Ok(&3));
+    /// assert_eq!(Checked::<_, NonZero>::refine(0).as_deref(), Err(&"zero"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This will return the error from [`Predicate::refine`] verbatim if the check fails.
+    pub fn refine(value: T) -> Result<Self, P::Err> {
+        P::refine(value).map(Checked::new_unchecked)
+    }
+}
+
+impl<T, P: Normalize<T>> Checked<T, P> {
+    /// Normalize a value, then check it against the predicate `P`.
+    ///
+    /// ```
+    /// use check_mate::{checks::Trimmed, Checked};
+    ///
+    /// assert!(Checked::<_, Trimmed>::refine(" ferris ").is_err());
+    /// assert_eq!(Checked::<_, Trimmed>::normalize(" ferris ").as_deref(), Ok(&"ferris"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This will return the error from [`Predicate::refine`] verbatim if the normalized value
+    /// doesn't satisfy the predicate.
+    pub fn normalize(value: T) -> Result<Self, P::Err> {
+        Self::refine(P::normalize(value))
+    }
+}
+
+impl<T, P> Checked<T, P> {
+    /// Convert to a weaker proof, without checking the value again.
+    ///
+    /// This is only possible when `P` [`Implies`] `Q`.
+    ///
+    /// ```
+    /// use check_mate::{Checked, Implies, Predicate};
+    ///
+    /// struct LessThan<const N: u32>;
+    ///
+    /// impl<const N: u32> Predicate<u32> for LessThan<N> {
+    ///     type Err = &'static str;
+    ///
+    ///     fn refine(value: u32) -> Result<u32, Self::Err> {
+    ///         if value < N { Ok(value) } else { Err("too big") }
+    ///     }
+    /// }
+    ///
+    /// impl Implies<LessThan<100>> for LessThan<10> {}
+    ///
+    /// let small: Checked<u32, LessThan<10>> = Checked::refine(7).unwrap();
+    /// let medium: Checked<u32, LessThan<100>> = small.weaken();
+    /// assert_eq!(*medium, 7);
+    /// ```
+    pub fn weaken<Q>(self) -> Checked<T, Q>
+    where
+        P: Implies<Q>,
+    {
+        Checked::new_unchecked(self.0)
+    }
+
     /// Retrieve the inner value, dropping the 'proof' that it was checked.
     pub fn into_inner(self) -> T {
         self.0
     }
 }
 
-impl<T> core::ops::Deref for Checked<T> {
+impl<T, Q, P> Checked<Checked<T, Q>, P> {
+    /// Remove a layer of nesting, keeping the inner proof.
+    ///
+    /// Nested checked values can arise when generic code layers checks on values that have already
+    /// been checked.
+    pub fn flatten(self) -> Checked<T, Q> {
+        self.0
+    }
+}
+
+/// A `Checked<T>` has already been checked, so checking it again trivially passes.
+///
+/// This allows already-checked values to be passed wherever a [`Check`] with `Ok = T` is expected,
+/// and the result of [`Checked::try_from`] or [`Checked::from`] will be a `Checked<T>`, rather than
+/// a nested `Checked<Checked<T>>`.
+impl<T> Check for Checked<T> {
+    type Ok = T;
+    type Err = core::convert::Infallible;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        Ok(self.0)
+    }
+}
+
+impl<T, P> core::ops::Deref for Checked<T, P> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -286,20 +952,249 @@ impl<T> core::ops::Deref for Checked<T> {
     }
 }
 
+impl<T, P> AsRef<T> for Checked<T, P> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// `Checked<T, P>` compares and hashes like `T` for every `P`, so checked values can be used as map
+/// keys and looked up by unchecked values.
+impl<T, P> core::borrow::Borrow<T> for Checked<T, P> {
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Like `String`, a `Checked<String, P>` can be looked up by `&str`.
+#[cfg(feature = "alloc")]
+impl<P> core::borrow::Borrow<str> for Checked<alloc::string::String, P> {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<T: Clone, P> Clone for Checked<T, P> {
+    fn clone(&self) -> Self {
+        Checked::new_unchecked(self.0.clone())
+    }
+}
+
+impl<T: fmt::Debug, P> fmt::Debug for Checked<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Checked").field(&self.0).finish()
+    }
+}
+
+impl<T: PartialEq, P> PartialEq for Checked<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, P> Eq for Checked<T, P> {}
+
+impl<T: PartialOrd, P> PartialOrd for Checked<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: Ord, P> Ord for Checked<T, P> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: Hash, P> Hash for Checked<T, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: ::serde::Serialize, P> ::serde::Serialize for Checked<T, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
 #[cfg(feature = "serde")]
-impl<'de, T> serde::Deserialize<'de> for Checked<T>
+impl<'de, T, P> ::serde::Deserialize<'de> for Checked<T, P>
 where
-    T: serde::Deserialize<'de> + Check<Ok = T>,
-    T::Err: core::fmt::Display,
+    T: ::serde::Deserialize<'de>,
+    P: Predicate<T>,
+    P::Err: fmt::Display,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
-        D: serde::Deserializer<'de>,
+        D: ::serde::Deserializer<'de>,
     {
-        use serde::de::Error;
-
         let value = T::deserialize(deserializer)?;
-        Self::try_from(value).map_err(D::Error::custom)
+        Self::refine(value)
+            .map_err(|error| serde::invalid(error::decode::CheckedTypeName::<T, P>::new(), error))
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: ::borsh::BorshSerialize, P> ::borsh::BorshSerialize for Checked<T, P> {
+    fn serialize<W: ::borsh::io::Write>(&self, writer: &mut W) -> ::borsh::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T, P> ::borsh::BorshDeserialize for Checked<T, P>
+where
+    T: ::borsh::BorshDeserialize,
+    P: Predicate<T>,
+    P::Err: fmt::Display,
+{
+    fn deserialize_reader<R: ::borsh::io::Read>(reader: &mut R) -> ::borsh::io::Result<Self> {
+        let value = T::deserialize_reader(reader)?;
+        Self::refine(value).map_err(|error| {
+            let message =
+                error::decode::Invalid(error::decode::CheckedTypeName::<T, P>::new(), error);
+            ::borsh::io::Error::new(
+                ::borsh::io::ErrorKind::InvalidData,
+                alloc::string::ToString::to_string(&message),
+            )
+        })
+    }
+}
+
+/// The number of values to generate when looking for one that passes the check, before giving up.
+#[cfg(feature = "arbitrary")]
+const ARBITRARY_ATTEMPTS: usize = 16;
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, P> ::arbitrary::Arbitrary<'a> for Checked<T, P>
+where
+    T: ::arbitrary::Arbitrary<'a>,
+    P: Predicate<T>,
+{
+    fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        for _ in 0..ARBITRARY_ATTEMPTS {
+            if let Ok(checked) = Self::refine(T::arbitrary(u)?) {
+                return Ok(checked);
+            }
+        }
+        Err(::arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        (T::size_hint(depth).0, None)
+    }
+}
+
+/// The number of values to generate when looking for one that passes the check, before panicking.
+#[cfg(feature = "quickcheck")]
+const QUICKCHECK_ATTEMPTS: usize = 1000;
+
+#[cfg(feature = "quickcheck")]
+impl<T, P> ::quickcheck::Arbitrary for Checked<T, P>
+where
+    T: ::quickcheck::Arbitrary,
+    P: Predicate<T> + 'static,
+{
+    fn arbitrary(g: &mut ::quickcheck::Gen) -> Self {
+        (0..QUICKCHECK_ATTEMPTS)
+            .find_map(|_| Self::refine(T::arbitrary(g)).ok())
+            .unwrap_or_else(|| {
+                panic!(
+                    "no {} passed the check after {QUICKCHECK_ATTEMPTS} attempts",
+                    core::any::type_name::<T>(),
+                )
+            })
+    }
+
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.0.shrink().filter_map(|value| Self::refine(value).ok()))
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<T: ::bincode::Encode, P> ::bincode::Encode for Checked<T, P> {
+    fn encode<E: ::bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), ::bincode::error::EncodeError> {
+        self.0.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<T, P, Context> ::bincode::Decode<Context> for Checked<T, P>
+where
+    T: ::bincode::Decode<Context>,
+    P: Predicate<T>,
+    P::Err: fmt::Display,
+{
+    fn decode<D: ::bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, ::bincode::error::DecodeError> {
+        let value = T::decode(decoder)?;
+        Self::refine(value).map_err(bincode_error::<T, P>)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, T, P, Context> ::bincode::BorrowDecode<'de, Context> for Checked<T, P>
+where
+    T: ::bincode::BorrowDecode<'de, Context>,
+    P: Predicate<T>,
+    P::Err: fmt::Display,
+{
+    fn borrow_decode<D: ::bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, ::bincode::error::DecodeError> {
+        let value = T::borrow_decode(decoder)?;
+        Self::refine(value).map_err(bincode_error::<T, P>)
+    }
+}
+
+/// The error for a decoded `Checked<T, P>` that failed its check.
+#[cfg(feature = "bincode")]
+fn bincode_error<T, P>(error: impl fmt::Display) -> ::bincode::error::DecodeError {
+    let message = error::decode::Invalid(error::decode::CheckedTypeName::<T, P>::new(), error);
+    ::bincode::error::DecodeError::OtherString(alloc::string::ToString::to_string(&message))
+}
+
+#[cfg(feature = "schemars")]
+impl<T, P> ::schemars::JsonSchema for Checked<T, P>
+where
+    T: ::schemars::JsonSchema,
+    P: Predicate<T> + 'static,
+{
+    fn inline_schema() -> bool {
+        // Constrained schemas are inlined, since they're specific to where they're used.
+        !schema::is_inherent::<P>() || T::inline_schema()
+    }
+
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        if schema::is_inherent::<P>() {
+            T::schema_id()
+        } else {
+            alloc::format!(
+                "check_mate::Checked<{}, {}>",
+                T::schema_id(),
+                core::any::type_name::<P>()
+            )
+            .into()
+        }
+    }
+
+    fn json_schema(generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+        let mut schema = T::json_schema(generator);
+        P::constrain_schema(&mut schema);
+        schema
     }
 }
 
@@ -322,10 +1217,143 @@ pub trait Check {
     fn check(self) -> Result<Self::Ok, Self::Err>;
 }
 
+/// Values that can be checked by reference.
+///
+/// [`Check`] consumes the value being checked, so that checks can transform it. Many checks don't
+/// need to, though, and implementing `CheckRef` as well allows values to be checked where they're
+/// only borrowed (e.g. elements of a slice). Derived `Check` implementations also implement
+/// `CheckRef`, unless they have `nested` fields.
+///
+/// ```
+/// use check_mate::CheckRef;
+///
+/// struct Even(u32);
+///
+/// impl CheckRef for Even {
+///     type Err = &'static str;
+///
+///     fn check_ref(&self) -> Result<(), Self::Err> {
+///         if self.0 % 2 == 0 { Ok(()) } else { Err("must be even") }
+///     }
+/// }
+///
+/// assert!([Even(2), Even(4)][..].check_ref().is_ok());
+/// assert_eq!([Even(2), Even(3)][..].check_ref(), Err("must be even"));
+/// ```
+pub trait CheckRef {
+    /// The error returned when the check fails.
+    type Err;
+
+    /// Check `self`, by reference.
+    ///
+    /// # Errors
+    ///
+    /// If `self` is valid this should return `Ok(())`, and otherwise `Err(Self::Err)`.
+    fn check_ref(&self) -> Result<(), Self::Err>;
+}
+
+impl<T: CheckRef + ?Sized> CheckRef for &T {
+    type Err = T::Err;
+
+    fn check_ref(&self) -> Result<(), Self::Err> {
+        (**self).check_ref()
+    }
+}
+
+/// Slices are checked element by element, returning the first error.
+impl<T: CheckRef> CheckRef for [T] {
+    type Err = T::Err;
+
+    fn check_ref(&self) -> Result<(), Self::Err> {
+        self.iter().try_for_each(CheckRef::check_ref)
+    }
+}
+
+/// References are checked using the referenced value's [`CheckRef`] implementation, so `Checked<&T>`
+/// can be used as a checked view of a borrowed value.
+impl<'a, T: CheckRef + ?Sized> Check for &'a T {
+    type Ok = &'a T;
+    type Err = T::Err;
+
+    fn check(self) -> Result<Self::Ok, Self::Err> {
+        self.check_ref()?;
+        Ok(self)
+    }
+}
+
+/// Predicates that can be used to refine values.
+///
+/// Unlike [`Check`], which is implemented by the value being checked, predicates are implemented by
+/// marker types that are used as the second type parameter of [`Checked`]. This allows a single
+/// type to have several named refinements, e.g. `Checked<u32, NonZero>` and
+/// `Checked<u32, LessThan100>`.
+///
+/// The [`combinators`] [`And`](combinators::And), [`Or`](combinators::Or), and
+/// [`Not`](combinators::Not) can also be used as predicates.
+pub trait Predicate<T> {
+    /// The error returned when the value doesn't satisfy the predicate.
+    type Err;
+
+    /// Check that `value` satisfies the predicate.
+    ///
+    /// # Errors
+    ///
+    /// If `value` satisfies the predicate this should return `Ok(value)`, and otherwise
+    /// `Err(Self::Err)`.
+    fn refine(value: T) -> Result<T, Self::Err>;
+
+    /// Add the predicate's constraints to the JSON Schema for `T`.
+    ///
+    /// This is used by the `JsonSchema` implementation for [`Checked`], which is enabled by the
+    /// `schemars` feature. The built-in length, range, and pattern predicates add the corresponding
+    /// keywords (e.g. `maxLength`, `minimum`, `pattern`). By default, the schema is left unchanged.
+    #[cfg(feature = "schemars")]
+    fn constrain_schema(_schema: &mut ::schemars::Schema) {}
+}
+
+/// Implications between predicates.
+///
+/// Implementing `Implies<Weaker>` for a predicate asserts that any value satisfying the predicate
+/// also satisfies `Weaker`. This allows a `Checked<T, Self>` to be converted to a
+/// `Checked<T, Weaker>` using [`Checked::weaken`], without checking the value again.
+///
+/// Nothing verifies the implication, so implementations must be careful not to assert one that
+/// doesn't hold for every value.
+pub trait Implies<Weaker> {}
+
+/// Canonicalization of values before checking them against a predicate.
+///
+/// Implementing `Normalize<T>` for a predicate allows call sites to choose between rejecting
+/// invalid values with [`Checked::refine`], or canonicalizing them first with
+/// [`Checked::normalize`] (e.g. trimming whitespace rather than rejecting it).
+pub trait Normalize<T>: Predicate<T> {
+    /// Canonicalize `value`.
+    ///
+    /// Normalization should usually produce a value that satisfies the predicate, but values are
+    /// still checked afterwards.
+    fn normalize(value: T) -> T;
+}
+
+/// The default predicate for [`Checked`], which uses the value's implementation of [`Check`].
+#[derive(Clone, Copy, Debug)]
+pub struct Inherent;
+
+impl<T: Check<Ok = T>> Predicate<T> for Inherent {
+    type Err = T::Err;
+
+    fn refine(value: T) -> Result<T, Self::Err> {
+        value.check()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[derive(Debug, PartialEq)]
     #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+    #[cfg_attr(
+        feature = "borsh",
+        derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+    )]
     struct LessThan10(usize);
 
     impl Check for LessThan10 {
@@ -352,7 +1380,31 @@ mod tests {
         }
     }
 
-    use super::{Check, Checked};
+    use super::{Check, Checked, Predicate};
+
+    struct Even;
+
+    impl Predicate<usize> for Even {
+        type Err = &'static str;
+
+        fn refine(value: usize) -> Result<usize, Self::Err> {
+            if value.is_multiple_of(2) {
+                Ok(value)
+            } else {
+                Err("odd")
+            }
+        }
+    }
+
+    struct Any;
+
+    impl<T> Predicate<T> for Any {
+        type Err = core::convert::Infallible;
+
+        fn refine(value: T) -> Result<T, Self::Err> {
+            Ok(value)
+        }
+    }
 
     #[test]
     fn try_from() {
@@ -367,6 +1419,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_from_context() {
+        let error = Checked::try_from_context(LessThan10(11)).unwrap_err();
+        assert_eq!(error.error(), &"too big");
+        assert!(error.type_name().ends_with("LessThan10"));
+        assert_eq!(
+            error.location().map(core::panic::Location::file),
+            Some(file!())
+        );
+    }
+
+    #[test]
+    fn refine() {
+        assert_eq!(Checked::<_, Even>::refine(4).as_deref(), Ok(&4));
+        assert_eq!(Checked::<_, Even>::refine(5).as_deref(), Err(&"odd"));
+        assert_eq!(
+            Checked::<_, super::Inherent>::refine(LessThan10(11)).as_deref(),
+            Err(&"too big")
+        );
+    }
+
+    impl super::Implies<Any> for Even {}
+
+    #[test]
+    fn weaken() {
+        let even = Checked::<_, Even>::refine(4).unwrap();
+        let any: Checked<_, Any> = even.weaken();
+        assert_eq!(*any, 4);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn borrow() {
+        use std::collections::{BTreeSet, HashMap};
+
+        use crate::checks::NonBlank;
+
+        let mut ages: HashMap<Checked<String, NonBlank>, u32> = HashMap::new();
+        ages.insert(Checked::refine("ferris".to_string()).unwrap(), 9);
+        assert_eq!(ages.get("ferris"), Some(&9));
+        assert_eq!(ages.get("corro"), None);
+
+        let evens: BTreeSet<Checked<usize, Even>> = vec![2, 4]
+            .into_iter()
+            .map(|n| Checked::refine(n).unwrap())
+            .collect();
+        assert!(evens.contains(&4));
+        assert!(!evens.contains(&3));
+
+        let even = Checked::<_, Even>::refine(2).unwrap();
+        assert_eq!(AsRef::<usize>::as_ref(&even), &2);
+    }
+
+    #[test]
+    fn independent_predicates() {
+        struct Small;
+
+        impl Predicate<LessThan10> for Small {
+            type Err = &'static str;
+
+            fn refine(value: LessThan10) -> Result<LessThan10, Self::Err> {
+                if value.0 < 5 {
+                    Ok(value)
+                } else {
+                    Err("not small")
+                }
+            }
+        }
+
+        struct Odd;
+
+        impl Predicate<LessThan10> for Odd {
+            type Err = &'static str;
+
+            fn refine(value: LessThan10) -> Result<LessThan10, Self::Err> {
+                if value.0 % 2 == 1 {
+                    Ok(value)
+                } else {
+                    Err("not odd")
+                }
+            }
+        }
+
+        let small: Checked<LessThan10, Small> = Checked::refine(LessThan10(4)).unwrap();
+        let odd: Checked<LessThan10, Odd> = Checked::refine(LessThan10(7)).unwrap();
+        assert_eq!((&*small, &*odd), (&LessThan10(4), &LessThan10(7)));
+
+        assert_eq!(
+            Checked::<_, Small>::refine(LessThan10(7)).as_deref(),
+            Err(&"not small")
+        );
+        assert_eq!(
+            Checked::<_, Odd>::refine(LessThan10(4)).as_deref(),
+            Err(&"not odd")
+        );
+    }
+
+    #[test]
+    fn flatten() {
+        let checked = Checked::try_from(LessThan10(3)).unwrap();
+        let checked: Checked<LessThan10> = Checked::from(checked);
+        assert_eq!(&*checked, &LessThan10(3));
+
+        let even = Checked::<_, Even>::refine(4).unwrap();
+        let nested = Checked::<_, Any>::refine(even).unwrap();
+        let flat: Checked<usize, Even> = nested.flatten();
+        assert_eq!(*flat, 4);
+    }
+
     #[test]
     fn from() {
         assert_eq!(&*Checked::from(GenLessThan10), &LessThan10(3));
@@ -386,8 +1547,17 @@ mod tests {
             serde_json::from_str::<Checked<LessThan10>>("10")
                 .err()
                 .map(|error| error.to_string()),
-            Some("too big".to_string())
+            Some("invalid value for Checked<LessThan10>: too big".to_string())
         );
+
+        assert_eq!(
+            serde_json::from_str::<Checked<usize, Even>>("4")
+                .ok()
+                .as_deref(),
+            Some(&4)
+        );
+
+        assert!(serde_json::from_str::<Checked<usize, Even>>("5").is_err());
     }
 
     #[cfg(feature = "serde")]
@@ -398,4 +1568,195 @@ mod tests {
             serde_json::to_string(&LessThan10(3)).unwrap()
         );
     }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        use crate::{
+            checks::{Bounded, LenBetween, MaxLen, MultipleOf, NonEmpty, Percent, Port},
+            combinators::And,
+        };
+        use schemars::JsonSchema;
+
+        #[derive(schemars::JsonSchema)]
+        struct Request {
+            #[allow(dead_code)]
+            name: Checked<String, MaxLen<8>>,
+        }
+
+        #[derive(schemars::JsonSchema)]
+        struct Name(#[allow(dead_code)] String);
+
+        impl Check for Name {
+            type Ok = Self;
+            type Err = core::convert::Infallible;
+
+            fn check(self) -> Result<Self, Self::Err> {
+                Ok(self)
+            }
+        }
+
+        let schema = schemars::schema_for!(Checked<u16, Bounded<1, 1024>>);
+        assert_eq!(schema.get("minimum"), Some(&1.into()));
+        assert_eq!(schema.get("maximum"), Some(&1024.into()));
+
+        let schema = schemars::schema_for!(Checked<u32, MultipleOf<512>>);
+        assert_eq!(schema.get("multipleOf"), Some(&512.into()));
+
+        let schema = schemars::schema_for!(Checked<u16, Port<false>>);
+        assert_eq!(schema.get("minimum"), Some(&1024.into()));
+        assert_eq!(schema.get("maximum"), Some(&65535.into()));
+
+        let schema = schemars::schema_for!(Checked<f64, Percent>);
+        assert_eq!(schema.get("minimum"), Some(&0.into()));
+        assert_eq!(schema.get("maximum"), Some(&100.into()));
+
+        let schema = schemars::schema_for!(Checked<String, LenBetween<3, 32>>);
+        assert_eq!(schema.get("minLength"), Some(&3.into()));
+        assert_eq!(schema.get("maxLength"), Some(&32.into()));
+
+        let schema = schemars::schema_for!(Checked<Vec<u8>, And<NonEmpty, MaxLen<4>>>);
+        assert_eq!(schema.get("minItems"), Some(&1.into()));
+        assert_eq!(schema.get("maxItems"), Some(&4.into()));
+
+        #[cfg(feature = "regex")]
+        {
+            struct Lowercase;
+
+            impl crate::checks::Pattern for Lowercase {
+                fn regex() -> &'static regex::Regex {
+                    static REGEX: std::sync::LazyLock<regex::Regex> =
+                        std::sync::LazyLock::new(|| regex::Regex::new("^[a-z]+$").unwrap());
+                    &REGEX
+                }
+            }
+
+            let schema = schemars::schema_for!(Checked<String, crate::checks::Matches<Lowercase>>);
+            assert_eq!(schema.get("pattern"), Some(&"^[a-z]+$".into()));
+        }
+
+        // `Checked<T>` shares `T`'s schema, but other predicates get their own.
+        assert_eq!(Checked::<Name>::schema_id(), Name::schema_id());
+        assert!(!Checked::<Name>::inline_schema());
+        assert_ne!(Checked::<usize, Even>::schema_id(), usize::schema_id());
+
+        // Unconstrained predicates leave the schema for `T` unchanged.
+        assert_eq!(
+            schemars::schema_for!(Checked<usize, Even>),
+            schemars::schema_for!(usize)
+        );
+
+        let schema = schemars::schema_for!(Request);
+        assert_eq!(
+            schema.pointer("/properties/name/maxLength"),
+            Some(&8.into())
+        );
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh() {
+        #[derive(borsh::BorshDeserialize, borsh::BorshSerialize)]
+        struct Record {
+            id: u32,
+            value: Checked<usize, Even>,
+        }
+
+        let record = Record {
+            id: 1,
+            value: Checked::refine(4).unwrap(),
+        };
+        let bytes = borsh::to_vec(&record).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&(1_u32, 4_usize)).unwrap());
+
+        let record: Record = borsh::from_slice(&bytes).unwrap();
+        assert_eq!((record.id, *record.value), (1, 4));
+
+        let bytes = borsh::to_vec(&(1_u32, 5_usize)).unwrap();
+        let error = borsh::from_slice::<Record>(&bytes).err().unwrap();
+        assert_eq!(error.kind(), borsh::io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.to_string(),
+            "invalid value for Checked<usize, Even>: odd"
+        );
+
+        let bytes = borsh::to_vec(&LessThan10(10)).unwrap();
+        assert!(borsh::from_slice::<Checked<LessThan10>>(&bytes).is_err());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode() {
+        #[derive(bincode::BorrowDecode, bincode::Encode)]
+        struct Record<'a> {
+            name: Checked<&'a str, crate::checks::NonEmpty>,
+            value: Checked<usize, Even>,
+        }
+
+        let config = bincode::config::standard();
+        let record = Record {
+            name: Checked::refine("abc").unwrap(),
+            value: Checked::refine(4).unwrap(),
+        };
+        let bytes = bincode::encode_to_vec(&record, config).unwrap();
+        assert_eq!(
+            bytes,
+            bincode::encode_to_vec(("abc", 4_usize), config).unwrap()
+        );
+
+        let (record, _): (Record, _) = bincode::borrow_decode_from_slice(&bytes, config).unwrap();
+        assert_eq!((*record.name, *record.value), ("abc", 4));
+
+        let bytes = bincode::encode_to_vec(("abc", 5_usize), config).unwrap();
+        let error = bincode::borrow_decode_from_slice::<Record, _>(&bytes, config)
+            .err()
+            .unwrap();
+        assert!(matches!(
+            error,
+            bincode::error::DecodeError::OtherString(message)
+                if message == "invalid value for Checked<usize, Even>: odd"
+        ));
+
+        let bytes = bincode::encode_to_vec(3_usize, config).unwrap();
+        assert!(bincode::decode_from_slice::<Checked<usize, Even>, _>(&bytes, config).is_err());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4]);
+        let even = Checked::<usize, Even>::arbitrary(&mut u).unwrap();
+        assert_eq!(*even, 4);
+
+        // Exhausted input produces zeros, which are even.
+        assert_eq!(*Checked::<usize, Even>::arbitrary(&mut u).unwrap(), 0);
+
+        // Values are only generated a limited number of times.
+        let mut u = Unstructured::new(&[1; 16 * 8]);
+        assert!(Checked::<usize, Even>::arbitrary(&mut u).is_err());
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn quickcheck() {
+        use quickcheck::{Arbitrary, Gen};
+
+        fn is_even(even: Checked<usize, Even>) -> bool {
+            even.into_inner().is_multiple_of(2)
+        }
+
+        let mut g = Gen::new(100);
+        for _ in 0..100 {
+            assert_eq!(*Checked::<usize, Even>::arbitrary(&mut g) % 2, 0);
+        }
+
+        let even = Checked::<usize, Even>::refine(100).unwrap();
+        let shrunk: Vec<_> = even.shrink().map(Checked::into_inner).collect();
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|value| value % 2 == 0 && *value < 100));
+
+        quickcheck::quickcheck(is_even as fn(_) -> bool);
+    }
 }
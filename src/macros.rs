@@ -0,0 +1,274 @@
+//! Declarative macros.
+
+/// Declare a newtype with a [`Check`](crate::Check) implementation.
+///
+/// This is a lightweight alternative to `#[derive(Check)]` for newtypes, which doesn't require the
+/// `derive` feature (or any proc-macro dependencies). The newtype is declared along with a check,
+/// written as a closure over a reference to the base value:
+///
+/// ```
+/// use check_mate::{checked_type, Checked};
+///
+/// checked_type! {
+///     /// An even number.
+///     #[derive(Clone, Copy, Debug)]
+///     pub struct Even(u32) = |value| -> Result<(), &'static str> {
+///         if value.is_multiple_of(2) { Ok(()) } else { Err("must be even") }
+///     }
+/// }
+///
+/// let even: Checked<Even> = Checked::try_from(Even::new(4)).unwrap();
+/// assert_eq!(*even.into_inner(), 4);
+/// assert_eq!(Checked::try_from(Even::new(3)).err(), Some("must be even"));
+/// ```
+///
+/// This generates:
+///
+/// - The newtype itself, with the given attributes and visibility. The field can be given a
+///   visibility too (e.g. `pub struct Even(pub u32)`), but is private by default.
+/// - `new` and `into_inner` methods, for wrapping and unwrapping the base value. Wrapping a value
+///   doesn't check it – that's what `Checked<Even>` is for.
+/// - A [`Check`](crate::Check) implementation, with `Ok = Self` and `Err` being the closure's error
+///   type.
+/// - A [`Deref`](core::ops::Deref) implementation targeting the base type.
+///
+/// Adding `#[serde]` before any other attributes also generates `Serialize` and `Deserialize`
+/// implementations that forward to the base type, so that `Checked<Even>` can be deserialized (and
+/// checked) from the base type's representation. This requires the `serde` feature.
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use check_mate::{checked_type, Checked};
+///
+/// checked_type! {
+///     #[serde]
+///     #[derive(Debug)]
+///     pub struct Even(u32) = |value| -> Result<(), &'static str> {
+///         if value.is_multiple_of(2) { Ok(()) } else { Err("must be even") }
+///     }
+/// }
+///
+/// assert!(serde_json::from_str::<Checked<Even>>("4").is_ok());
+/// assert!(serde_json::from_str::<Checked<Even>>("3").is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! checked_type {
+    (
+        #[serde]
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($field_vis:vis $inner:ty)
+            = |$value:ident| -> Result<(), $err:ty> $body:block
+    ) => {
+        $crate::checked_type! {
+            $(#[$attr])*
+            $vis struct $name($field_vis $inner) = |$value| -> Result<(), $err> $body
+        }
+        $crate::__checked_type_serde!($name, $inner);
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($field_vis:vis $inner:ty)
+            = |$value:ident| -> Result<(), $err:ty> $body:block
+    ) => {
+        $(#[$attr])*
+        $vis struct $name($field_vis $inner);
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Wrap a value, without checking it.
+            #[must_use]
+            $vis const fn new(value: $inner) -> Self {
+                Self(value)
+            }
+
+            /// Retrieve the wrapped value.
+            #[must_use]
+            $vis fn into_inner(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl $crate::Check for $name {
+            type Ok = Self;
+            type Err = $err;
+
+            fn check(self) -> ::core::result::Result<Self::Ok, Self::Err> {
+                #[allow(clippy::ptr_arg)]
+                fn check($value: &$inner) -> ::core::result::Result<(), $err> $body
+                check(&self.0)?;
+                ::core::result::Result::Ok(self)
+            }
+        }
+
+        impl ::core::ops::Deref for $name {
+            type Target = $inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+    };
+}
+
+/// Implement [`Check`](crate::Check) for a type using a closure.
+///
+/// This cuts the boilerplate of implementing `Check` by hand for simple cases. The closure takes
+/// the value to check and returns it if the check passes, so `Ok = Self`:
+///
+/// ```
+/// use check_mate::{impl_check, Checked};
+///
+/// struct Signed {
+///     payload: Vec<u8>,
+///     signature: u8,
+/// }
+///
+/// fn verify(payload: &[u8], signature: u8) -> Result<(), &'static str> {
+///     let sum = payload.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+///     if sum == signature { Ok(()) } else { Err("bad signature") }
+/// }
+///
+/// impl_check!(Signed, &'static str, |signed| {
+///     verify(&signed.payload, signed.signature)?;
+///     Ok(signed)
+/// });
+///
+/// assert!(Checked::try_from(Signed { payload: vec![1, 2], signature: 3 }).is_ok());
+/// assert_eq!(
+///     Checked::try_from(Signed { payload: vec![1, 2], signature: 4 }).err(),
+///     Some("bad signature"),
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_check {
+    ($ty:ty, $err:ty, |$value:ident| $body:expr $(,)?) => {
+        impl $crate::Check for $ty {
+            type Ok = Self;
+            type Err = $err;
+
+            fn check(self) -> ::core::result::Result<Self::Ok, Self::Err> {
+                let $value = self;
+                $body
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_type_serde {
+    ($name:ident, $inner:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                <$inner as $crate::__private::serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __checked_type_serde {
+    ($name:ident, $inner:ty) => {
+        ::core::compile_error!("`#[serde]` requires the `serde` feature of `check_mate`");
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Check, Checked};
+
+    checked_type! {
+        #[derive(Debug, PartialEq)]
+        struct Even(u32) = |value| -> Result<(), &'static str> {
+            if value.is_multiple_of(2) {
+                Ok(())
+            } else {
+                Err("must be even")
+            }
+        }
+    }
+
+    checked_type! {
+        struct Name(pub String) = |name| -> Result<(), crate::checks::Empty> {
+            if name.is_empty() {
+                Err(crate::checks::Empty)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    struct Port(u16);
+
+    impl_check!(Port, &'static str, |port| {
+        if port.0 == 0 {
+            Err("must not be zero")
+        } else {
+            Ok(port)
+        }
+    });
+
+    #[test]
+    fn impl_check() {
+        assert_eq!(Port(80).check().map(|port| port.0), Ok(80));
+        assert_eq!(Port(0).check().err(), Some("must not be zero"));
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(Even::new(2).check(), Ok(Even::new(2)));
+        assert_eq!(Even::new(3).check(), Err("must be even"));
+        assert_eq!(*Checked::try_from(Even::new(4)).unwrap().into_inner(), 4);
+    }
+
+    #[test]
+    fn field_visibility() {
+        let name = Name(String::from("ferris"));
+        assert_eq!(name.len(), 6);
+        assert_eq!(name.check().map(Name::into_inner).as_deref(), Ok("ferris"));
+        assert!(Name(String::new()).check().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        checked_type! {
+            #[serde]
+            #[derive(Debug)]
+            struct Small(u8) = |value| -> Result<(), &'static str> {
+                if *value < 10 {
+                    Ok(())
+                } else {
+                    Err("too big")
+                }
+            }
+        }
+
+        let small: Checked<Small> = serde_json::from_str("3").unwrap();
+        assert_eq!(**small, 3);
+        assert_eq!(serde_json::to_string(&small).unwrap(), "3");
+        assert_eq!(
+            serde_json::from_str::<Checked<Small>>("10")
+                .unwrap_err()
+                .to_string(),
+            "invalid value for Checked<Small>: too big"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Strategies for generating checked values with `proptest`.
+//!
+//! This module is enabled by the `proptest` feature. [`checked_strategy`] and [`refined_strategy`]
+//! wrap a strategy for unchecked values, discarding values that fail the check. Shrinking works
+//! through the inner strategy, so shrunk values are also checked.
+//!
+//! ```
+//! use check_mate::{checks::NonZero, proptest::refined_strategy};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn divide(n in any::<u32>(), d in refined_strategy::<_, NonZero>(any::<u32>())) {
+//!         prop_assert!(n / *d <= n);
+//!     }
+//! }
+//! divide();
+//! ```
+//!
+//! Since values are generated by rejection, the inner strategy should usually produce valid values,
+//! otherwise `proptest` will give up after too many rejections.
+
+use proptest::strategy::Strategy;
+
+use crate::{Check, Checked, Inherent, Predicate};
+
+/// Generate values that pass their [`Check`], using `inner` to generate candidates.
+///
+/// ```
+/// use check_mate::{proptest::checked_strategy, Check, Checked};
+/// use proptest::prelude::*;
+///
+/// #[derive(Debug)]
+/// struct Even(u32);
+///
+/// impl Check for Even {
+///     type Ok = Self;
+///     type Err = &'static str;
+///
+///     fn check(self) -> Result<Self, Self::Err> {
+///         if self.0 % 2 == 0 { Ok(self) } else { Err("must be even") }
+///     }
+/// }
+///
+/// proptest! {
+///     fn halves(even in checked_strategy(any::<u32>().prop_map(Even))) {
+///         prop_assert_eq!(even.0 / 2 * 2, even.0);
+///     }
+/// }
+/// halves();
+/// ```
+pub fn checked_strategy<S>(inner: S) -> impl Strategy<Value = Checked<S::Value>>
+where
+    S: Strategy,
+    S::Value: Check<Ok = S::Value>,
+{
+    refined_strategy::<S, Inherent>(inner)
+}
+
+/// Generate values that satisfy the [`Predicate`] `P`, using `inner` to generate candidates.
+pub fn refined_strategy<S, P>(inner: S) -> impl Strategy<Value = Checked<S::Value, P>>
+where
+    S: Strategy,
+    P: Predicate<S::Value>,
+{
+    inner.prop_filter_map("value failed its check", |value| {
+        Checked::refine(value).ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{
+        prelude::*,
+        strategy::ValueTree,
+        test_runner::{Config, TestRunner},
+    };
+
+    use super::refined_strategy;
+    use crate::checks::Bounded;
+
+    proptest! {
+        #[test]
+        fn bounded(value in refined_strategy::<_, Bounded<10, 20>>(0..100_u32)) {
+            prop_assert!((10..=20).contains(&*value));
+        }
+    }
+
+    #[test]
+    fn shrinking() {
+        let mut runner = TestRunner::new(Config::default());
+        let strategy = refined_strategy::<_, Bounded<10, 20>>(0..100_u32);
+        let mut tree = loop {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+            if *tree.current() > 10 {
+                break tree;
+            }
+        };
+        while tree.simplify() {
+            assert!((10..=20).contains(&*tree.current()));
+        }
+        assert_eq!(*tree.current(), 10);
+    }
+}
@@ -0,0 +1,168 @@
+//! Utilities for decoding and checking `prost` messages.
+//!
+//! This module is enabled by the `prost` feature. Messages can be checked as they're decoded, so
+//! that invalid payloads (e.g. in gRPC requests) are rejected at the decoding boundary, using
+//! [`Checked::decode_checked`] or [`decode`]. Both report a [`DecodeError`], which distinguishes
+//! malformed messages from messages that failed their checks.
+//!
+//! ```
+//! use check_mate::{prost::DecodeError, Check, Checked};
+//! use prost::Message;
+//!
+//! #[derive(Clone, PartialEq, prost::Message)]
+//! struct CreateUser {
+//!     #[prost(string, tag = "1")]
+//!     name: String,
+//! }
+//!
+//! impl Check for CreateUser {
+//!     type Ok = Self;
+//!     type Err = &'static str;
+//!
+//!     fn check(self) -> Result<Self, Self::Err> {
+//!         if self.name.is_empty() { Err("name must not be empty") } else { Ok(self) }
+//!     }
+//! }
+//!
+//! let bytes = CreateUser { name: "ferris".into() }.encode_to_vec();
+//! let request = Checked::<CreateUser>::decode_checked(&bytes[..]).unwrap();
+//! assert_eq!(request.name, "ferris");
+//!
+//! let bytes = CreateUser { name: String::new() }.encode_to_vec();
+//! assert_eq!(
+//!     Checked::<CreateUser>::decode_checked(&bytes[..]).unwrap_err(),
+//!     DecodeError::Invalid("name must not be empty"),
+//! );
+//!
+//! assert!(matches!(
+//!     Checked::<CreateUser>::decode_checked(&[0xff][..]),
+//!     Err(DecodeError::Decode(_)),
+//! ));
+//! ```
+
+use core::fmt;
+
+use prost::{bytes::Buf, Message};
+
+use crate::{Check, Checked, ErrorCode, Predicate};
+
+impl<T: Message + Default, P: Predicate<T>> Checked<T, P> {
+    /// Decode a message from `buf`, and check it.
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if the message can't be decoded, or if it fails the check.
+    pub fn decode_checked(buf: impl Buf) -> Result<Self, DecodeError<P::Err>> {
+        let value = T::decode(buf)?;
+        Self::refine(value).map_err(DecodeError::Invalid)
+    }
+}
+
+/// Decode a message from `buf`, and check it.
+///
+/// Unlike [`Checked::decode_checked`], this returns the result of `T`'s [`Check`] implementation,
+/// which needn't be `T` itself.
+///
+/// # Errors
+///
+/// This will return an error if the message can't be decoded, or if it fails the check.
+pub fn decode<T>(buf: impl Buf) -> Result<T::Ok, DecodeError<T::Err>>
+where
+    T: Message + Default + Check,
+{
+    T::decode(buf)?.check().map_err(DecodeError::Invalid)
+}
+
+/// An error from decoding and checking a message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodeError<E> {
+    /// The message couldn't be decoded.
+    Decode(prost::DecodeError),
+
+    /// The decoded message failed its check.
+    Invalid(E),
+}
+
+impl<E> From<prost::DecodeError> for DecodeError<E> {
+    fn from(error: prost::DecodeError) -> Self {
+        Self::Decode(error)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for DecodeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(error) => error.fmt(f),
+            Self::Invalid(error) => write!(f, "invalid message: {error}"),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for DecodeError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Decode(error) => Some(error),
+            Self::Invalid(error) => Some(error),
+        }
+    }
+}
+
+impl<E: ErrorCode> ErrorCode for DecodeError<E> {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Decode(_) => "decode",
+            Self::Invalid(error) => error.code(),
+        }
+    }
+
+    fn numeric_code(&self) -> Option<u32> {
+        match self {
+            Self::Decode(_) => None,
+            Self::Invalid(error) => error.numeric_code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::{decode, DecodeError};
+    use crate::{checks::Empty, Check, ErrorCode};
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Tags {
+        #[prost(string, repeated, tag = "1")]
+        tags: Vec<String>,
+    }
+
+    impl Check for Tags {
+        type Ok = Vec<String>;
+        type Err = Empty;
+
+        fn check(self) -> Result<Self::Ok, Self::Err> {
+            if self.tags.is_empty() {
+                Err(Empty)
+            } else {
+                Ok(self.tags)
+            }
+        }
+    }
+
+    #[test]
+    fn decode_message() {
+        let bytes = Tags {
+            tags: vec!["a".into()],
+        }
+        .encode_to_vec();
+        assert_eq!(decode::<Tags>(&bytes[..]), Ok(vec!["a".to_string()]));
+
+        let error = decode::<Tags>(&[][..]).unwrap_err();
+        assert_eq!(error.code(), "empty");
+        assert_eq!(error.to_string(), "invalid message: must not be empty");
+
+        let error = decode::<Tags>(&[0xff][..]).unwrap_err();
+        assert!(matches!(error, DecodeError::Decode(_)));
+        assert_eq!(error.code(), "decode");
+    }
+}
@@ -0,0 +1,39 @@
+//! The vocabulary for declaring refinements.
+//!
+//! This module re-exports the [`Predicate`]-related items from across the crate, so that
+//! refinements can be declared without hunting for where each item lives. The [`prelude`] module
+//! contains the most commonly used items, and can be glob-imported:
+//!
+//! ```
+//! use check_mate::refine::prelude::*;
+//!
+//! struct Even;
+//!
+//! impl Predicate<u32> for Even {
+//!     type Err = &'static str;
+//!
+//!     fn refine(value: u32) -> Result<u32, Self::Err> {
+//!         if value % 2 == 0 { Ok(value) } else { Err("odd") }
+//!     }
+//! }
+//!
+//! type EvenNumbers = Checked<Vec<u32>, All<Even>>;
+//!
+//! assert!(EvenNumbers::refine(vec![2, 4]).is_ok());
+//! ```
+
+pub use crate::checks::*;
+pub use crate::combinators::{All, AllError, And, AndError, Any, AnyError, Not, Or, OrError};
+pub use crate::dynamic::DynTag;
+#[cfg(feature = "alloc")]
+pub use crate::dynamic::{DynPredicate, DynPredicateError};
+pub use crate::{Checked, Implies, Inherent, Normalize, Predicate};
+
+/// The most commonly used items for declaring refinements.
+pub mod prelude {
+    pub use super::{
+        All, And, Any, Ascii, Bounded, Checked, Finite, Implies, LenBetween, Lowercase, MaxLen,
+        MinLen, Negative, NonBlank, NonEmpty, NonNegative, NonZero, Normalize, Not, NotNan, Or,
+        Positive, Predicate, Sorted, Trimmed, Uppercase,
+    };
+}
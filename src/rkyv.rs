@@ -0,0 +1,251 @@
+//! Utilities for archiving checked values with `rkyv`.
+//!
+//! This module is enabled by the `rkyv` feature. `Checked<T, P>` implements `Archive`,
+//! `Serialize`, and `Deserialize`, and is archived as an [`ArchivedChecked`] wrapping `T`'s
+//! archived form. Archives can come from anywhere (e.g. a memory-mapped file), so archived values
+//! are checked when they're deserialized:
+//!
+//! ```
+//! use check_mate::{checks::Bounded, Checked};
+//!
+//! type Port = Checked<u16, Bounded<1, 65535>>;
+//!
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&Port::refine(8080).unwrap()).unwrap();
+//! let port = rkyv::from_bytes::<Port, rkyv::rancor::Error>(&bytes).unwrap();
+//! assert_eq!(*port, 8080);
+//!
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&0_u16).unwrap();
+//! let error = rkyv::from_bytes::<Port, rkyv::rancor::Error>(&bytes).unwrap_err();
+//! assert!(error.to_string().starts_with("invalid value for Checked<u16, Bounded<1, 65535>>"));
+//! ```
+//!
+//! Accessing an archive without deserializing it only validates its bytes, so an
+//! `ArchivedChecked` isn't known to be valid. Types whose archived form implements
+//! [`CheckRef`] can be checked as they're accessed using [`access`], which returns a checked view
+//! of the archived value:
+//!
+//! ```
+//! use check_mate::CheckRef;
+//!
+//! #[derive(rkyv::Archive, rkyv::Serialize)]
+//! struct Range {
+//!     start: u32,
+//!     end: u32,
+//! }
+//!
+//! impl CheckRef for ArchivedRange {
+//!     type Err = &'static str;
+//!
+//!     fn check_ref(&self) -> Result<(), Self::Err> {
+//!         if self.start <= self.end { Ok(()) } else { Err("start must not be after end") }
+//!     }
+//! }
+//!
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&Range { start: 1, end: 2 }).unwrap();
+//! let range = check_mate::rkyv::access::<ArchivedRange, rkyv::rancor::Error>(&bytes).unwrap();
+//! assert_eq!(range.end, 2);
+//!
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&Range { start: 2, end: 1 }).unwrap();
+//! assert!(check_mate::rkyv::access::<ArchivedRange, rkyv::rancor::Error>(&bytes).is_err());
+//! ```
+
+use alloc::string::{String, ToString};
+use core::{any::type_name, fmt, marker::PhantomData, ops::Deref};
+
+use rkyv::{
+    api::high::HighValidator,
+    bytecheck::CheckBytes,
+    munge::munge,
+    rancor::{Fallible, Source},
+    Archive, Deserialize, Place, Portable, Serialize,
+};
+
+use crate::{
+    error::decode::{CheckedTypeName, Invalid, ShortTypeName},
+    CheckRef, Checked, Inherent, Predicate,
+};
+
+/// The archived form of [`Checked<T, P>`](Checked), which wraps `T`'s archived form `A`.
+///
+/// Archived values are validated like `A`, but they aren't checked until they're deserialized
+/// (see the [module documentation](self)).
+#[derive(CheckBytes, Portable)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[rkyv(crate = rkyv)]
+#[repr(transparent)]
+pub struct ArchivedChecked<A, P = Inherent> {
+    value: A,
+    predicate: PhantomData<fn() -> P>,
+}
+
+impl<A, P> Deref for ArchivedChecked<A, P> {
+    type Target = A;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<A: fmt::Debug, P> fmt::Debug for ArchivedChecked<A, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<A: CheckRef> CheckRef for ArchivedChecked<A> {
+    type Err = A::Err;
+
+    fn check_ref(&self) -> Result<(), Self::Err> {
+        self.value.check_ref()
+    }
+}
+
+impl<T: Archive, P> Archive for Checked<T, P> {
+    type Archived = ArchivedChecked<T::Archived, P>;
+    type Resolver = T::Resolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedChecked { value, predicate: _ } = out);
+        self.0.resolve(resolver, value);
+    }
+}
+
+impl<T, P, S> Serialize<S> for Checked<T, P>
+where
+    T: Serialize<S>,
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<T, P, D> Deserialize<Checked<T, P>, D> for ArchivedChecked<T::Archived, P>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    P: Predicate<T>,
+    P::Err: fmt::Display,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Checked<T, P>, D::Error> {
+        let value = self.value.deserialize(deserializer)?;
+        Checked::refine(value).map_err(|error| {
+            D::Error::new(InvalidArchive(
+                Invalid(CheckedTypeName::<T, P>::new(), error).to_string(),
+            ))
+        })
+    }
+}
+
+/// Access an archived value in a byte slice, and check it.
+///
+/// This is like [`rkyv::access`], but the value is also checked using its [`CheckRef`]
+/// implementation, and a checked view of it is returned.
+///
+/// # Errors
+///
+/// This will return an error if the bytes aren't a valid archive, or if the archived value fails
+/// its check.
+pub fn access<A, E>(bytes: &[u8]) -> Result<Checked<&A>, E>
+where
+    A: Portable + CheckRef + for<'a> CheckBytes<HighValidator<'a, E>>,
+    A::Err: fmt::Display,
+    E: Source,
+{
+    let value = rkyv::access::<A, E>(bytes)?;
+    Checked::try_from(value).map_err(|error| {
+        E::new(InvalidArchive(
+            Invalid(ShortTypeName(type_name::<A>()), error).to_string(),
+        ))
+    })
+}
+
+/// The error for an archived value that failed its check.
+#[derive(Debug)]
+struct InvalidArchive(String);
+
+impl fmt::Display for InvalidArchive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl core::error::Error for InvalidArchive {}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+
+    use crate::{checks::MaxLen, CheckRef, Checked};
+
+    #[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)]
+    struct Record {
+        name: Checked<String, MaxLen<4>>,
+        tags: Vec<Checked<String, MaxLen<4>>>,
+    }
+
+    impl CheckRef for ArchivedRecord {
+        type Err = &'static str;
+
+        fn check_ref(&self) -> Result<(), Self::Err> {
+            if self
+                .tags
+                .iter()
+                .any(|tag| tag.as_str() == self.name.as_str())
+            {
+                Err("tags must not repeat the name")
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let record = Record {
+            name: Checked::refine("abc".into()).unwrap(),
+            tags: vec![Checked::refine("x".into()).unwrap()],
+        };
+        let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+        let record = rkyv::from_bytes::<Record, Error>(&bytes).unwrap();
+        assert_eq!((record.name.as_str(), record.tags.len()), ("abc", 1));
+
+        let archived = super::access::<ArchivedRecord, Error>(&bytes).unwrap();
+        assert_eq!(archived.name.as_str(), "abc");
+    }
+
+    #[test]
+    fn invalid() {
+        #[derive(rkyv::Archive, rkyv::Serialize)]
+        struct Unchecked {
+            name: String,
+            tags: Vec<String>,
+        }
+
+        let bytes = rkyv::to_bytes::<Error>(&Unchecked {
+            name: "abc".into(),
+            tags: vec!["abcde".into()],
+        })
+        .unwrap();
+        let error = rkyv::from_bytes::<Record, Error>(&bytes).err().unwrap();
+        assert!(error
+            .to_string()
+            .starts_with("invalid value for Checked<String, MaxLen<4>>: "));
+
+        let bytes = rkyv::to_bytes::<Error>(&Unchecked {
+            name: "abc".into(),
+            tags: vec!["abc".into()],
+        })
+        .unwrap();
+        assert!(rkyv::from_bytes::<Record, Error>(&bytes).is_ok());
+        let error = super::access::<ArchivedRecord, Error>(&bytes)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "invalid value for ArchivedRecord: tags must not repeat the name"
+        );
+    }
+}
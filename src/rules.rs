@@ -0,0 +1,401 @@
+//! Declarative checks, loaded from configuration.
+//!
+//! This module is enabled by the `rules` feature. A [`Rules`] value maps field names to [`Rule`]s,
+//! and can be deserialized from any serde-compatible configuration format. Since `Rules`
+//! implements [`DynCheck`], it can be used with [`Checked::try_from_dyn`](crate::Checked::try_from_dyn)
+//! or registered in a [`CheckRegistry`](crate::dynamic::CheckRegistry), allowing limits to be
+//! tuned without recompiling.
+//!
+//! ```
+//! use check_mate::{rules::Rules, Checked};
+//!
+//! #[derive(serde::Serialize)]
+//! struct User {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! let rules: Rules = serde_json::from_str(r#"{
+//!     "name": { "required": true, "min_length": 1, "max_length": 32, "pattern": "^[a-z]+$" },
+//!     "age": { "min": 18 }
+//! }"#).unwrap();
+//!
+//! let user = User { name: "ferris".into(), age: 21 };
+//! assert!(Checked::try_from_dyn(user, &rules).is_ok());
+//!
+//! let user = User { name: "ferris".into(), age: 3 };
+//! assert_eq!(
+//!     Checked::try_from_dyn(user, &rules).err().map(|error| error.to_string()),
+//!     Some("age: must be at least 18, but was 3".into()),
+//! );
+//! ```
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use core::fmt;
+
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{DynCheck, ErrorCode};
+
+/// A set of rules, keyed by field name.
+///
+/// When checking a value, the value must be an object (or serialize as one), and each field is
+/// checked against its rule.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Rules {
+    fields: BTreeMap<String, Rule>,
+}
+
+impl Rules {
+    /// Construct an empty set of rules.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a rule for the given field.
+    ///
+    /// If there was already a rule for the field it will be replaced.
+    #[must_use]
+    pub fn field(mut self, name: impl Into<String>, rule: Rule) -> Self {
+        self.fields.insert(name.into(), rule);
+        self
+    }
+
+    /// Check a JSON value against the rules.
+    ///
+    /// This is equivalent to [`check_dyn`](DynCheck::check_dyn), but avoids converting the value.
+    ///
+    /// # Errors
+    ///
+    /// This will return an error for the first field that fails its rule, or if `value` is not an
+    /// object.
+    pub fn check_value(&self, value: &Value) -> Result<(), RuleError> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| RuleError::new(RuleErrorKind::WrongType("an object")))?;
+
+        for (name, rule) in &self.fields {
+            match object.get(name) {
+                None | Some(Value::Null) if rule.required => {
+                    return Err(RuleError::new(RuleErrorKind::Missing).at(name));
+                }
+                None | Some(Value::Null) => {}
+                Some(value) => rule.check_dyn(value).map_err(|error| error.at(name))?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: serde::Serialize + ?Sized> DynCheck<T> for Rules {
+    type Err = RuleError;
+
+    fn check_dyn(&self, value: &T) -> Result<(), Self::Err> {
+        let value = serde_json::to_value(value)
+            .map_err(|error| RuleError::new(RuleErrorKind::Serialize(error.to_string())))?;
+        self.check_value(&value)
+    }
+}
+
+/// A declarative rule for a single value.
+///
+/// Every constraint is optional, and constraints that are set must all hold. `min` and `max` apply
+/// to numbers, `min_length` and `max_length` apply to strings (counting `char`s) and arrays, and
+/// `pattern` applies to strings.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rule {
+    /// Whether the field must be present and non-null (only used by [`Rules`]).
+    pub required: bool,
+
+    /// The minimum allowed value (inclusive).
+    pub min: Option<f64>,
+
+    /// The maximum allowed value (inclusive).
+    pub max: Option<f64>,
+
+    /// The minimum allowed length (inclusive).
+    pub min_length: Option<usize>,
+
+    /// The maximum allowed length (inclusive).
+    pub max_length: Option<usize>,
+
+    /// A pattern the value must match.
+    #[serde(deserialize_with = "deserialize_pattern")]
+    pub pattern: Option<Regex>,
+}
+
+impl DynCheck<Value> for Rule {
+    type Err = RuleError;
+
+    fn check_dyn(&self, value: &Value) -> Result<(), Self::Err> {
+        if self.min.is_some() || self.max.is_some() {
+            let number = value
+                .as_f64()
+                .ok_or_else(|| RuleError::new(RuleErrorKind::WrongType("a number")))?;
+            if let Some(min) = self.min.filter(|min| number < *min) {
+                return Err(RuleError::new(RuleErrorKind::TooSmall {
+                    min,
+                    actual: number,
+                }));
+            }
+            if let Some(max) = self.max.filter(|max| number > *max) {
+                return Err(RuleError::new(RuleErrorKind::TooLarge {
+                    max,
+                    actual: number,
+                }));
+            }
+        }
+
+        if self.min_length.is_some() || self.max_length.is_some() {
+            let length = match value {
+                Value::String(string) => string.chars().count(),
+                Value::Array(array) => array.len(),
+                _ => {
+                    return Err(RuleError::new(RuleErrorKind::WrongType(
+                        "a string or an array",
+                    )))
+                }
+            };
+            if let Some(min) = self.min_length.filter(|min| length < *min) {
+                return Err(RuleError::new(RuleErrorKind::TooShort {
+                    min,
+                    actual: length,
+                }));
+            }
+            if let Some(max) = self.max_length.filter(|max| length > *max) {
+                return Err(RuleError::new(RuleErrorKind::TooLong {
+                    max,
+                    actual: length,
+                }));
+            }
+        }
+
+        if let Some(pattern) = &self.pattern {
+            let string = value
+                .as_str()
+                .ok_or_else(|| RuleError::new(RuleErrorKind::WrongType("a string")))?;
+            if !pattern.is_match(string) {
+                return Err(RuleError::new(RuleErrorKind::Mismatch {
+                    pattern: pattern.as_str().into(),
+                }));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn deserialize_pattern<'de, D>(deserializer: D) -> Result<Option<Regex>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    Option::<String>::deserialize(deserializer)?
+        .map(|pattern| Regex::new(&pattern).map_err(D::Error::custom))
+        .transpose()
+}
+
+/// The error returned when a value fails a [`Rule`] or [`Rules`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleError {
+    field: Option<String>,
+    kind: RuleErrorKind,
+}
+
+impl RuleError {
+    fn new(kind: RuleErrorKind) -> Self {
+        Self { field: None, kind }
+    }
+
+    fn at(mut self, field: &str) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+
+    /// The field that failed its rule, if the error came from [`Rules`].
+    #[must_use]
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// The reason the rule failed.
+    #[must_use]
+    pub fn kind(&self) -> &RuleErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(field) = &self.field {
+            write!(f, "{field}: ")?;
+        }
+        self.kind.fmt(f)
+    }
+}
+
+impl core::error::Error for RuleError {}
+
+impl ErrorCode for RuleError {
+    fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+}
+
+/// The reasons a [`Rule`] can fail.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum RuleErrorKind {
+    /// A required field was missing or null.
+    Missing,
+
+    /// The value was not of a type the rule applies to.
+    WrongType(&'static str),
+
+    /// The value was less than `min`.
+    TooSmall {
+        /// The minimum allowed value.
+        min: f64,
+        /// The actual value.
+        actual: f64,
+    },
+
+    /// The value was greater than `max`.
+    TooLarge {
+        /// The maximum allowed value.
+        max: f64,
+        /// The actual value.
+        actual: f64,
+    },
+
+    /// The value was shorter than `min_length`.
+    TooShort {
+        /// The minimum allowed length.
+        min: usize,
+        /// The actual length.
+        actual: usize,
+    },
+
+    /// The value was longer than `max_length`.
+    TooLong {
+        /// The maximum allowed length.
+        max: usize,
+        /// The actual length.
+        actual: usize,
+    },
+
+    /// The value did not match `pattern`.
+    Mismatch {
+        /// The pattern that wasn't matched.
+        pattern: String,
+    },
+
+    /// The value could not be converted to JSON.
+    Serialize(String),
+}
+
+impl fmt::Display for RuleErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => write!(f, "is required"),
+            Self::WrongType(expected) => write!(f, "must be {expected}"),
+            Self::TooSmall { min, actual } => {
+                write!(f, "must be at least {min}, but was {actual}")
+            }
+            Self::TooLarge { max, actual } => write!(f, "must be at most {max}, but was {actual}"),
+            Self::TooShort { min, actual } => {
+                write!(
+                    f,
+                    "must have length at least {min}, but had length {actual}"
+                )
+            }
+            Self::TooLong { max, actual } => {
+                write!(f, "must have length at most {max}, but had length {actual}")
+            }
+            Self::Mismatch { pattern } => write!(f, "must match `{pattern}`"),
+            Self::Serialize(error) => write!(f, "could not be converted to JSON: {error}"),
+        }
+    }
+}
+
+impl ErrorCode for RuleErrorKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Missing => "missing",
+            Self::WrongType(_) => "wrong_type",
+            Self::TooSmall { .. } => "too_small",
+            Self::TooLarge { .. } => "too_large",
+            Self::TooShort { .. } => "too_short",
+            Self::TooLong { .. } => "too_long",
+            Self::Mismatch { .. } => "mismatch",
+            Self::Serialize(_) => "serialize",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{RuleErrorKind, Rules};
+    use crate::ErrorCode;
+
+    fn rules() -> Rules {
+        serde_json::from_value(json!({
+            "name": { "required": true, "max_length": 3, "pattern": "^[a-z]*$" },
+            "score": { "min": 0, "max": 1.5 },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn check_value() {
+        let rules = rules();
+
+        assert_eq!(rules.check_value(&json!({ "name": "abc" })), Ok(()));
+        assert_eq!(
+            rules.check_value(&json!({ "name": "abc", "score": 1 })),
+            Ok(())
+        );
+
+        let error = rules.check_value(&json!({ "score": 1 })).unwrap_err();
+        assert_eq!(error.field(), Some("name"));
+        assert_eq!(error.kind(), &RuleErrorKind::Missing);
+        assert_eq!(error.code(), "missing");
+
+        let error = rules.check_value(&json!({ "name": "abcd" })).unwrap_err();
+        assert_eq!(error.kind(), &RuleErrorKind::TooLong { max: 3, actual: 4 });
+
+        let error = rules.check_value(&json!({ "name": "ABC" })).unwrap_err();
+        assert_eq!(error.to_string(), "name: must match `^[a-z]*$`");
+
+        let error = rules
+            .check_value(&json!({ "name": "abc", "score": 2 }))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "score: must be at most 1.5, but was 2");
+
+        let error = rules
+            .check_value(&json!({ "name": "abc", "score": "2" }))
+            .unwrap_err();
+        assert_eq!(error.kind(), &RuleErrorKind::WrongType("a number"));
+
+        let error = rules.check_value(&json!([])).unwrap_err();
+        assert_eq!(error.field(), None);
+    }
+
+    #[test]
+    fn invalid_spec() {
+        assert!(serde_json::from_value::<Rules>(json!({ "name": { "pattern": "(" } })).is_err());
+        assert!(serde_json::from_value::<Rules>(json!({ "name": { "maxlength": 1 } })).is_err());
+    }
+}
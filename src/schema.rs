@@ -0,0 +1,45 @@
+//! Helpers for adding predicates' constraints to JSON Schemas.
+
+use schemars::Schema;
+use serde_json::{Number, Value};
+
+use crate::Inherent;
+
+/// Whether `P` is the [`Inherent`] predicate, which doesn't change the schema.
+pub(crate) fn is_inherent<P: 'static>() -> bool {
+    core::any::TypeId::of::<P>() == core::any::TypeId::of::<Inherent>()
+}
+
+/// Set a numeric keyword, e.g. `minimum`, if the number can be represented in JSON.
+pub(crate) fn number(schema: &mut Schema, keyword: &str, number: Option<Number>) {
+    if let Some(number) = number {
+        schema.insert(keyword.into(), Value::Number(number));
+    }
+}
+
+/// Set `minimum` and `maximum` for percentages.
+pub(crate) fn percent(schema: &mut Schema) {
+    number(schema, "minimum", Some(0.into()));
+    number(schema, "maximum", Some(100.into()));
+}
+
+/// Set `multipleOf`, unless `n` is `0` (JSON Schema requires a positive divisor, and only `0` is a
+/// multiple of `0`, which can't be expressed this way).
+pub(crate) fn multiple_of(schema: &mut Schema, n: u64) {
+    if n != 0 {
+        number(schema, "multipleOf", Some(n.into()));
+    }
+}
+
+/// Set the length keywords appropriate for the schema's type, i.e. `minItems` and `maxItems` for
+/// arrays, `minProperties` and `maxProperties` for objects, and `minLength` and `maxLength`
+/// otherwise.
+pub(crate) fn length(schema: &mut Schema, min: Option<usize>, max: Option<usize>) {
+    let (min_keyword, max_keyword) = match schema.get("type").and_then(Value::as_str) {
+        Some("array") => ("minItems", "maxItems"),
+        Some("object") => ("minProperties", "maxProperties"),
+        _ => ("minLength", "maxLength"),
+    };
+    number(schema, min_keyword, min.map(Number::from));
+    number(schema, max_keyword, max.map(Number::from));
+}
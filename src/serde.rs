@@ -0,0 +1,931 @@
+//! Utilities for (de)serializing checked values.
+//!
+//! This module is enabled by the `serde` feature. `Checked<T, P>` implements `Serialize` and
+//! `Deserialize` itself, so this is mostly useful for fields that aren't wrapped in `Checked`, but
+//! should still be checked when they're deserialized. The module can be used with
+//! `#[serde(with = "check_mate::serde")]`, and the [`option`], [`vec`], and [`keys`] modules can be
+//! used in the same way for optional and repeated fields, and for map keys:
+//!
+//! ```
+//! use check_mate::Check;
+//!
+//! #[derive(Debug, serde::Deserialize, serde::Serialize)]
+//! struct Port(u16);
+//!
+//! impl Check for Port {
+//!     type Ok = Self;
+//!     type Err = &'static str;
+//!
+//!     fn check(self) -> Result<Self, Self::Err> {
+//!         if self.0 == 0 { Err("must not be zero") } else { Ok(self) }
+//!     }
+//! }
+//!
+//! #[derive(Debug, serde::Deserialize, serde::Serialize)]
+//! struct Config {
+//!     #[serde(with = "check_mate::serde")]
+//!     port: Port,
+//!     #[serde(with = "check_mate::serde::option", default)]
+//!     admin_port: Option<Port>,
+//!     #[serde(with = "check_mate::serde::vec")]
+//!     extra_ports: Vec<Port>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{ "port": 80, "extra_ports": [8080] }"#).unwrap();
+//! assert_eq!(config.port.0, 80);
+//! assert!(config.admin_port.is_none());
+//!
+//! assert!(serde_json::from_str::<Config>(r#"{ "port": 0, "extra_ports": [] }"#).is_err());
+//! assert!(serde_json::from_str::<Config>(r#"{ "port": 80, "extra_ports": [0] }"#).is_err());
+//! ```
+//!
+//! Borrowed values can be checked without copying them out of the input. `Checked<&str, P>` and
+//! `Checked<&[u8], P>` borrow from the deserializer's input like `&str` and `&[u8]` do (using
+//! `#[serde(borrow)]` in derived implementations), and types that borrow from the input can
+//! implement [`Check`] in the usual way. For types that implement [`CheckRef`](crate::CheckRef),
+//! `Checked<&T>` is a checked view of a value that's borrowed from elsewhere.
+//!
+//! ```
+//! use check_mate::{checks::{Ascii, NonBlank}, Checked};
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Request<'a> {
+//!     #[serde(borrow)]
+//!     name: Checked<&'a str, NonBlank>,
+//!     #[serde(borrow)]
+//!     token: Checked<&'a [u8], Ascii>,
+//! }
+//!
+//! let input = r#"{ "name": "widget", "token": "abc123" }"#;
+//! let request: Request = serde_json::from_str(input).unwrap();
+//! assert_eq!(*request.name, "widget");
+//! assert_eq!(*request.token, b"abc123");
+//!
+//! assert!(serde_json::from_str::<Request>(r#"{ "name": " ", "token": "abc123" }"#).is_err());
+//! ```
+//!
+//! Data that was serialized from already-checked values can be re-loaded without checking it
+//! again, using [`Checked::deserialize_trusted`] or [`Trusted`].
+//!
+//! Checks that need context at runtime (e.g. a trust store or configuration) can be passed into
+//! deserialization as a [`DynCheck`] using [`CheckedSeed`], rather than stored in globals.
+//!
+//! To keep going when values fail their checks (e.g. to quarantine bad records in a batch import),
+//! deserialize [`CheckedOrRaw`] instead, which keeps invalid values along with their errors.
+//!
+//! Large sequences can be checked element-by-element as they're deserialized, without collecting
+//! them first, using [`checked_seq`] or [`CheckedSeq`].
+//!
+//! With the `serde_with` feature enabled, [`CheckedAs`] can be used with `#[serde_as]` to check
+//! values using a separate checker type.
+
+use core::{any::type_name, fmt, marker::PhantomData};
+
+use serde::{
+    de::{DeserializeSeed, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+#[cfg(feature = "serde_with")]
+use serde_with::{DeserializeAs, SerializeAs};
+
+use crate::{
+    dynamic::DynTag,
+    error::decode::{CheckedTypeName, Invalid, ShortTypeName},
+    Check, Checked, DynCheck, Predicate,
+};
+
+/// Values that are checked when they're deserialized.
+///
+/// This is implemented for `Checked<T, P>`, and for any `T: Check<Ok = T>`, which is checked after
+/// being deserialized. It's used by the functions in this module.
+pub trait DeserializeChecked<'de>: Sized {
+    /// Deserialize and check a value.
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if deserialization fails, or if the value fails its check.
+    fn deserialize_checked<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+impl<'de, T, P> DeserializeChecked<'de> for Checked<T, P>
+where
+    T: Deserialize<'de>,
+    P: Predicate<T>,
+    P::Err: fmt::Display,
+{
+    fn deserialize_checked<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<'de, T> DeserializeChecked<'de> for T
+where
+    T: Deserialize<'de> + Check<Ok = T>,
+    T::Err: fmt::Display,
+{
+    fn deserialize_checked<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer)?
+            .check()
+            .map_err(|error| invalid(ShortTypeName(type_name::<T>()), error))
+    }
+}
+
+/// The error for a deserialized value of the given type that failed its check.
+///
+/// Errors read like `invalid value for Checked<Email>: <error>`. Deserializers may add their own
+/// context, such as the position of the value.
+pub(crate) fn invalid<E: serde::de::Error>(ty: impl fmt::Display, error: impl fmt::Display) -> E {
+    E::custom(Invalid(ty, error))
+}
+
+/// Serialize a value, for use with `#[serde(with = "check_mate::serde")]`.
+///
+/// Values are serialized as-is.
+///
+/// # Errors
+///
+/// This will return the serializer's error if serialization fails.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + ?Sized,
+    S: Serializer,
+{
+    value.serialize(serializer)
+}
+
+/// Deserialize and check a value, for use with `#[serde(with = "check_mate::serde")]`.
+///
+/// # Errors
+///
+/// This will return an error if deserialization fails, or if the value fails its check.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeChecked<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize_checked(deserializer)
+}
+
+/// A value deserialized with [`DeserializeChecked`], for deserializing containers of them.
+struct CheckedValue<T>(T);
+
+impl<'de, T: DeserializeChecked<'de>> Deserialize<'de> for CheckedValue<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_checked(deserializer).map(CheckedValue)
+    }
+}
+
+impl<T, P> Checked<T, P> {
+    /// Deserialize a value **without checking it**.
+    ///
+    /// This is for re-loading data that was serialized from values that had already been checked
+    /// (e.g. our own snapshots), where checking again would be expensive. It must not be used for
+    /// untrusted input, since nothing ensures the value satisfies `P`. It can be used for fields
+    /// with `#[serde(deserialize_with = "Checked::deserialize_trusted")]`, and [`Trusted`] can be
+    /// used to deserialize whole values.
+    ///
+    /// ```
+    /// use check_mate::{checks::NonZero, Checked};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Snapshot {
+    ///     #[serde(deserialize_with = "Checked::deserialize_trusted")]
+    ///     count: Checked<u32, NonZero>,
+    /// }
+    ///
+    /// let snapshot: Snapshot = serde_json::from_str(r#"{ "count": 3 }"#).unwrap();
+    /// assert_eq!(*snapshot.count, 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if deserialization fails.
+    pub fn deserialize_trusted<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Checked::new_unchecked)
+    }
+}
+
+/// A checked value that's deserialized **without checking it**.
+///
+/// This is like [`Checked::deserialize_trusted`], but for deserializing whole values rather than
+/// fields. The same caveats apply: it must only be used for data that was serialized from values
+/// that had already been checked.
+///
+/// ```
+/// use check_mate::{checks::NonZero, serde::Trusted, Checked};
+///
+/// let Trusted(count): Trusted<u32, NonZero> = serde_json::from_str("3").unwrap();
+/// assert_eq!(*count, 3);
+/// ```
+pub struct Trusted<T, P = crate::Inherent>(pub Checked<T, P>);
+
+impl<'de, T: Deserialize<'de>, P> Deserialize<'de> for Trusted<T, P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Checked::deserialize_trusted(deserializer).map(Trusted)
+    }
+}
+
+impl<T: Serialize, P> Serialize for Trusted<T, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// A [`DeserializeSeed`] that checks the deserialized value with a [`DynCheck`].
+///
+/// This allows checks that depend on runtime context to be performed during deserialization, like
+/// [`Checked::try_from_dyn`]. Likewise, values are tagged with [`DynTag`] rather than
+/// [`Inherent`](crate::Inherent), since `T`'s own [`Check`] (if any) isn't run.
+///
+/// ```
+/// use check_mate::{dynamic::DynTag, serde::CheckedSeed, Checked, DynCheck};
+/// use serde::de::DeserializeSeed;
+///
+/// struct AllowedHosts(Vec<String>);
+///
+/// impl DynCheck<String> for AllowedHosts {
+///     type Err = &'static str;
+///
+///     fn check_dyn(&self, host: &String) -> Result<(), Self::Err> {
+///         if self.0.contains(host) { Ok(()) } else { Err("host is not allowed") }
+///     }
+/// }
+///
+/// let allowed = AllowedHosts(vec!["example.com".into()]);
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""example.com""#);
+/// let host: Checked<String, DynTag> =
+///     CheckedSeed::new(&allowed).deserialize(&mut deserializer).unwrap();
+/// assert_eq!(*host, "example.com");
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(r#""example.org""#);
+/// assert_eq!(
+///     CheckedSeed::new(&allowed).deserialize(&mut deserializer).unwrap_err().to_string(),
+///     "invalid value for Checked<String, DynTag>: host is not allowed",
+/// );
+/// ```
+pub struct CheckedSeed<'a, T, C: ?Sized> {
+    check: &'a C,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<'a, T, C: ?Sized> CheckedSeed<'a, T, C> {
+    /// Create a seed that checks values with `check`.
+    pub fn new(check: &'a C) -> Self {
+        Self {
+            check,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<T, C: ?Sized> Clone for CheckedSeed<'_, T, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, C: ?Sized> Copy for CheckedSeed<'_, T, C> {}
+
+impl<'de, T, C> DeserializeSeed<'de> for CheckedSeed<'_, T, C>
+where
+    T: Deserialize<'de>,
+    C: DynCheck<T> + ?Sized,
+    C::Err: fmt::Display,
+{
+    type Value = Checked<T, DynTag>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        Checked::try_from_dyn(value, self.check)
+            .map_err(|error| invalid(CheckedTypeName::<T, DynTag>::new(), error))
+    }
+}
+
+/// Deserialize a sequence, checking each element and passing it to `f` as soon as it's checked.
+///
+/// Unlike [`vec`], elements aren't collected, so large arrays (e.g. multi-gigabyte imports read
+/// with `serde_json::Deserializer::from_reader`) can be processed element-by-element, and checking
+/// stops at the first invalid element. Use [`CheckedSeq`] to do the same for a sequence nested
+/// inside a larger document.
+///
+/// ```
+/// use check_mate::{checks::NonZero, serde::checked_seq, Checked};
+///
+/// let mut total = 0;
+/// let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3]");
+/// checked_seq(&mut deserializer, |n: Checked<u32, NonZero>| total += *n).unwrap();
+/// assert_eq!(total, 6);
+///
+/// let mut seen = Vec::new();
+/// let mut deserializer = serde_json::Deserializer::from_str("[1, 0, 3]");
+/// assert!(checked_seq(&mut deserializer, |n: Checked<u32, NonZero>| seen.push(*n)).is_err());
+/// assert_eq!(seen, [1]);
+/// ```
+///
+/// # Errors
+///
+/// This will return an error if deserialization fails, or if any element fails its check.
+pub fn checked_seq<'de, T, D, F>(deserializer: D, f: F) -> Result<(), D::Error>
+where
+    T: DeserializeChecked<'de>,
+    D: Deserializer<'de>,
+    F: FnMut(T),
+{
+    CheckedSeq::new(f).deserialize(deserializer)
+}
+
+/// A [`DeserializeSeed`] that checks the elements of a sequence and passes them to a closure.
+///
+/// This is what [`checked_seq`] uses, and can be used for sequences nested inside larger
+/// documents, e.g. from a `Visitor` for the document.
+pub struct CheckedSeq<T, F> {
+    f: F,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<T, F: FnMut(T)> CheckedSeq<T, F> {
+    /// Create a seed that passes checked elements to `f`.
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<T, F> fmt::Debug for CheckedSeq<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CheckedSeq").finish_non_exhaustive()
+    }
+}
+
+impl<'de, T, F> DeserializeSeed<'de> for CheckedSeq<T, F>
+where
+    T: DeserializeChecked<'de>,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T, F> Visitor<'de> for CheckedSeq<T, F>
+where
+    T: DeserializeChecked<'de>,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut access: A) -> Result<Self::Value, A::Error> {
+        while let Some(CheckedValue(value)) = access.next_element()? {
+            (self.f)(value);
+        }
+        Ok(())
+    }
+}
+
+/// A value that either passed its check, or failed it, in which case the unchecked value is kept
+/// along with the error.
+///
+/// Deserializing a `CheckedOrRaw<T>` only fails if a `T` can't be deserialized, so one invalid
+/// value doesn't abort the whole document. The value has to be cloned to keep it around while it's
+/// checked, so `T` must implement `Clone`.
+///
+/// ```
+/// use check_mate::{serde::CheckedOrRaw, Check};
+///
+/// #[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+/// struct Record {
+///     id: u32,
+///     email: String,
+/// }
+///
+/// impl Check for Record {
+///     type Ok = Self;
+///     type Err = &'static str;
+///
+///     fn check(self) -> Result<Self, Self::Err> {
+///         if self.email.contains('@') { Ok(self) } else { Err("invalid email") }
+///     }
+/// }
+///
+/// let records: Vec<CheckedOrRaw<Record>> = serde_json::from_str(r#"[
+///     { "id": 1, "email": "ferris@example.com" },
+///     { "id": 2, "email": "ferris" }
+/// ]"#).unwrap();
+///
+/// let (valid, quarantined): (Vec<_>, Vec<_>) =
+///     records.into_iter().map(CheckedOrRaw::into_result).partition(Result::is_ok);
+/// assert_eq!(valid.len(), 1);
+/// assert_eq!(
+///     quarantined[0].as_ref().unwrap_err(),
+///     &(Record { id: 2, email: "ferris".into() }, "invalid email"),
+/// );
+/// ```
+#[derive(Debug)]
+pub enum CheckedOrRaw<T: Check> {
+    /// The value passed its check.
+    Checked(Checked<T>),
+
+    /// The value failed its check.
+    Invalid {
+        /// The unchecked value.
+        raw: T,
+
+        /// The error from the check.
+        error: T::Err,
+    },
+}
+
+impl<T: Check> CheckedOrRaw<T> {
+    /// Convert into a `Result`, with the unchecked value and the error on failure.
+    ///
+    /// # Errors
+    ///
+    /// This will return the unchecked value and the error if the value failed its check.
+    pub fn into_result(self) -> Result<Checked<T>, (T, T::Err)> {
+        match self {
+            Self::Checked(checked) => Ok(checked),
+            Self::Invalid { raw, error } => Err((raw, error)),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for CheckedOrRaw<T>
+where
+    T: Deserialize<'de> + Clone + Check<Ok = T>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = T::deserialize(deserializer)?;
+        Ok(match Checked::try_from(raw.clone()) {
+            Ok(checked) => Self::Checked(checked),
+            Err(error) => Self::Invalid { raw, error },
+        })
+    }
+}
+
+/// Values are serialized as-is, whether or not they passed their check.
+impl<T: Check + Serialize> Serialize for CheckedOrRaw<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Checked(checked) => checked.serialize(serializer),
+            Self::Invalid { raw, .. } => raw.serialize(serializer),
+        }
+    }
+}
+
+/// (De)serialization of optional values, for use with
+/// `#[serde(with = "check_mate::serde::option")]`.
+///
+/// `None` is always valid. Combine with `#[serde(default)]` to allow the field to be missing.
+pub mod option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{CheckedValue, DeserializeChecked};
+
+    /// Serialize an optional value.
+    ///
+    /// # Errors
+    ///
+    /// This will return the serializer's error if serialization fails.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserialize an optional value, checking it if it's present.
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if deserialization fails, or if the value fails its check.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: DeserializeChecked<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<CheckedValue<T>>::deserialize(deserializer)
+            .map(|option| option.map(|value| value.0))
+    }
+}
+
+/// (De)serialization of sequences of values, for use with
+/// `#[serde(with = "check_mate::serde::vec")]`.
+///
+/// This requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub mod vec {
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{CheckedValue, DeserializeChecked};
+
+    /// Serialize a sequence of values.
+    ///
+    /// # Errors
+    ///
+    /// This will return the serializer's error if serialization fails.
+    pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        values.serialize(serializer)
+    }
+
+    /// Deserialize a sequence of values, checking each of them.
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if deserialization fails, or if any value fails its check.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: DeserializeChecked<'de>,
+        D: Deserializer<'de>,
+    {
+        Vec::<CheckedValue<T>>::deserialize(deserializer)
+            .map(|values| values.into_iter().map(|value| value.0).collect())
+    }
+}
+
+/// (De)serialization of maps with checked keys, for use with
+/// `#[serde(with = "check_mate::serde::keys")]`.
+///
+/// This works with any map that implements `Default` and `Extend`, such as `HashMap` and
+/// `BTreeMap`. Keys are deserialized from strings, and any [`DeserializeChecked`] type can be used
+/// as the key, including `Checked<String, P>` with any predicate. Values are deserialized as-is.
+/// Errors include the key that failed its check, which isn't otherwise reported by most formats.
+///
+/// This requires the `alloc` feature.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use check_mate::{checks::NonBlank, Checked};
+///
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Labels {
+///     #[serde(with = "check_mate::serde::keys")]
+///     labels: BTreeMap<Checked<String, NonBlank>, String>,
+/// }
+///
+/// let labels: Labels = serde_json::from_str(r#"{ "labels": { "env": "prod" } }"#).unwrap();
+/// assert_eq!(labels.labels.keys().next().unwrap().as_str(), "env");
+///
+/// assert_eq!(
+///     serde_json::from_str::<Labels>(r#"{ "labels": { " ": "prod" } }"#)
+///         .err()
+///         .unwrap()
+///         .to_string(),
+///     "key \" \": invalid value for Checked<String, NonBlank>: must not be blank at line 1 column 17",
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub mod keys {
+    use alloc::{borrow::Cow, string::String};
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{
+        de::{
+            value::{BorrowedStrDeserializer, Error as ValueError, StrDeserializer},
+            Error, MapAccess, Visitor,
+        },
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::DeserializeChecked;
+
+    /// Serialize a map.
+    ///
+    /// # Errors
+    ///
+    /// This will return the serializer's error if serialization fails.
+    pub fn serialize<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+        K: Serialize + 'a,
+        V: Serialize + 'a,
+        S: Serializer,
+    {
+        serializer.collect_map(map)
+    }
+
+    /// Deserialize a map, checking each of its keys.
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if deserialization fails, or if any key fails its check.
+    pub fn deserialize<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: Default + Extend<(K, V)>,
+        K: DeserializeChecked<'de>,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MapVisitor(PhantomData, PhantomData))
+    }
+
+    struct MapVisitor<M, K, V>(PhantomData<fn() -> M>, PhantomData<fn() -> (K, V)>);
+
+    impl<'de, M, K, V> Visitor<'de> for MapVisitor<M, K, V>
+    where
+        M: Default + Extend<(K, V)>,
+        K: DeserializeChecked<'de>,
+        V: Deserialize<'de>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a map")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+            let mut map = M::default();
+            while let Some(Key(key)) = access.next_key()? {
+                let checked = match &key {
+                    Cow::Borrowed(key) => K::deserialize_checked(BorrowedStrDeserializer::new(key)),
+                    Cow::Owned(key) => K::deserialize_checked(StrDeserializer::new(key)),
+                };
+                let checked = checked.map_err(|error: ValueError| {
+                    A::Error::custom(format_args!("key {key:?}: {error}"))
+                })?;
+                map.extend(Some((checked, access.next_value()?)));
+            }
+            Ok(map)
+        }
+    }
+
+    /// A map key, borrowed from the input if possible.
+    struct Key<'de>(Cow<'de, str>);
+
+    impl<'de> Deserialize<'de> for Key<'de> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(KeyVisitor)
+        }
+    }
+
+    struct KeyVisitor;
+
+    impl<'de> Visitor<'de> for KeyVisitor {
+        type Value = Key<'de>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string key")
+        }
+
+        fn visit_borrowed_str<E: Error>(self, value: &'de str) -> Result<Self::Value, E> {
+            Ok(Key(Cow::Borrowed(value)))
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+            Ok(Key(Cow::Owned(value.into())))
+        }
+
+        fn visit_string<E: Error>(self, value: String) -> Result<Self::Value, E> {
+            Ok(Key(Cow::Owned(value)))
+        }
+    }
+}
+
+/// A `serde_with` adapter for checking values with a separate checker type.
+///
+/// `Checked<T>` only implements `Deserialize` when `T: Check<Ok = T>`, since the checker type
+/// can't be inferred otherwise. `CheckedAs<U>` names the checker type, so that a `Checked<T>` can
+/// be deserialized by deserializing a `U: Check<Ok = T>` and checking it. Serialization just
+/// serializes the checked `T`.
+///
+/// This is enabled by the `serde_with` feature.
+///
+/// ```
+/// use check_mate::{serde::CheckedAs, Check, Checked};
+/// use serde_with::serde_as;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Celsius(f64);
+///
+/// impl Check for Celsius {
+///     type Ok = f64;
+///     type Err = &'static str;
+///
+///     fn check(self) -> Result<f64, Self::Err> {
+///         if self.0 >= -273.15 { Ok(self.0) } else { Err("below absolute zero") }
+///     }
+/// }
+///
+/// #[serde_as]
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Reading {
+///     #[serde_as(as = "CheckedAs<Celsius>")]
+///     temperature: Checked<f64>,
+/// }
+///
+/// let reading: Reading = serde_json::from_str(r#"{ "temperature": 21.5 }"#).unwrap();
+/// assert_eq!(*reading.temperature, 21.5);
+/// assert_eq!(serde_json::to_string(&reading).unwrap(), r#"{"temperature":21.5}"#);
+///
+/// assert!(serde_json::from_str::<Reading>(r#"{ "temperature": -300 }"#).is_err());
+/// ```
+#[cfg(feature = "serde_with")]
+pub struct CheckedAs<U>(PhantomData<fn() -> U>);
+
+#[cfg(feature = "serde_with")]
+impl<'de, T, U> DeserializeAs<'de, Checked<T>> for CheckedAs<U>
+where
+    U: Deserialize<'de> + Check<Ok = T>,
+    U::Err: fmt::Display,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Checked<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = U::deserialize(deserializer)?;
+        Checked::try_from(value)
+            .map_err(|error| invalid(CheckedTypeName::<T, crate::Inherent>::new(), error))
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<T: Serialize, U> SerializeAs<Checked<T>> for CheckedAs<U> {
+    fn serialize_as<S>(source: &Checked<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        source.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trusted;
+    use crate::{
+        checks::{MaxLen, NonBlank, NonZero},
+        CheckRef, Checked,
+    };
+
+    #[test]
+    fn path() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Users {
+            #[allow(dead_code)]
+            users: Vec<User>,
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        struct User {
+            #[allow(dead_code)]
+            id: Checked<u32, NonZero>,
+        }
+
+        // The path isn't tracked by the check itself, but composes with `serde_path_to_error`.
+        let json = r#"{ "users": [{ "id": 1 }, { "id": 0 }] }"#;
+        let error = serde_path_to_error::deserialize::<_, Users>(
+            &mut serde_json::Deserializer::from_str(json),
+        )
+        .unwrap_err();
+        assert_eq!(error.path().to_string(), "users[1].id");
+        assert!(error
+            .to_string()
+            .starts_with("users[1].id: invalid value for Checked<u32, NonZero>: "));
+    }
+
+    #[test]
+    fn trusted() {
+        assert!(serde_json::from_str::<Checked<u32, NonZero>>("0").is_err());
+
+        // Trusted values aren't checked, so invalid data gets through.
+        let Trusted(zero): Trusted<u32, NonZero> = serde_json::from_str("0").unwrap();
+        assert_eq!(*zero, 0);
+        assert_eq!(serde_json::to_string(&Trusted(zero)).unwrap(), "0");
+    }
+
+    #[test]
+    fn checked_seq() {
+        let mut seen = 0;
+        let input = "[1, 2, 3";
+        let mut deserializer = serde_json::Deserializer::from_reader(input.as_bytes());
+        let error = super::checked_seq(&mut deserializer, |_: Checked<u32, NonZero>| {
+            seen += 1;
+        })
+        .unwrap_err();
+
+        // Elements are checked and handled before the rest of the input is read.
+        assert_eq!(seen, 3);
+        assert!(error.is_eof());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn keys() {
+        use std::collections::HashMap;
+
+        #[derive(serde::Deserialize, serde::Serialize)]
+        struct Limits<'a> {
+            #[serde(with = "super::keys", borrow)]
+            limits: HashMap<Checked<&'a str, MaxLen<4>>, u32>,
+        }
+
+        let input = r#"{"limits":{"cpu":2}}"#;
+        let limits: Limits = serde_json::from_str(input).unwrap();
+        let key = limits.limits.keys().next().unwrap();
+        assert!(input.as_bytes().as_ptr_range().contains(&key.as_ptr()));
+        assert_eq!(serde_json::to_string(&limits).unwrap(), input);
+
+        let error = serde_json::from_str::<Limits>(r#"{"limits":{"memory":2}}"#)
+            .err()
+            .unwrap();
+        assert!(error
+            .to_string()
+            .starts_with("key \"memory\": invalid value for Checked<&str, MaxLen<4>>: "));
+    }
+
+    #[test]
+    fn borrowed() {
+        #[derive(serde::Deserialize)]
+        struct Record<'a> {
+            #[serde(borrow)]
+            name: Checked<&'a str, NonBlank>,
+            #[serde(borrow)]
+            data: Checked<&'a [u8], MaxLen<4>>,
+        }
+
+        impl CheckRef for Record<'_> {
+            type Err = &'static str;
+
+            fn check_ref(&self) -> Result<(), Self::Err> {
+                if self.data.starts_with(b"x") {
+                    Err("data must not start with x")
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        let input = r#"{ "name": "widget", "data": "abcd" }"#;
+        let record: Record = serde_json::from_str(input).unwrap();
+        let range = input.as_bytes().as_ptr_range();
+        assert!(range.contains(&record.name.as_ptr()));
+        assert!(range.contains(&record.data.as_ptr()));
+        assert!(Checked::<&Record>::try_from(&record).is_ok());
+
+        let error = serde_json::from_str::<Record>(r#"{ "name": "widget", "data": "abcde" }"#)
+            .err()
+            .unwrap();
+        assert!(error
+            .to_string()
+            .starts_with("invalid value for Checked<&[u8], MaxLen<4>>: "));
+
+        let record: Record =
+            serde_json::from_str(r#"{ "name": "widget", "data": "xyz" }"#).unwrap();
+        assert_eq!(
+            Checked::<&Record>::try_from(&record).err(),
+            Some("data must not start with x")
+        );
+    }
+}
@@ -0,0 +1,753 @@
+#![cfg(feature = "derive")]
+
+use check_mate::{
+    error::{BuildError, FieldError},
+    Check, CheckRef, Checked,
+};
+
+#[derive(Check, Debug)]
+struct Order {
+    #[check(non_empty, custom = "no_spaces")]
+    sku: String,
+    #[check(range(1..=10))]
+    quantity: u8,
+}
+
+fn no_spaces(sku: &str) -> Result<(), &'static str> {
+    if sku.contains(' ') {
+        Err("must not contain spaces")
+    } else {
+        Ok(())
+    }
+}
+
+fn order(sku: &str, quantity: u8) -> Order {
+    Order {
+        sku: sku.into(),
+        quantity,
+    }
+}
+
+fn message<T: Check>(value: T) -> Option<String>
+where
+    T::Err: ToString,
+{
+    value.check().err().map(|error| error.to_string())
+}
+
+#[test]
+fn fields() {
+    assert!(Checked::try_from(order("ABC-123", 1)).is_ok());
+    assert!(Checked::try_from(order("ABC-123", 10)).is_ok());
+    assert_eq!(message(order("", 1)), Some("sku: must not be empty".into()));
+    assert_eq!(
+        message(order("ABC 123", 1)),
+        Some("sku: must not contain spaces".into())
+    );
+    assert_eq!(
+        message(order("ABC-123", 0)),
+        Some("quantity: must be in the range 1..=10".into())
+    );
+}
+
+#[test]
+fn first_failure() {
+    let error = order("", 0).check().unwrap_err();
+    assert_eq!(error.field(), Some("sku"));
+    assert_eq!(error.error().to_string(), "must not be empty");
+}
+
+#[derive(Check)]
+struct Range(#[check(range(..100))] u32, #[check(range(0.0..1.0))] f64);
+
+#[test]
+fn tuple_struct() {
+    assert!(Range(99, 0.5).check().is_ok());
+    assert_eq!(
+        message(Range(100, 0.5)),
+        Some("0: must be in the range ..100".into())
+    );
+    assert_eq!(
+        message(Range(1, 1.0)),
+        Some("1: must be in the range 0.0..1.0".into())
+    );
+}
+
+#[derive(Check)]
+struct Raw {
+    #[check(non_empty)]
+    r#type: Vec<u8>,
+}
+
+#[test]
+fn raw_identifier() {
+    assert_eq!(
+        message(Raw { r#type: vec![] }),
+        Some("type: must not be empty".into())
+    );
+}
+
+#[derive(Check, Debug, PartialEq)]
+enum Shape {
+    Circle {
+        #[check(range(0.0..))]
+        radius: f64,
+    },
+    Polygon(
+        #[check(range(3..))] u32,
+        #[check(non_empty)] Vec<(f64, f64)>,
+    ),
+    Point,
+}
+
+#[test]
+fn enum_variants() {
+    assert!(Shape::Circle { radius: 1.0 }.check().is_ok());
+    assert!(Shape::Polygon(3, vec![(0.0, 0.0)]).check().is_ok());
+    assert!(Shape::Point.check().is_ok());
+
+    let circle = Shape::Circle { radius: -1.0 }.check().err().unwrap();
+    assert_eq!(circle.variant(), Some("Circle"));
+    assert_eq!(circle.field(), Some("radius"));
+    assert_eq!(
+        circle.to_string(),
+        "Circle.radius: must be in the range 0.0.."
+    );
+
+    assert_eq!(
+        message(Shape::Polygon(2, vec![(0.0, 0.0)])),
+        Some("Polygon.0: must be in the range 3..".into())
+    );
+    assert_eq!(
+        message(Shape::Polygon(3, vec![])),
+        Some("Polygon.1: must not be empty".into())
+    );
+}
+
+#[derive(Check)]
+#[check(with = "start_before_end")]
+struct Span {
+    #[check(range(..1000))]
+    start: u32,
+    end: u32,
+}
+
+fn start_before_end(span: &Span) -> Result<(), &'static str> {
+    if span.start <= span.end {
+        Ok(())
+    } else {
+        Err("start must not be after end")
+    }
+}
+
+#[test]
+fn with() {
+    assert!(Span { start: 1, end: 2 }.check().is_ok());
+    assert!(Span { start: 2, end: 2 }.check().is_ok());
+
+    let error = Span { start: 3, end: 2 }.check().err().unwrap();
+    assert_eq!(error.field(), None);
+    assert_eq!(error.to_string(), "start must not be after end");
+
+    // Field checks run first.
+    assert_eq!(
+        message(Span {
+            start: 1000,
+            end: 2
+        }),
+        Some("start: must be in the range ..1000".into())
+    );
+}
+
+#[derive(Check)]
+#[check(error_enum = "SignupError", with = "passwords_match")]
+struct Signup {
+    #[check(non_empty)]
+    user_name: String,
+    #[check(custom = "strong")]
+    password: String,
+    confirm_password: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Weak;
+
+impl std::fmt::Display for Weak {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("too weak")
+    }
+}
+
+impl std::error::Error for Weak {}
+
+fn strong(password: &str) -> Result<(), Weak> {
+    if password.len() < 8 {
+        Err(Weak)
+    } else {
+        Ok(())
+    }
+}
+
+fn passwords_match(signup: &Signup) -> Result<(), &'static str> {
+    if signup.password == signup.confirm_password {
+        Ok(())
+    } else {
+        Err("passwords must match")
+    }
+}
+
+fn signup(user_name: &str, password: &str, confirm_password: &str) -> Signup {
+    Signup {
+        user_name: user_name.into(),
+        password: password.into(),
+        confirm_password: confirm_password.into(),
+    }
+}
+
+#[test]
+fn error_enum() {
+    assert!(signup("ferris", "hunter22", "hunter22").check().is_ok());
+
+    match signup("", "hunter22", "hunter22").check() {
+        Err(SignupError::UserName(error)) => assert_eq!(error.to_string(), "must not be empty"),
+        _ => panic!("expected a `UserName` error"),
+    }
+    match signup("ferris", "hunter2", "hunter2").check() {
+        Err(SignupError::Password(error)) => assert_eq!(error.downcast_ref(), Some(&Weak)),
+        _ => panic!("expected a `Password` error"),
+    }
+    assert!(matches!(
+        signup("ferris", "hunter22", "hunter23").check(),
+        Err(SignupError::Invalid(_))
+    ));
+
+    assert_eq!(
+        message(signup("ferris", "hunter2", "hunter2")),
+        Some("password: too weak".into())
+    );
+    assert_eq!(
+        message(signup("ferris", "hunter22", "hunter23")),
+        Some("passwords must match".into())
+    );
+}
+
+#[derive(Check)]
+#[check(error_enum = "ShapeError")]
+enum TypedShape {
+    Circle {
+        #[check(range(0.0..))]
+        radius: f64,
+    },
+    Polygon(#[check(range(3..))] u32),
+}
+
+#[test]
+fn error_enum_variants() {
+    assert!(matches!(
+        TypedShape::Circle { radius: -1.0 }.check(),
+        Err(ShapeError::CircleRadius(_))
+    ));
+    assert!(matches!(
+        TypedShape::Polygon(2).check(),
+        Err(ShapeError::Polygon0(_))
+    ));
+    assert_eq!(
+        message(TypedShape::Polygon(2)),
+        Some("Polygon.0: must be in the range 3..".into())
+    );
+}
+
+#[derive(Check)]
+#[check(error_enum = "NeverError")]
+struct Unchecked {
+    _value: u8,
+}
+
+#[test]
+fn error_enum_empty() {
+    assert!(Unchecked { _value: 0 }.check().is_ok());
+}
+
+mod newtype {
+    use check_mate::{checked, Check, Checked};
+    use std::convert::TryFrom;
+
+    #[checked(derive(Clone, Debug, PartialEq))]
+    #[derive(Check, Clone, Debug, PartialEq)]
+    pub struct Email {
+        #[check(custom = "has_at")]
+        pub address: String,
+    }
+
+    fn has_at(address: &str) -> Result<(), &'static str> {
+        if address.contains('@') {
+            Ok(())
+        } else {
+            Err("must contain '@'")
+        }
+    }
+
+    fn email(address: &str) -> Email {
+        Email {
+            address: address.into(),
+        }
+    }
+
+    #[test]
+    fn checked_newtype() {
+        let checked = CheckedEmail::new(email("ferris@example.com")).unwrap();
+        assert_eq!(checked.address, "ferris@example.com");
+        assert_eq!(checked.clone(), checked);
+        assert_eq!(checked.into_inner(), email("ferris@example.com"));
+
+        assert_eq!(
+            CheckedEmail::new(email("ferris")).unwrap_err().to_string(),
+            "address: must contain '@'"
+        );
+        assert!(CheckedEmail::try_from(email("ferris")).is_err());
+
+        // SAFETY: nothing relies on the value being valid.
+        let unchecked = unsafe { CheckedEmail::new_unchecked(email("ferris")) };
+        assert_eq!(unchecked.address, "ferris");
+    }
+
+    #[test]
+    fn checked_conversions() {
+        let checked = Checked::try_from(email("ferris@example.com")).unwrap();
+        let newtype = CheckedEmail::from(checked);
+        assert_eq!(newtype.into_inner(), email("ferris@example.com"));
+    }
+
+    #[checked(name = "Window")]
+    #[derive(Check)]
+    pub struct Bounds<T>
+    where
+        T: PartialOrd,
+    {
+        pub lo: T,
+        pub hi: T,
+    }
+
+    #[test]
+    fn checked_generic() {
+        let window = Window::new(Bounds { lo: 1, hi: 2 }).unwrap();
+        assert_eq!((window.lo, window.hi), (1, 2));
+    }
+}
+
+#[derive(Check, Debug, PartialEq)]
+struct Address {
+    #[check(non_empty)]
+    city: String,
+}
+
+#[derive(Check, Debug, PartialEq)]
+#[check(with = "has_contact")]
+struct Customer {
+    #[check(non_empty)]
+    name: String,
+    #[check(nested)]
+    address: Address,
+    #[check(nested)]
+    shape: Shape,
+    #[check(nested)]
+    quantity: Checked<u8, check_mate::checks::NonZero>,
+    email: Option<String>,
+}
+
+fn has_contact(customer: &Customer) -> Result<(), &'static str> {
+    if customer.email.is_some() {
+        Ok(())
+    } else {
+        Err("must have an email address")
+    }
+}
+
+fn customer(city: &str, radius: f64) -> Customer {
+    Customer {
+        name: "ferris".into(),
+        address: Address { city: city.into() },
+        shape: Shape::Circle { radius },
+        quantity: Checked::refine(1).unwrap(),
+        email: Some("ferris@example.com".into()),
+    }
+}
+
+#[test]
+fn nested() {
+    assert_eq!(
+        customer("Berlin", 1.0).check().ok(),
+        Some(customer("Berlin", 1.0))
+    );
+
+    let error = customer("", 1.0).check().err().unwrap();
+    assert_eq!(error.field(), Some("address.city"));
+    assert_eq!(error.to_string(), "address.city: must not be empty");
+
+    assert_eq!(
+        message(customer("Berlin", -1.0)),
+        Some("shape.Circle.radius: must be in the range 0.0..".into())
+    );
+    assert_eq!(
+        message(Customer {
+            email: None,
+            ..customer("Berlin", 1.0)
+        }),
+        Some("must have an email address".into())
+    );
+}
+
+#[derive(Check)]
+#[check(error_enum = "OuterError")]
+enum Outer {
+    Address(#[check(nested)] Address),
+    Empty,
+}
+
+#[test]
+fn nested_error_enum() {
+    assert!(Outer::Empty.check().is_ok());
+    match (Outer::Address(Address { city: "".into() })).check() {
+        Err(OuterError::Address0(error)) => {
+            assert_eq!(error.to_string(), "city: must not be empty")
+        }
+        _ => panic!("expected an `Address0` error"),
+    }
+}
+
+#[derive(Check, Debug)]
+#[check(with = "ordered")]
+struct Window<T: Ord>
+where
+    T: Copy,
+{
+    lo: T,
+    hi: T,
+}
+
+fn ordered<T: Ord + Copy>(window: &Window<T>) -> Result<(), &'static str> {
+    if window.lo <= window.hi {
+        Ok(())
+    } else {
+        Err("lo must not be greater than hi")
+    }
+}
+
+#[derive(Check)]
+struct Labelled<'a, T, const N: usize> {
+    #[check(non_empty)]
+    label: &'a str,
+    #[check(nested)]
+    value: T,
+    #[check(nested)]
+    proof: Checked<[u8; N], check_mate::checks::NonEmpty>,
+}
+
+#[derive(Check)]
+#[check(bound = "T: std::fmt::Debug + PartialOrd + From<u8>")]
+struct Positive<T> {
+    #[check(custom = "positive")]
+    value: T,
+}
+
+fn positive<T: PartialOrd + From<u8>>(value: &T) -> Result<(), &'static str> {
+    if *value > T::from(0) {
+        Ok(())
+    } else {
+        Err("must be positive")
+    }
+}
+
+#[test]
+fn generics() {
+    assert!(Window { lo: 1, hi: 2 }.check().is_ok());
+    assert_eq!(
+        message(Window { lo: 'b', hi: 'a' }),
+        Some("lo must not be greater than hi".into())
+    );
+
+    let labelled = |label, lo, hi| Labelled {
+        label,
+        value: Window { lo, hi },
+        proof: Checked::refine([0; 2]).unwrap(),
+    };
+    assert!(labelled("window", 1, 2).check().is_ok());
+    assert_eq!(
+        message(labelled("", 1, 2)),
+        Some("label: must not be empty".into())
+    );
+    assert_eq!(
+        message(labelled("window", 2, 1)),
+        Some("value: lo must not be greater than hi".into())
+    );
+
+    assert!(Positive { value: 1.5 }.check().is_ok());
+    assert_eq!(
+        message(Positive { value: 0u32 }),
+        Some("value: must be positive".into())
+    );
+}
+
+#[derive(Check, Debug)]
+#[check(builder)]
+struct Account<T> {
+    #[check(non_empty)]
+    r#name: String,
+    #[check(range(18..))]
+    age: u8,
+    extra: T,
+}
+
+#[test]
+fn builder() {
+    let account = Account::builder()
+        .name("ferris".into())
+        .age(18)
+        .extra(())
+        .build()
+        .unwrap();
+    assert_eq!(account.name, "ferris");
+    assert_eq!(account.age, 18);
+    assert_eq!(account.extra, ());
+
+    let missing = Account::<()>::builder().age(18).build().unwrap_err();
+    assert!(matches!(missing, BuildError::Missing("name")));
+    assert_eq!(missing.to_string(), "missing field `name`");
+
+    let invalid = Account::builder()
+        .name("ferris".into())
+        .age(17)
+        .extra(())
+        .build()
+        .unwrap_err();
+    assert_eq!(invalid.to_string(), "age: must be in the range 18..");
+}
+
+#[derive(Check)]
+struct Registration {
+    #[check(length(min = 3, max = 16), contains(pattern = "_"))]
+    user_name: String,
+    password: String,
+    #[check(must_match = "password")]
+    password_again: String,
+    #[check(range(min = 18))]
+    age: u8,
+    #[check(length(equal = 2))]
+    country: &'static str,
+}
+
+#[test]
+fn validator_vocabulary() {
+    let registration = || Registration {
+        user_name: "ferris_crab".into(),
+        password: "hunter2".into(),
+        password_again: "hunter2".into(),
+        age: 18,
+        country: "NZ",
+    };
+    assert!(registration().check().is_ok());
+    assert_eq!(
+        message(Registration {
+            user_name: "fe".into(),
+            ..registration()
+        }),
+        Some("user_name: must have length between 3 and 16, but had length 2".into())
+    );
+    assert_eq!(
+        message(Registration {
+            user_name: "ferris".into(),
+            ..registration()
+        }),
+        Some("user_name: must contain \"_\"".into())
+    );
+    assert_eq!(
+        message(Registration {
+            password_again: "hunter3".into(),
+            ..registration()
+        }),
+        Some("password_again: must match `password`".into())
+    );
+    assert_eq!(
+        message(Registration {
+            age: 17,
+            ..registration()
+        }),
+        Some("age: must be in the range 18..".into())
+    );
+    assert_eq!(
+        message(Registration {
+            country: "NZL",
+            ..registration()
+        }),
+        Some("country: must have length between 2 and 2, but had length 3".into())
+    );
+}
+
+#[cfg(all(feature = "email", feature = "url"))]
+#[derive(Check)]
+struct Contact(
+    #[check(email)] &'static str,
+    #[check(url)] String,
+    #[check(must_match = "0")] &'static str,
+);
+
+#[cfg(all(feature = "email", feature = "url"))]
+#[test]
+fn validator_email_url() {
+    let address = "ferris@rust-lang.org";
+    assert!(Contact(address, "https://rust-lang.org".into(), address)
+        .check()
+        .is_ok());
+    assert!(Contact("ferris", "https://rust-lang.org".into(), "ferris")
+        .check()
+        .is_err());
+    assert!(Contact(address, "rust-lang.org".into(), address)
+        .check()
+        .is_err());
+    assert_eq!(
+        message(Contact(address, "https://rust-lang.org".into(), "")),
+        Some("2: must match `0`".into())
+    );
+}
+
+#[test]
+fn check_ref() {
+    let orders = [
+        Order {
+            sku: "ABC-123".into(),
+            quantity: 3,
+        },
+        Order {
+            sku: "ABC-123".into(),
+            quantity: 11,
+        },
+    ];
+    assert!(orders[0].check_ref().is_ok());
+    assert_eq!(
+        orders[..].check_ref().unwrap_err().to_string(),
+        "quantity: must be in the range 1..=10"
+    );
+    assert!(Span { start: 2, end: 1 }.check_ref().is_err());
+    assert!(Shape::Circle { radius: -1.0 }.check_ref().is_err());
+}
+
+#[derive(Debug)]
+enum AppError {
+    Validation(String),
+}
+
+impl From<FieldError> for AppError {
+    fn from(error: FieldError) -> Self {
+        Self::Validation(error.to_string())
+    }
+}
+
+impl From<LegacyError> for AppError {
+    fn from(error: LegacyError) -> Self {
+        Self::Validation(format!("legacy {error}"))
+    }
+}
+
+#[derive(Check)]
+#[check(error = "AppError", with = "legacy_check")]
+struct Legacy {
+    #[check(skip, non_empty)]
+    name: String,
+    #[check(range(1..))]
+    version: u32,
+}
+
+#[derive(Check)]
+#[check(error = "AppError", error_enum = "LegacyError", builder)]
+struct LegacyEnum {
+    #[check(range(1..))]
+    version: u32,
+}
+
+fn legacy_check(legacy: &Legacy) -> Result<(), &'static str> {
+    if legacy.version < 100 {
+        Ok(())
+    } else {
+        Err("version too new")
+    }
+}
+
+#[test]
+fn skip_and_error() {
+    let legacy = |name: &str, version| Legacy {
+        name: name.into(),
+        version,
+    };
+    assert_eq!(legacy("", 1).check().unwrap().name, "");
+    assert!(matches!(
+        legacy("", 0).check(),
+        Err(AppError::Validation(message)) if message == "version: must be in the range 1.."
+    ));
+    assert!(matches!(
+        legacy("", 100).check(),
+        Err(AppError::Validation(message)) if message == "version too new"
+    ));
+    assert!(matches!(
+        LegacyEnum { version: 0 }.check(),
+        Err(AppError::Validation(message)) if message == "legacy version: must be in the range 1.."
+    ));
+    assert!(matches!(
+        LegacyEnum::builder().version(0).build(),
+        Err(BuildError::Invalid(AppError::Validation(_)))
+    ));
+}
+
+#[derive(Check, Debug)]
+#[check(
+    builder(typestate),
+    error_enum = "ProfileError",
+    with = "profile_consistent"
+)]
+struct Profile<T: Ord + Copy> {
+    #[check(length(max = 8))]
+    handle: String,
+    #[check(nested)]
+    window: Window<T>,
+    bio: Option<String>,
+}
+
+fn profile_consistent<T: Ord + Copy>(profile: &Profile<T>) -> Result<(), &'static str> {
+    if profile.bio.as_deref() == Some(&profile.handle) {
+        Err("bio must not repeat the handle")
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn typestate_builder() {
+    let profile = Profile::builder()
+        .bio(None)
+        .window(Window { lo: 1, hi: 2 })
+        .unwrap()
+        .handle("ferris".into())
+        .unwrap()
+        .build()
+        .unwrap();
+    assert_eq!(profile.handle, "ferris");
+    assert_eq!(profile.window.hi, 2);
+
+    assert!(matches!(
+        Profile::<u8>::builder().handle("ferris the crab".into()),
+        Err(ProfileError::Handle(_))
+    ));
+    assert!(matches!(
+        Profile::builder().window(Window { lo: 2, hi: 1 }),
+        Err(ProfileError::Window(_))
+    ));
+    assert!(matches!(
+        Profile::builder()
+            .handle("ferris".into())
+            .unwrap()
+            .window(Window { lo: 1, hi: 2 })
+            .unwrap()
+            .bio(Some("ferris".into()))
+            .build(),
+        Err(ProfileError::Invalid(_))
+    ));
+}